@@ -1,7 +1,8 @@
 use crate::config::loader::ConfigLoader;
+use crate::format::OutputFormat;
 use crate::logger::log_level::LogLevel;
-use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects};
+use clap::builder::{PossibleValue, Styles};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::fmt::Display;
 use std::path::PathBuf;
@@ -29,17 +30,16 @@ pub struct Cli {
         long,
         global = true,
         help = "Path to TOML configuration file (`mdlint.toml`)",
-        help_heading = "Configuration",
-        overrides_with = "no_config"
+        help_heading = "Configuration"
     )]
     pub config: Option<PathBuf>,
 
     #[arg(
         long,
         global = true,
-        help = "Ignore all configuration files",
+        help = "Ignore all configuration files and use the built-in defaults",
         help_heading = "Configuration",
-        overrides_with = "config"
+        conflicts_with = "config"
     )]
     pub no_config: bool,
 
@@ -73,14 +73,38 @@ pub struct Cli {
     )]
     pub silent: bool,
 
+    #[arg(
+        long,
+        global = true,
+        help = "Print config discovery, the effective config, and the resolved file list to stderr",
+        help_heading = "Log levels"
+    )]
+    pub debug: bool,
+
     #[arg(
         long,
         global = true,
         default_value_t = TerminalColor::Auto,
         hide_default_value = true,
-        help = "Control colors in output"
+        help = "Control colors in output",
+        overrides_with = "no_color"
     )]
     pub color: TerminalColor,
+
+    /// Alias for `--color never`.
+    #[arg(long, global = true, hide = true, overrides_with = "color")]
+    pub no_color: bool,
+}
+
+impl Cli {
+    /// The color setting, with `--no-color` resolved to [`TerminalColor::Never`].
+    pub fn color_choice(&self) -> TerminalColor {
+        if self.no_color {
+            TerminalColor::Never
+        } else {
+            self.color.clone()
+        }
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -100,7 +124,7 @@ pub struct ServerArgs {}
 pub struct CheckArgs {
     #[arg(
         value_name = "FILES",
-        help = "Files or directories to check (defaults to current directory)"
+        help = "Files, directories, or glob patterns to check (defaults to current directory)"
     )]
     pub files: Vec<PathBuf>,
 
@@ -111,6 +135,15 @@ pub struct CheckArgs {
     )]
     pub exclude: Vec<PathBuf>,
 
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Lint exactly the newline-separated paths listed in PATH (`-` for stdin), skipping discovery",
+        help_heading = "File selection",
+        conflicts_with = "files"
+    )]
+    pub files_from: Option<PathBuf>,
+
     #[arg(
         long,
         default_value_t = true,
@@ -123,16 +156,74 @@ pub struct CheckArgs {
     #[arg(long, hide = true, conflicts_with = "respect_ignore")]
     pub no_respect_ignore: bool,
 
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Descend at most N directories below each given directory (0 = direct children only)",
+        help_heading = "File selection"
+    )]
+    pub max_depth: Option<usize>,
+
     #[arg(long, help = "Apply auto-fixes where possible")]
     pub fix: bool,
 
     #[arg(
         long,
+        conflicts_with = "fix",
+        help = "Print the changes auto-fixes would make as a unified diff on stderr without writing them"
+    )]
+    pub fix_dry_run: bool,
+
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_name = "RULE_CODE",
+        help = "Comma-separated list of rules whose fixes are applied; others are only reported"
+    )]
+    pub fix_rules: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = ".bak",
+        help = "Copy each file to <path><SUFFIX> before fixing it [default suffix: .bak]"
+    )]
+    pub backup: Option<String>,
+
+    #[arg(
+        long,
+        help = "Exit with code 0 even when violations are found",
+        help_heading = "Exit status",
+        conflicts_with = "max_errors"
+    )]
+    pub exit_zero: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Exit with code 1 only when more than N violations are found",
+        help_heading = "Exit status"
+    )]
+    pub max_errors: Option<usize>,
+
+    #[arg(
+        long,
+        alias = "format",
         value_name = "FORMAT",
-        default_value_t = OutputFormat::Default,
-        help = "Output format"
+        help = "Output format [default: the config's `output_format`, else default]"
     )]
-    pub output_format: OutputFormat,
+    pub output_format: Option<OutputFormat>,
+
+    #[arg(long, help = "Print file paths relative to the current directory")]
+    pub relative_paths: bool,
+
+    #[arg(
+        long,
+        help = "Follow the default output's summary with violation counts per rule"
+    )]
+    pub stats: bool,
 
     #[arg(
         long,
@@ -162,6 +253,21 @@ pub struct CheckArgs {
         help_heading = "Rule selection"
     )]
     pub ignore: Vec<String>,
+
+    #[arg(
+        long,
+        help = "List every available rule with its description and tags, then exit",
+        help_heading = "Rule information"
+    )]
+    pub list_rules: bool,
+
+    #[arg(
+        long,
+        value_name = "RULE_CODE",
+        help = "Print a rule's description, tags, and default configuration, then exit",
+        help_heading = "Rule information"
+    )]
+    pub explain: Option<String>,
 }
 
 impl CheckArgs {
@@ -182,7 +288,7 @@ impl CheckArgs {
 pub struct FormatArgs {
     #[arg(
         value_name = "FILES",
-        help = "Files or directories to format (defaults to current directory)"
+        help = "Files, directories, or glob patterns to format (defaults to current directory)"
     )]
     pub files: Vec<PathBuf>,
 
@@ -205,6 +311,14 @@ pub struct FormatArgs {
     #[arg(long, hide = true, conflicts_with = "respect_ignore")]
     pub no_respect_ignore: bool,
 
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Descend at most N directories below each given directory (0 = direct children only)",
+        help_heading = "File selection"
+    )]
+    pub max_depth: Option<usize>,
+
     #[arg(
         long,
         help = "Check formatting without modifying files (exits with 1 if any file would change)"
@@ -252,19 +366,28 @@ impl From<&Cli> for LogLevel {
     }
 }
 
-#[derive(ValueEnum, Debug, Default, Clone)]
-pub enum OutputFormat {
-    #[default]
-    Default,
-    Json,
-}
+// The enum lives with the formatters so the config layer can name it too.
+impl ValueEnum for OutputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            OutputFormat::Default,
+            OutputFormat::Concise,
+            OutputFormat::Json,
+            OutputFormat::Ndjson,
+            OutputFormat::Junit,
+            OutputFormat::Sarif,
+        ]
+    }
 
-impl Display for OutputFormat {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            OutputFormat::Default => write!(f, "default"),
-            OutputFormat::Json => write!(f, "json"),
-        }
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            OutputFormat::Default => PossibleValue::new("default"),
+            OutputFormat::Concise => PossibleValue::new("concise").alias("simple"),
+            OutputFormat::Json => PossibleValue::new("json"),
+            OutputFormat::Ndjson => PossibleValue::new("ndjson"),
+            OutputFormat::Junit => PossibleValue::new("junit"),
+            OutputFormat::Sarif => PossibleValue::new("sarif"),
+        })
     }
 }
 
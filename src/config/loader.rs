@@ -1,9 +1,14 @@
-use crate::config::Config;
+use crate::config::{Config, RuleConfig};
 use crate::error::{MarkdownlintError, Result};
+use crate::glob::{GlobMatcher, is_glob_pattern};
 use std::path::{Path, PathBuf};
 use std::{fs, iter};
 
-const CONFIG_FILE_NAMES: &[&str] = &["mdlint.toml", ".mdlint.toml"];
+/// Checked in order in each directory; the first that exists wins.
+const CONFIG_FILE_NAMES: &[&str] = &["mdlint.toml", ".mdlint.toml", ".markdownlintrc"];
+
+/// markdownlint's JSON config file, read as JSONC.
+const MARKDOWNLINTRC: &str = ".markdownlintrc";
 
 pub enum ConfigLoader {
     Detect,
@@ -32,6 +37,11 @@ pub fn discover_config() -> Result<Config> {
     }
 }
 
+/// Collects the config file from `start_dir` and each of its ancestors, outermost first.
+///
+/// A config file that cannot be read or parsed is skipped with a warning on stderr so a
+/// stray broken file further up the tree does not block linting. One that parses but sets
+/// an invalid rule severity is still an error.
 pub fn find_all_configs(start_dir: &Path) -> Result<Vec<(PathBuf, Config)>> {
     let mut configs = Vec::new();
     let mut current = start_dir.to_path_buf();
@@ -40,8 +50,17 @@ pub fn find_all_configs(start_dir: &Path) -> Result<Vec<(PathBuf, Config)>> {
         for config_file in CONFIG_FILE_NAMES {
             let config_path = current.join(config_file);
             if config_path.exists() {
-                let config = load_config(&config_path)?;
-                configs.push((config_path, config));
+                match parse_config_file(&config_path) {
+                    Ok(config) => {
+                        validate_severities(&config, &config_path)?;
+                        configs.push((config_path, config));
+                    }
+                    Err(error) => eprintln!(
+                        "Warning: skipping config file {}: {}",
+                        config_path.display(),
+                        error
+                    ),
+                }
                 break;
             }
         }
@@ -55,20 +74,181 @@ pub fn find_all_configs(start_dir: &Path) -> Result<Vec<(PathBuf, Config)>> {
     Ok(configs)
 }
 fn load_config(path: &PathBuf) -> Result<Config> {
+    let config = parse_config_file(path)?;
+    validate_severities(&config, path)?;
+    Ok(config)
+}
+
+fn parse_config_file(path: &PathBuf) -> Result<Config> {
     let content = fs::read_to_string(path).map_err(|e| {
         MarkdownlintError::Config(format!("Failed to read config file {:?}: {}", path, e))
     })?;
-    parse_toml_config(&content, path)
+    let mut config = if path.file_name().is_some_and(|name| name == MARKDOWNLINTRC) {
+        parse_jsonc(&content, path)?
+    } else {
+        parse_toml_config(&content, path)?
+    };
+    anchor_to_config_dir(&mut config, path);
+    Ok(config)
+}
+
+/// Reject a rule `severity` other than `"error"` or `"warning"`, so a typo doesn't
+/// quietly make the rule an error.
+fn validate_severities(config: &Config, path: &Path) -> Result<()> {
+    let rules = config.rules.iter().chain(
+        config
+            .overrides
+            .iter()
+            .flat_map(|config_override| &config_override.rules),
+    );
+    for (name, rule_config) in rules {
+        let RuleConfig::Config(options) = rule_config else {
+            continue;
+        };
+        match options.get("severity") {
+            None => {}
+            Some(toml::Value::String(severity)) if severity == "error" || severity == "warning" => {
+            }
+            Some(other) => {
+                return Err(MarkdownlintError::Config(format!(
+                    "Invalid severity for {} in {}: {} (expected \"error\" or \"warning\")",
+                    name,
+                    path.display(),
+                    other
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `ignores` and `overrides` globs are relative to the config file's directory.
+/// Prefix each with that directory so it matches the same files whichever path is
+/// linted and wherever mdlint runs from.
+fn anchor_to_config_dir(config: &mut Config, path: &Path) {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let Ok(dir) = dir.canonicalize() else {
+        return;
+    };
+    let root = globset::escape(&dir.to_string_lossy());
+    let anchor = |pattern: &str| format!("{}/{}", root, pattern.trim_start_matches("./"));
+    for ignore in &mut config.ignores {
+        if !Path::new(ignore.as_str()).is_absolute() {
+            *ignore = anchor(ignore);
+        }
+    }
+    for config_override in &mut config.overrides {
+        for pattern in &mut config_override.files {
+            // A `#` exclusion of a bare name matches that directory anywhere.
+            let (prefix, glob) = match pattern.strip_prefix('#') {
+                Some(rest) if is_glob_pattern(rest) => ("#", rest),
+                Some(_) => continue,
+                None => ("", pattern.as_str()),
+            };
+            if !Path::new(glob).is_absolute() {
+                *pattern = format!("{}{}", prefix, anchor(glob));
+            }
+        }
+    }
 }
 
 fn parse_toml_config(content: &str, _path: &Path) -> Result<Config> {
-    toml::from_str(content)
-        .map_err(|e| MarkdownlintError::Config(format!("Failed to parse TOML: {}", e)))
+    let config: Config = toml::from_str(content)
+        .map_err(|e| MarkdownlintError::Config(format!("Failed to parse TOML: {}", e)))?;
+    validate_overrides(&config)?;
+    Ok(config)
+}
+
+/// Reject override globs that cannot be compiled, so the engine can rely on them.
+fn validate_overrides(config: &Config) -> Result<()> {
+    for config_override in &config.overrides {
+        GlobMatcher::new(&config_override.files)
+            .map_err(|e| MarkdownlintError::Config(format!("Invalid overrides entry: {}", e)))?;
+    }
+    Ok(())
+}
+
+/// Parse a markdownlint-style JSON config, which may contain comments. Rule keys
+/// sit at the top level next to `"default"`, which maps to `default_enabled`.
+fn parse_jsonc(content: &str, _path: &Path) -> Result<Config> {
+    let parse_error = |e: serde_json::Error| {
+        MarkdownlintError::Config(format!("Failed to parse JSON config: {}", e))
+    };
+    let value: serde_json::Value =
+        serde_json::from_str(&strip_json_comments(content)).map_err(parse_error)?;
+    let serde_json::Value::Object(entries) = value else {
+        return Err(MarkdownlintError::Config(
+            "Failed to parse JSON config: expected an object".to_string(),
+        ));
+    };
+
+    let mut config = serde_json::Map::new();
+    let mut rules = serde_json::Map::new();
+    for (key, value) in entries {
+        match key.as_str() {
+            "default" => {
+                config.insert("default_enabled".to_string(), value);
+            }
+            "commonmark" => {
+                config.insert(key, value);
+            }
+            // Schema references and config inheritance are not supported.
+            "$schema" | "extends" => {}
+            _ => {
+                rules.insert(key, value);
+            }
+        }
+    }
+    config.insert("rules".to_string(), serde_json::Value::Object(rules));
+    serde_json::from_value(serde_json::Value::Object(config)).map_err(parse_error)
+}
+
+/// Remove `//` and `/* */` comments outside of string literals.
+fn strip_json_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|&c| c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                output.push(' ');
+            }
+            _ => output.push(c),
+        }
+    }
+    output
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::RuleConfig;
     use std::io::Write;
     use tempfile::TempDir;
 
@@ -91,6 +271,36 @@ style = "atx"
         assert_eq!(config.rules.len(), 2);
     }
 
+    #[test]
+    fn test_parse_top_level_line_length() {
+        let config = parse_toml_config("line_length = 100\n", Path::new("test.toml")).unwrap();
+        assert_eq!(config.line_length, Some(100));
+
+        let config = parse_toml_config("lineLength = 120\n", Path::new("test.toml")).unwrap();
+        assert_eq!(config.line_length, Some(120));
+    }
+
+    #[test]
+    fn test_parse_overrides() {
+        let content = r#"
+[rules.MD013]
+line_length = 80
+
+[[overrides]]
+files = ["docs/**"]
+rules.MD013.line_length = 120
+"#;
+
+        let config = parse_toml_config(content, Path::new("test.toml")).unwrap();
+        assert_eq!(config.overrides.len(), 1);
+        assert_eq!(config.overrides[0].files, vec!["docs/**"]);
+        assert!(config.overrides[0].rules.contains_key("MD013"));
+
+        let invalid = "[[overrides]]\nfiles = [\"docs/[\"]\n";
+        let err = parse_toml_config(invalid, Path::new("test.toml")).unwrap_err();
+        assert!(err.to_string().contains("overrides"), "{err}");
+    }
+
     #[test]
     fn test_load_from_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -134,4 +344,143 @@ line_length = 80
         // Restore original directory
         std::env::set_current_dir(original_dir).unwrap();
     }
+
+    #[test]
+    fn test_find_all_configs_skips_malformed_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let sub_dir = temp_dir.path().join("docs");
+        fs::create_dir(&sub_dir).unwrap();
+        fs::write(temp_dir.path().join(".markdownlintrc"), "{ \"MD013\": \n").unwrap();
+        fs::write(
+            sub_dir.join("mdlint.toml"),
+            "[rules.MD013]\nline_length = 120\n",
+        )
+        .unwrap();
+
+        let configs = find_all_configs(&sub_dir).unwrap();
+        let paths: Vec<&PathBuf> = configs.iter().map(|(path, _)| path).collect();
+        assert!(!paths.iter().any(|path| path.ends_with(".markdownlintrc")));
+        assert!(paths.iter().any(|path| path.starts_with(&sub_dir)));
+    }
+
+    #[test]
+    fn test_load_malformed_config_file_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("mdlint.toml");
+        fs::write(&config_path, "line_length = \n").unwrap();
+
+        assert!(ConfigLoader::File(config_path).load().is_err());
+    }
+
+    #[test]
+    fn test_load_anchors_ignores_to_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("mdlint.toml");
+        fs::write(
+            &config_path,
+            "ignores = [\"CHANGELOG.md\", \"./vendor/**\"]\n",
+        )
+        .unwrap();
+
+        let config = ConfigLoader::File(config_path).load().unwrap();
+        let root = globset::escape(&temp_dir.path().canonicalize().unwrap().to_string_lossy());
+        assert_eq!(
+            config.ignores,
+            vec![format!("{root}/CHANGELOG.md"), format!("{root}/vendor/**")]
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_severity() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("mdlint.toml");
+
+        fs::write(&config_path, "[rules.MD013]\nseverity = \"warning\"\n").unwrap();
+        assert!(ConfigLoader::File(config_path.clone()).load().is_ok());
+
+        fs::write(&config_path, "[rules.MD013]\nseverity = \"warn\"\n").unwrap();
+        let err = ConfigLoader::File(config_path.clone()).load().unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid severity for MD013"),
+            "{err}"
+        );
+
+        fs::write(
+            &config_path,
+            "[[overrides]]\nfiles = [\"docs/**\"]\n\n[overrides.rules.MD009]\nseverity = 1\n",
+        )
+        .unwrap();
+        assert!(ConfigLoader::File(config_path).load().is_err());
+    }
+
+    #[test]
+    fn test_load_anchors_override_files_to_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("mdlint.toml");
+        fs::write(
+            &config_path,
+            "[[overrides]]\nfiles = [\"docs/**\", \"#docs/gen/**\", \"#vendor\"]\n",
+        )
+        .unwrap();
+
+        let config = ConfigLoader::File(config_path).load().unwrap();
+        let root = globset::escape(&temp_dir.path().canonicalize().unwrap().to_string_lossy());
+        assert_eq!(
+            config.overrides[0].files,
+            vec![
+                format!("{root}/docs/**"),
+                format!("#{root}/docs/gen/**"),
+                "#vendor".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_jsonc() {
+        let content = r#"{
+  // Allow long lines
+  "default": true,
+  "MD013": false, /* trailing comment */
+  "MD003": { "style": "atx" },
+  "MD044": { "names": ["http://example.com"] },
+  "commonmark": { "footnotes": false }
+}"#;
+
+        let config = parse_jsonc(content, Path::new(".markdownlintrc")).unwrap();
+        assert!(config.default_enabled);
+        assert!(matches!(
+            config.rules.get("MD013"),
+            Some(RuleConfig::Enabled(false))
+        ));
+        assert!(matches!(
+            config.rules.get("MD003"),
+            Some(RuleConfig::Config(options)) if options["style"].as_str() == Some("atx")
+        ));
+        assert!(config.rules.contains_key("MD044"));
+        assert!(!config.rules.contains_key("commonmark"));
+        assert!(!config.commonmark.footnotes);
+    }
+
+    #[test]
+    fn test_discover_markdownlintrc() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join(".markdownlintrc"),
+            "{ \"MD013\": false }\n",
+        )
+        .unwrap();
+
+        let configs = find_all_configs(temp_dir.path()).unwrap();
+        let (path, config) = configs.last().unwrap();
+        assert!(path.ends_with(".markdownlintrc"));
+        assert!(matches!(
+            config.rules.get("MD013"),
+            Some(RuleConfig::Enabled(false))
+        ));
+
+        let engine = crate::lint::LintEngine::new(config.clone());
+        let long_line = format!("# Title\n\n{}\n", "word ".repeat(40).trim_end());
+        let violations = engine.lint_content(&long_line).unwrap();
+        assert!(!violations.iter().any(|v| v.rule == "MD013"));
+    }
 }
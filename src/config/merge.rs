@@ -12,32 +12,65 @@ pub fn merge_configs(mut base: Config, override_cfg: Config) -> Config {
         base.exclude.extend(override_cfg.exclude);
     }
 
+    // Extend ignore globs
+    if !override_cfg.ignores.is_empty() {
+        base.ignores.extend(override_cfg.ignores);
+    }
+
+    // Overrides from every config level apply, later (closer) ones last
+    base.overrides.extend(override_cfg.overrides);
+
+    // A configured extension list replaces the inherited one
+    if !override_cfg.extensions.is_empty() {
+        base.extensions = override_cfg.extensions;
+    }
+
     base.default_enabled = override_cfg.default_enabled;
     base.fix = override_cfg.fix;
+    base.fail_on = override_cfg.fail_on;
 
     // Override front_matter if set
     if override_cfg.front_matter.is_some() {
         base.front_matter = override_cfg.front_matter;
     }
 
+    if override_cfg.output_format.is_some() {
+        base.output_format = override_cfg.output_format;
+    }
+
+    if override_cfg.line_length.is_some() {
+        base.line_length = override_cfg.line_length;
+    }
+
     // Override gitignore setting
     if !override_cfg.gitignore {
         base.gitignore = false;
     }
 
+    if override_cfg.math {
+        base.math = true;
+    }
+
+    // Like gitignore, an extension disabled at any level stays disabled
+    let commonmark = &mut base.commonmark;
+    commonmark.tables &= override_cfg.commonmark.tables;
+    commonmark.footnotes &= override_cfg.commonmark.footnotes;
+    commonmark.strikethrough &= override_cfg.commonmark.strikethrough;
+    commonmark.tasklists &= override_cfg.commonmark.tasklists;
+    commonmark.heading_attributes &= override_cfg.commonmark.heading_attributes;
+
     // Override no_inline_config if set
     if override_cfg.no_inline_config {
         base.no_inline_config = true;
     }
 
-    // Merge rule configurations
-    for (rule_name, rule_config) in override_cfg.rules {
-        base.rules.insert(rule_name, rule_config);
-    }
+    base.rules = merge_rule_configs(&base.rules, &override_cfg.rules);
 
     base
 }
 
+/// Merge per-rule configuration, deep-merging option tables so that options set
+/// in `base` survive unless `override_cfg` sets the same key (override wins per key).
 pub fn merge_rule_configs(
     base: &HashMap<String, RuleConfig>,
     override_cfg: &HashMap<String, RuleConfig>,
@@ -45,12 +78,60 @@ pub fn merge_rule_configs(
     let mut merged = base.clone();
 
     for (rule_name, rule_config) in override_cfg {
-        merged.insert(rule_name.clone(), rule_config.clone());
+        let combined = match merged.remove(rule_name) {
+            Some(existing) => merge_rule_config(existing, rule_config.clone()),
+            None => rule_config.clone(),
+        };
+        merged.insert(rule_name.clone(), combined);
     }
 
     merged
 }
 
+fn merge_rule_config(base: RuleConfig, override_cfg: RuleConfig) -> RuleConfig {
+    match (base, override_cfg) {
+        (RuleConfig::Config(base_opts), RuleConfig::Config(override_opts)) => {
+            RuleConfig::Config(merge_tables(base_opts, override_opts))
+        }
+        // Re-enabling a rule keeps the options configured further up the hierarchy.
+        (RuleConfig::Config(mut base_opts), RuleConfig::Enabled(true)) => {
+            base_opts.insert("enabled".to_string(), toml::Value::Boolean(true));
+            RuleConfig::Config(base_opts)
+        }
+        (_, override_cfg) => override_cfg,
+    }
+}
+
+fn merge_tables(
+    mut base: HashMap<String, toml::Value>,
+    override_opts: HashMap<String, toml::Value>,
+) -> HashMap<String, toml::Value> {
+    for (key, value) in override_opts {
+        let merged = match base.remove(&key) {
+            Some(existing) => merge_values(existing, value),
+            None => value,
+        };
+        base.insert(key, merged);
+    }
+    base
+}
+
+fn merge_values(base: toml::Value, override_value: toml::Value) -> toml::Value {
+    match (base, override_value) {
+        (toml::Value::Table(mut base_table), toml::Value::Table(override_table)) => {
+            for (key, value) in override_table {
+                let merged = match base_table.remove(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => value,
+                };
+                base_table.insert(key, merged);
+            }
+            toml::Value::Table(base_table)
+        }
+        (_, override_value) => override_value,
+    }
+}
+
 pub fn merge_many_configs(configs: Vec<Config>) -> Config {
     configs.into_iter().fold(Config::default(), merge_configs)
 }
@@ -89,6 +170,15 @@ mod tests {
         assert!(!merged.gitignore);
     }
 
+    #[test]
+    fn test_merge_configs_commonmark_disabled_extension_stays_disabled() {
+        let mut base = Config::default();
+        base.commonmark.footnotes = false;
+        let merged = merge_configs(base, Config::default());
+        assert!(!merged.commonmark.footnotes);
+        assert!(merged.commonmark.tables);
+    }
+
     #[test]
     fn test_merge_configs_rules() {
         let mut base = Config::default();
@@ -129,4 +219,149 @@ mod tests {
         assert!(merged.no_inline_config);
         assert_eq!(merged.rules.len(), 1);
     }
+
+    #[test]
+    fn test_merge_configs_ignores_extend() {
+        let base = Config {
+            ignores: vec!["vendor/**".to_string()],
+            ..Default::default()
+        };
+        let override_cfg = Config {
+            ignores: vec!["generated/*.md".to_string()],
+            ..Default::default()
+        };
+
+        let merged = merge_configs(base, override_cfg);
+        assert_eq!(merged.ignores, vec!["vendor/**", "generated/*.md"]);
+    }
+
+    #[test]
+    fn test_merge_configs_extensions_replace() {
+        let base = Config {
+            extensions: vec!["md".to_string()],
+            ..Default::default()
+        };
+        let override_cfg = Config {
+            extensions: vec!["mdx".to_string()],
+            ..Default::default()
+        };
+
+        let merged = merge_configs(base, override_cfg);
+        assert_eq!(merged.extensions, vec!["mdx"]);
+    }
+
+    fn rule_options(entries: &[(&str, toml::Value)]) -> RuleConfig {
+        RuleConfig::Config(
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        )
+    }
+
+    fn config_with_rule(name: &str, rule_config: RuleConfig) -> Config {
+        let mut config = Config::default();
+        config.rules.insert(name.to_string(), rule_config);
+        config
+    }
+
+    #[test]
+    fn test_merge_rule_options_disjoint_keys() {
+        let parent = config_with_rule(
+            "MD013",
+            rule_options(&[("line_length", toml::Value::Integer(100))]),
+        );
+        let child = config_with_rule(
+            "MD013",
+            rule_options(&[("tables", toml::Value::Boolean(false))]),
+        );
+
+        let merged = merge_many_configs(vec![parent, child]);
+        let Some(RuleConfig::Config(opts)) = merged.rules.get("MD013") else {
+            panic!("MD013 should have merged options");
+        };
+        assert_eq!(opts.get("line_length"), Some(&toml::Value::Integer(100)));
+        assert_eq!(opts.get("tables"), Some(&toml::Value::Boolean(false)));
+    }
+
+    #[test]
+    fn test_merge_rule_options_overlapping_keys_child_wins() {
+        let parent = config_with_rule(
+            "MD013",
+            rule_options(&[
+                ("line_length", toml::Value::Integer(100)),
+                ("code_blocks", toml::Value::Boolean(false)),
+            ]),
+        );
+        let child = config_with_rule(
+            "MD013",
+            rule_options(&[("line_length", toml::Value::Integer(120))]),
+        );
+
+        let merged = merge_many_configs(vec![parent, child]);
+        let Some(RuleConfig::Config(opts)) = merged.rules.get("MD013") else {
+            panic!("MD013 should have merged options");
+        };
+        assert_eq!(opts.get("line_length"), Some(&toml::Value::Integer(120)));
+        assert_eq!(opts.get("code_blocks"), Some(&toml::Value::Boolean(false)));
+    }
+
+    #[test]
+    fn test_merge_rule_disabled_by_child() {
+        let parent = config_with_rule(
+            "MD013",
+            rule_options(&[("line_length", toml::Value::Integer(100))]),
+        );
+        let child = config_with_rule("MD013", RuleConfig::Enabled(false));
+
+        let merged = merge_configs(parent, child);
+        assert!(matches!(
+            merged.rules.get("MD013"),
+            Some(RuleConfig::Enabled(false))
+        ));
+    }
+
+    #[test]
+    fn test_merge_rule_reenabled_keeps_parent_options() {
+        let parent = config_with_rule(
+            "MD013",
+            rule_options(&[
+                ("line_length", toml::Value::Integer(100)),
+                ("enabled", toml::Value::Boolean(false)),
+            ]),
+        );
+        let child = config_with_rule("MD013", RuleConfig::Enabled(true));
+
+        let merged = merge_configs(parent, child);
+        let Some(RuleConfig::Config(opts)) = merged.rules.get("MD013") else {
+            panic!("MD013 should keep its options");
+        };
+        assert_eq!(opts.get("line_length"), Some(&toml::Value::Integer(100)));
+        assert_eq!(opts.get("enabled"), Some(&toml::Value::Boolean(true)));
+    }
+
+    #[test]
+    fn test_merge_rule_nested_tables() {
+        let mut parent_nested = toml::map::Map::new();
+        parent_nested.insert("a".to_string(), toml::Value::Integer(1));
+        let mut child_nested = toml::map::Map::new();
+        child_nested.insert("b".to_string(), toml::Value::Integer(2));
+
+        let parent = config_with_rule(
+            "MD999",
+            rule_options(&[("nested", toml::Value::Table(parent_nested))]),
+        );
+        let child = config_with_rule(
+            "MD999",
+            rule_options(&[("nested", toml::Value::Table(child_nested))]),
+        );
+
+        let merged = merge_configs(parent, child);
+        let Some(RuleConfig::Config(opts)) = merged.rules.get("MD999") else {
+            panic!("MD999 should have merged options");
+        };
+        let nested = opts.get("nested").and_then(|v| v.as_table()).unwrap();
+        assert_eq!(nested.get("a"), Some(&toml::Value::Integer(1)));
+        assert_eq!(nested.get("b"), Some(&toml::Value::Integer(2)));
+    }
 }
@@ -4,4 +4,4 @@ mod types;
 
 pub use loader::ConfigLoader;
 pub use merge::{merge_configs, merge_many_configs, merge_rule_configs};
-pub use types::{Config, RuleConfig};
+pub use types::{CommonMarkOptions, Config, ConfigOverride, FailOn, RuleConfig, Severity};
@@ -1,3 +1,4 @@
+use crate::format::OutputFormat;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -19,10 +20,22 @@ pub struct Config {
     #[serde(default = "default_gitignore")]
     pub gitignore: bool,
 
-    /// Front matter pattern (YAML --- or TOML +++)
+    /// Front matter delimiter line (future feature; not yet applied to linting)
     #[serde(default)]
     pub front_matter: Option<String>,
 
+    /// Project-wide maximum line length, used by MD013 unless its own config sets one
+    #[serde(default, alias = "lineLength", alias = "line-length")]
+    pub line_length: Option<u64>,
+
+    /// Parse `$…$` / `$$…$$` as math so emphasis rules ignore `*` and `_` inside it
+    #[serde(default)]
+    pub math: bool,
+
+    /// Markdown extensions enabled when parsing, on top of strict CommonMark
+    #[serde(default)]
+    pub commonmark: CommonMarkOptions,
+
     /// Disable inline configuration comments
     #[serde(default)]
     pub no_inline_config: bool,
@@ -31,9 +44,93 @@ pub struct Config {
     #[serde(default)]
     pub exclude: Vec<String>,
 
+    /// Glob patterns (relative to the config file's directory) for files to skip when linting
+    #[serde(default)]
+    pub ignores: Vec<String>,
+
+    /// File extensions treated as Markdown during discovery; empty means the built-in list
+    #[serde(default)]
+    pub extensions: Vec<String>,
+
     /// Apply auto-fixes automatically when running `mdlint check`
     #[serde(default = "default_fix")]
     pub fix: bool,
+
+    /// Lowest rule severity whose violations make `mdlint check` exit non-zero
+    #[serde(default)]
+    pub fail_on: FailOn,
+
+    /// Output format for `mdlint check` when `--format` is not passed
+    #[serde(default, alias = "format")]
+    pub output_format: Option<OutputFormat>,
+
+    /// Rule settings applied only to files matching a set of globs
+    #[serde(default)]
+    pub overrides: Vec<ConfigOverride>,
+}
+
+/// Parser extensions beyond strict CommonMark. All are on by default; turning one off
+/// makes rules see that syntax as plain text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CommonMarkOptions {
+    #[serde(default = "default_extension")]
+    pub tables: bool,
+
+    #[serde(default = "default_extension")]
+    pub footnotes: bool,
+
+    #[serde(default = "default_extension")]
+    pub strikethrough: bool,
+
+    #[serde(default = "default_extension")]
+    pub tasklists: bool,
+
+    /// `{#id .class}` attributes after heading text
+    #[serde(default = "default_extension")]
+    pub heading_attributes: bool,
+}
+
+impl Default for CommonMarkOptions {
+    fn default() -> Self {
+        Self {
+            tables: true,
+            footnotes: true,
+            strikethrough: true,
+            tasklists: true,
+            heading_attributes: true,
+        }
+    }
+}
+
+/// Rule settings for the files matching `files`, merged over the base `rules`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConfigOverride {
+    /// Glob patterns, relative to the config file's directory
+    pub files: Vec<String>,
+
+    #[serde(default)]
+    pub rules: HashMap<String, RuleConfig>,
+}
+
+/// How serious a rule's violations are, set per rule with `severity = "warning"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// Which violations cause a non-zero exit code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    /// Only violations of error-severity rules.
+    #[default]
+    Error,
+    /// Violations of any severity.
+    Warning,
+    /// Never; violations are still reported.
+    None,
 }
 
 fn default_default_enabled() -> bool {
@@ -48,6 +145,10 @@ fn default_fix() -> bool {
     true
 }
 
+fn default_extension() -> bool {
+    true
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -56,9 +157,17 @@ impl Default for Config {
             custom_rules: Vec::new(),
             gitignore: default_gitignore(),
             front_matter: None,
+            line_length: None,
+            math: false,
+            commonmark: CommonMarkOptions::default(),
             no_inline_config: false,
             exclude: Vec::new(),
+            ignores: Vec::new(),
+            extensions: Vec::new(),
             fix: true,
+            fail_on: FailOn::default(),
+            output_format: None,
+            overrides: Vec::new(),
         }
     }
 }
@@ -77,3 +186,18 @@ impl Config {
         &self.rules
     }
 }
+
+impl RuleConfig {
+    /// The `severity` option of a rule's table; rules are errors unless set to `"warning"`.
+    /// Other values are rejected when the config is loaded.
+    pub fn severity(&self) -> Severity {
+        match self {
+            RuleConfig::Config(options)
+                if options.get("severity").and_then(|v| v.as_str()) == Some("warning") =>
+            {
+                Severity::Warning
+            }
+            _ => Severity::Error,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Line-based unified diffs, used to preview fixes without writing them.
+
+/// Lines of context shown around each change.
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Render a unified diff turning `old` into `new`, or an empty string when they are equal.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let edits = diff_lines(&old_lines, &new_lines);
+    if edits.iter().all(|&edit| edit == Edit::Equal) {
+        return String::new();
+    }
+
+    // Lines of each side consumed before every edit, so hunk headers can be computed.
+    let mut old_pos = Vec::with_capacity(edits.len() + 1);
+    let mut new_pos = Vec::with_capacity(edits.len() + 1);
+    let (mut o, mut n) = (0, 0);
+    for edit in &edits {
+        old_pos.push(o);
+        new_pos.push(n);
+        match edit {
+            Edit::Equal => {
+                o += 1;
+                n += 1;
+            }
+            Edit::Delete => o += 1,
+            Edit::Insert => n += 1,
+        }
+    }
+    old_pos.push(o);
+    new_pos.push(n);
+
+    let mut output = format!("--- a/{path}\n+++ b/{path}\n");
+    for (start, end) in hunks(&edits) {
+        let old_len = old_pos[end] - old_pos[start];
+        let new_len = new_pos[end] - new_pos[start];
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk_start(old_pos[start], old_len),
+            old_len,
+            hunk_start(new_pos[start], new_len),
+            new_len
+        ));
+        for i in start..end {
+            let (marker, line) = match edits[i] {
+                Edit::Equal => (' ', old_lines[old_pos[i]]),
+                Edit::Delete => ('-', old_lines[old_pos[i]]),
+                Edit::Insert => ('+', new_lines[new_pos[i]]),
+            };
+            output.push(marker);
+            output.push_str(line);
+            if !line.ends_with('\n') {
+                output.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    output
+}
+
+/// Unified diff headers name the line before an empty range.
+fn hunk_start(consumed: usize, len: usize) -> usize {
+    if len == 0 { consumed } else { consumed + 1 }
+}
+
+/// Ranges of `edits` to print, each a run of changes padded with context and
+/// merged with its neighbour when their context would overlap.
+fn hunks(edits: &[Edit]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| **edit != Edit::Equal)
+    {
+        let start = i.saturating_sub(CONTEXT);
+        let end = (i + 1 + CONTEXT).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    hunks
+}
+
+/// Shortest edit script between two line sequences (Myers' O(ND) algorithm).
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let offset = n + m + 1;
+    let index = |k: isize| (k + offset) as usize;
+    let mut v = vec![0isize; (2 * offset + 1) as usize];
+    let mut trace = Vec::new();
+
+    'search: for d in 0..=(n + m) {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+                v[index(k + 1)]
+            } else {
+                v[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[index(prev_k)];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            edits.push(if x == prev_x {
+                Edit::Insert
+            } else {
+                Edit::Delete
+            });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.reverse();
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_content_has_no_diff() {
+        assert_eq!(unified_diff("a.md", "# A\n", "# A\n"), "");
+        assert_eq!(unified_diff("a.md", "", ""), "");
+    }
+
+    #[test]
+    fn test_changed_line_with_context() {
+        let old = "1\n2\n3\n4\nold\n6\n7\n8\n9\n";
+        let new = "1\n2\n3\n4\nnew\n6\n7\n8\n9\n";
+        assert_eq!(
+            unified_diff("doc.md", old, new),
+            "--- a/doc.md\n+++ b/doc.md\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-old\n+new\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn test_distant_changes_get_separate_hunks() {
+        let old: String = (1..=20).map(|i| format!("{i}\n")).collect();
+        let new = old
+            .replacen("2\n", "two\n", 1)
+            .replacen("19\n", "nineteen\n", 1);
+        let diff = unified_diff("doc.md", &old, &new);
+        assert_eq!(diff.matches("@@ -").count(), 2, "{diff}");
+        assert!(diff.contains("@@ -1,5 +1,5 @@"), "{diff}");
+        assert!(diff.contains("@@ -16,5 +16,5 @@"), "{diff}");
+    }
+
+    #[test]
+    fn test_inserted_and_deleted_lines() {
+        let diff = unified_diff("doc.md", "a\n\n\nb\n", "a\n\nb\nc\n");
+        assert_eq!(
+            diff,
+            "--- a/doc.md\n+++ b/doc.md\n@@ -1,4 +1,4 @@\n a\n \n-\n b\n+c\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_final_newline_is_marked() {
+        let diff = unified_diff("doc.md", "# A", "# A\n");
+        assert_eq!(
+            diff,
+            "--- a/doc.md\n+++ b/doc.md\n@@ -1,1 +1,1 @@\n-# A\n\\ No newline at end of file\n+# A\n"
+        );
+    }
+}
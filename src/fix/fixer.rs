@@ -43,7 +43,11 @@ impl Fixer {
                 std::cmp::Ordering::Equal => {
                     // If same line, sort by column (reverse)
                     match (&b.column_start, &a.column_start) {
-                        (Some(bc), Some(ac)) => bc.cmp(ac),
+                        // At the same column, an insertion goes in after the edit
+                        // there so the inserted text ends up in front of it.
+                        (Some(bc), Some(ac)) => bc
+                            .cmp(ac)
+                            .then_with(|| is_insertion(a).cmp(&is_insertion(b))),
                         _ => std::cmp::Ordering::Equal,
                     }
                 }
@@ -51,6 +55,35 @@ impl Fixer {
             }
         });
 
+        // Rules may propose the very same edit; apply it once rather than
+        // rejecting it as an overlap with itself.
+        sorted_fixes.dedup_by(|a, b| same_edit(a, b));
+
+        // A fix confined to lines another fix deletes (e.g. trailing spaces on an
+        // excess blank line) has nothing left to change.
+        let deletions: Vec<Fix> = sorted_fixes
+            .iter()
+            .filter(|fix| is_line_deletion(fix))
+            .cloned()
+            .collect();
+        sorted_fixes.retain(|fix| {
+            is_line_deletion(fix)
+                || !deletions.iter().any(|deletion| {
+                    deletion.line_start <= fix.line_start && fix.line_end <= deletion.line_end
+                })
+        });
+
+        // Likewise a fix inside a larger one (e.g. a tab in an indented code block
+        // MD046 rewrites as fenced) would be overwritten by it; anything the larger
+        // fix leaves behind is reported again on the next run.
+        let ranged: Vec<Fix> = sorted_fixes
+            .iter()
+            .filter(|fix| !is_insertion(fix))
+            .cloned()
+            .collect();
+        sorted_fixes
+            .retain(|fix| is_insertion(fix) || !ranged.iter().any(|outer| supersedes(outer, fix)));
+
         // Check for overlapping fixes
         if has_overlaps(&sorted_fixes) {
             return Err(MarkdownlintError::Fix(
@@ -109,6 +142,18 @@ fn detect_line_ending(content: &str) -> &str {
     }
 }
 
+/// Whether two fixes make the identical change.
+fn same_edit(a: &Fix, b: &Fix) -> bool {
+    (a.line_start, a.line_end, a.column_start, a.column_end)
+        == (b.line_start, b.line_end, b.column_start, b.column_end)
+        && a.replacement == b.replacement
+}
+
+/// Whether a fix removes whole lines outright.
+fn is_line_deletion(fix: &Fix) -> bool {
+    fix.replacement.is_empty() && fix.column_start.is_none() && fix.column_end.is_none()
+}
+
 /// Check if any fixes overlap
 fn has_overlaps(fixes: &[Fix]) -> bool {
     for i in 0..fixes.len() {
@@ -121,30 +166,39 @@ fn has_overlaps(fixes: &[Fix]) -> bool {
     false
 }
 
-/// Check if two fixes overlap
-fn fixes_overlap(a: &Fix, b: &Fix) -> bool {
-    // If fixes are on different lines and don't span, they don't overlap
-    if a.line_end < b.line_start || b.line_end < a.line_start {
-        return false;
+/// Whether a fix inserts text without replacing any (`column_end` just before
+/// `column_start`).
+fn is_insertion(fix: &Fix) -> bool {
+    matches!((fix.column_start, fix.column_end), (Some(start), Some(end)) if end < start)
+}
+
+/// The `(line, column)` positions a fix covers, inclusive. A fix without columns
+/// covers its lines entirely.
+fn fix_span(fix: &Fix) -> ((usize, usize), (usize, usize)) {
+    match (fix.column_start, fix.column_end) {
+        (Some(start), Some(end)) => ((fix.line_start, start), (fix.line_end, end)),
+        _ => ((fix.line_start, 1), (fix.line_end, usize::MAX)),
     }
+}
 
-    // If they share any lines, check column overlap
-    if a.line_start == b.line_start && a.line_end == b.line_end {
-        match (
-            &a.column_start,
-            &a.column_end,
-            &b.column_start,
-            &b.column_end,
-        ) {
-            (Some(a_start), Some(a_end), Some(b_start), Some(b_end)) => {
-                // Check column overlap
-                !(a_end < b_start || b_end < a_start)
-            }
-            _ => true, // If columns not specified, assume overlap
-        }
-    } else {
-        true // Multi-line fixes that touch same lines overlap
+/// Whether `outer` makes `inner` moot: it covers every position `inner` does and
+/// more, or the very same ones but removes them (e.g. MD009 deleting a trailing tab
+/// that MD010 would expand).
+fn supersedes(outer: &Fix, inner: &Fix) -> bool {
+    let (outer_start, outer_end) = fix_span(outer);
+    let (inner_start, inner_end) = fix_span(inner);
+    if !(outer_start <= inner_start && inner_end <= outer_end) {
+        return false;
     }
+    (outer_start, outer_end) != (inner_start, inner_end)
+        || (outer.replacement.is_empty() && !inner.replacement.is_empty())
+}
+
+/// Check if two fixes overlap
+fn fixes_overlap(a: &Fix, b: &Fix) -> bool {
+    let (a_start, a_end) = fix_span(a);
+    let (b_start, b_end) = fix_span(b);
+    !(a_end < b_start || b_end < a_start)
 }
 
 /// Apply a single fix to the lines
@@ -174,7 +228,8 @@ fn apply_single_fix(lines: &mut Vec<String>, fix: &Fix) -> Result<()> {
         let line = &lines[start_line];
         let chars: Vec<char> = line.chars().collect();
 
-        if col_start > chars.len() || col_end > chars.len() {
+        // `col_start` may sit one past the end for an insertion at end of line.
+        if col_start > chars.len() + 1 || col_end > chars.len() {
             return Err(MarkdownlintError::Fix(format!(
                 "Fix column range {}..{} out of bounds for line length {}",
                 col_start,
@@ -190,6 +245,27 @@ fn apply_single_fix(lines: &mut Vec<String>, fix: &Fix) -> Result<()> {
         return Ok(());
     }
 
+    // A column range spanning lines runs from `col_start` on the first line to
+    // `col_end` on the last.
+    if let (Some(col_start), Some(col_end)) = (fix.column_start, fix.column_end) {
+        let first: Vec<char> = lines[start_line].chars().collect();
+        let last: Vec<char> = lines[end_line].chars().collect();
+        if col_start > first.len() + 1 || col_end > last.len() {
+            return Err(MarkdownlintError::Fix(format!(
+                "Fix column range {}..{} out of bounds for lines {}..{}",
+                col_start, col_end, fix.line_start, fix.line_end
+            )));
+        }
+        let before: String = first[..col_start.saturating_sub(1)].iter().collect();
+        let after: String = last[col_end..].iter().collect();
+        let joined = format!("{}{}{}", before, fix.replacement, after);
+        lines.splice(
+            start_line..=end_line,
+            joined.split('\n').map(str::to_string),
+        );
+        return Ok(());
+    }
+
     // Handle line-based fixes (replace entire lines)
     if start_line == end_line {
         if fix.replacement.is_empty() && fix.column_start.is_none() {
@@ -243,6 +319,23 @@ mod tests {
         assert_eq!(result, "line 1\nREPLACED\nline 3");
     }
 
+    #[test]
+    fn test_apply_insertion_at_end_of_line() {
+        let fix = Fix {
+            line_start: 1,
+            line_end: 1,
+            column_start: Some(6),
+            column_end: Some(5),
+            replacement: "!".to_string(),
+            description: "Test".to_string(),
+        };
+
+        let result = Fixer::new()
+            .apply_fixes_to_content("hello\nworld", &[fix])
+            .unwrap();
+        assert_eq!(result, "hello!\nworld");
+    }
+
     #[test]
     fn test_apply_column_fix() {
         let content = "hello world";
@@ -303,4 +396,208 @@ mod tests {
         let result = fixer.apply_fixes_to_content(content, &[fix]).unwrap();
         assert_eq!(result, "line 1\r\nFIXED\r\nline 3");
     }
+
+    #[test]
+    fn test_insertion_lands_before_edit_at_same_column() {
+        // A blank line inserted before a heading whose indentation is also removed.
+        let content = "Text\n  # Heading\n";
+        let fixes = vec![
+            Fix {
+                line_start: 2,
+                line_end: 2,
+                column_start: Some(1),
+                column_end: Some(0),
+                replacement: "\n".to_string(),
+                description: "Add blank line before heading".to_string(),
+            },
+            Fix {
+                line_start: 2,
+                line_end: 2,
+                column_start: Some(1),
+                column_end: Some(2),
+                replacement: String::new(),
+                description: "Remove indentation".to_string(),
+            },
+        ];
+
+        let fixer = Fixer::new();
+        let result = fixer.apply_fixes_to_content(content, &fixes).unwrap();
+        assert_eq!(result, "Text\n\n# Heading\n");
+    }
+
+    #[test]
+    fn test_column_range_spanning_lines() {
+        // Join a setext heading's text and underline, keeping the underline's
+        // trailing space for another fix on that line.
+        let content = "Title\n===== \nText\n";
+        let fixes = vec![
+            Fix {
+                line_start: 1,
+                line_end: 2,
+                column_start: Some(1),
+                column_end: Some(5),
+                replacement: "# Title".to_string(),
+                description: "Convert heading to Atx style".to_string(),
+            },
+            Fix {
+                line_start: 2,
+                line_end: 2,
+                column_start: Some(6),
+                column_end: Some(6),
+                replacement: String::new(),
+                description: "Remove trailing spaces".to_string(),
+            },
+        ];
+
+        let fixer = Fixer::new();
+        let result = fixer.apply_fixes_to_content(content, &fixes).unwrap();
+        assert_eq!(result, "# Title\nText\n");
+    }
+
+    #[test]
+    fn test_column_fixes_on_same_line() {
+        // A tab replacement and a trailing-space removal on one line.
+        let content = "a\tb   \nnext";
+        let fixes = vec![
+            Fix {
+                line_start: 1,
+                line_end: 1,
+                column_start: Some(4),
+                column_end: Some(6),
+                replacement: String::new(),
+                description: "Remove trailing spaces".to_string(),
+            },
+            Fix {
+                line_start: 1,
+                line_end: 1,
+                column_start: Some(2),
+                column_end: Some(2),
+                replacement: "    ".to_string(),
+                description: "Replace tabs with spaces".to_string(),
+            },
+        ];
+
+        let fixer = Fixer::new();
+        let result = fixer.apply_fixes_to_content(content, &fixes).unwrap();
+        assert_eq!(result, "a    b\nnext");
+    }
+
+    #[test]
+    fn test_identical_fixes_applied_once() {
+        let fix = Fix {
+            line_start: 1,
+            line_end: 1,
+            column_start: Some(1),
+            column_end: Some(1),
+            replacement: "#".to_string(),
+            description: "Test".to_string(),
+        };
+
+        let fixer = Fixer::new();
+        let result = fixer
+            .apply_fixes_to_content("x heading", &[fix.clone(), fix])
+            .unwrap();
+        assert_eq!(result, "# heading");
+    }
+
+    #[test]
+    fn test_overlapping_column_fixes_rejected() {
+        let fix = |start, end, replacement: &str| Fix {
+            line_start: 1,
+            line_end: 1,
+            column_start: Some(start),
+            column_end: Some(end),
+            replacement: replacement.to_string(),
+            description: "Test".to_string(),
+        };
+
+        let fixer = Fixer::new();
+        let result =
+            fixer.apply_fixes_to_content("hello world", &[fix(1, 5, "hi"), fix(5, 7, "o_w")]);
+        assert!(result.is_err());
+    }
+
+    fn delete_line(line: usize) -> Fix {
+        Fix {
+            line_start: line,
+            line_end: line,
+            column_start: None,
+            column_end: None,
+            replacement: String::new(),
+            description: "Remove excess blank line".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_blank_line_deletions_with_unrelated_fix() {
+        // Three blank lines collapse to one while a later line is also fixed.
+        let content = "A\n\n\n\nB   \nC\n";
+        let trim = Fix {
+            line_start: 5,
+            line_end: 5,
+            column_start: Some(2),
+            column_end: Some(4),
+            replacement: String::new(),
+            description: "Remove trailing spaces".to_string(),
+        };
+        let fixes = [delete_line(3), delete_line(4), trim];
+
+        let result = Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap();
+        assert_eq!(result, "A\n\nB\nC\n");
+    }
+
+    #[test]
+    fn test_fix_on_deleted_line_is_dropped() {
+        let content = "A\n\n   \n\nB\n";
+        let trim = Fix {
+            line_start: 3,
+            line_end: 3,
+            column_start: Some(1),
+            column_end: Some(3),
+            replacement: String::new(),
+            description: "Remove trailing spaces".to_string(),
+        };
+        let fixes = [trim, delete_line(3), delete_line(4)];
+
+        let result = Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap();
+        assert_eq!(result, "A\n\nB\n");
+    }
+
+    #[test]
+    fn test_fix_inside_larger_fix_is_dropped() {
+        let content = "A\n\n\tB  \n";
+        let fence = Fix {
+            line_start: 3,
+            line_end: 3,
+            column_start: None,
+            column_end: None,
+            replacement: "```\nB  \n```".to_string(),
+            description: "Convert indented code block to fenced".to_string(),
+        };
+        let tab = Fix {
+            line_start: 3,
+            line_end: 3,
+            column_start: Some(1),
+            column_end: Some(1),
+            replacement: "    ".to_string(),
+            description: "Replace hard tab".to_string(),
+        };
+        let trim = Fix {
+            line_start: 3,
+            line_end: 3,
+            column_start: Some(3),
+            column_end: Some(4),
+            replacement: String::new(),
+            description: "Remove trailing spaces".to_string(),
+        };
+
+        let result = Fixer::new()
+            .apply_fixes_to_content(content, &[tab, fence, trim])
+            .unwrap();
+        assert_eq!(result, "A\n\n```\nB  \n```\n");
+    }
 }
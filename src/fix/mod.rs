@@ -1,3 +1,5 @@
+mod diff;
 mod fixer;
 
+pub use diff::unified_diff;
 pub use fixer::Fixer;
@@ -0,0 +1,83 @@
+use crate::format::Formatter;
+use crate::lint::LintResult;
+
+/// One `path:line:column: RULE message` line per violation, suitable for editor
+/// quickfix lists. No per-file headers or summary.
+pub struct ConciseFormatter;
+
+impl Default for ConciseFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConciseFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Formatter for ConciseFormatter {
+    fn format(&self, result: &LintResult) -> String {
+        let mut output = String::new();
+        for file_result in &result.file_results {
+            let path = file_result.path.display();
+            for violation in &file_result.violations {
+                let location = match violation.column {
+                    Some(col) => format!("{}:{}:{}", path, violation.line, col),
+                    None => format!("{}:{}", path, violation.line),
+                };
+                output.push_str(&format!(
+                    "{}: {} {}\n",
+                    location, violation.rule, violation.message
+                ));
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Violation;
+    use std::path::PathBuf;
+
+    fn format_single(column: Option<usize>) -> String {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("docs/test.md"),
+            vec![Violation {
+                line: 3,
+                column,
+                end_column: None,
+                rule: "MD009".to_string(),
+                message: "Trailing spaces".to_string(),
+                fix: None,
+            }],
+            vec![],
+        );
+        ConciseFormatter::new().format(&result)
+    }
+
+    #[test]
+    fn test_violation_with_column() {
+        assert_eq!(
+            format_single(Some(7)),
+            "docs/test.md:3:7: MD009 Trailing spaces\n"
+        );
+    }
+
+    #[test]
+    fn test_violation_without_column() {
+        assert_eq!(
+            format_single(None),
+            "docs/test.md:3: MD009 Trailing spaces\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_result() {
+        assert_eq!(ConciseFormatter::new().format(&LintResult::new()), "");
+    }
+}
@@ -1,17 +1,26 @@
-use crate::format::Formatter;
+use crate::config::Severity;
+use crate::format::{Formatter, SeveritiesFn, all_errors, boxed_severities};
 use crate::lint::LintResult;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
 
-pub struct DefaultFormatter {
+pub struct DefaultFormatter<'a> {
     use_color: bool,
     /// Show the offending source line and column indicator under each violation.
     show_context: bool,
+    /// Follow the summary with violation counts per rule.
+    show_stats: bool,
+    severities: SeveritiesFn<'a>,
 }
 
-impl DefaultFormatter {
+impl<'a> DefaultFormatter<'a> {
     pub fn new(use_color: bool) -> Self {
         Self {
             use_color,
             show_context: true,
+            show_stats: false,
+            severities: all_errors(),
         }
     }
 
@@ -19,9 +28,43 @@ impl DefaultFormatter {
         Self {
             use_color,
             show_context: false,
+            show_stats: false,
+            severities: all_errors(),
         }
     }
 
+    pub fn with_stats(mut self, show_stats: bool) -> Self {
+        self.show_stats = show_stats;
+        self
+    }
+
+    /// Count warnings apart from errors in the summary, with `severities` giving a
+    /// file's rule severity lookup; without this every violation is an error.
+    pub fn with_severity<F>(mut self, severities: impl Fn(&Path) -> F + 'a) -> Self
+    where
+        F: Fn(&str) -> Severity + 'a,
+    {
+        self.severities = boxed_severities(severities);
+        self
+    }
+
+    /// Total violations reported as errors and as warnings.
+    fn count_by_severity(&self, result: &LintResult) -> (usize, usize) {
+        let warnings: usize = result
+            .file_results
+            .iter()
+            .map(|file_result| {
+                let severity = (self.severities)(&file_result.path);
+                file_result
+                    .violations
+                    .iter()
+                    .filter(|v| severity(&v.rule) == Severity::Warning)
+                    .count()
+            })
+            .sum();
+        (result.total_errors - warnings, warnings)
+    }
+
     fn colorize(&self, text: &str, color_code: &str) -> String {
         if self.use_color {
             format!("\x1b[{}m{}\x1b[0m", color_code, text)
@@ -43,10 +86,15 @@ impl DefaultFormatter {
     }
 }
 
-impl Formatter for DefaultFormatter {
+impl Formatter for DefaultFormatter<'_> {
     fn format(&self, result: &LintResult) -> String {
-        let mut output = String::new();
+        let mut output = Vec::new();
+        self.format_to(result, &mut output)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(output).expect("formatter output is UTF-8")
+    }
 
+    fn format_to(&self, result: &LintResult, writer: &mut dyn Write) -> io::Result<()> {
         // Output violations by file
         for file_result in &result.file_results {
             if file_result.violations.is_empty() {
@@ -55,7 +103,7 @@ impl Formatter for DefaultFormatter {
 
             // File path header
             let path_display = file_result.path.display();
-            output.push_str(&format!("{}\n", self.yellow(&path_display.to_string())));
+            writeln!(writer, "{}", self.yellow(&path_display.to_string()))?;
 
             // Each violation
             for violation in &file_result.violations {
@@ -65,29 +113,30 @@ impl Formatter for DefaultFormatter {
                     format!("{}", violation.line)
                 };
 
-                output.push_str(&format!(
-                    "  {}: {} {}\n",
+                writeln!(
+                    writer,
+                    "  {}: {} {}",
                     self.gray(&location),
                     self.red(&violation.rule),
                     violation.message
-                ));
+                )?;
 
                 // Source snippet
                 if self.show_context {
                     let line_idx = violation.line.saturating_sub(1);
                     if let Some(src) = file_result.source_lines.get(line_idx) {
                         let src_trimmed = src.trim_end();
-                        output.push_str(&format!("       | {}\n", src_trimmed));
+                        writeln!(writer, "       | {}", src_trimmed)?;
                         if let Some(col) = violation.column {
                             // Point at the column with a caret (col is 1-indexed)
                             let spaces = " ".repeat(col.saturating_sub(1));
-                            output.push_str(&format!("       | {}{}\n", spaces, self.red("^")));
+                            writeln!(writer, "       | {}{}", spaces, self.red("^"))?;
                         }
                     }
                 }
             }
 
-            output.push('\n');
+            writeln!(writer)?;
         }
 
         // Summary line
@@ -95,16 +144,30 @@ impl Formatter for DefaultFormatter {
         let total = result.total_files_checked;
         if result.total_errors == 0 {
             let msg = format!("Checked {} file(s), no errors found.", total);
-            output.push_str(&format!("{}\n", self.gray(&msg)));
+            writeln!(writer, "{}", self.gray(&msg))?;
         } else {
+            let (errors, warnings) = self.count_by_severity(result);
+            let found = match (errors, warnings) {
+                (errors, 0) => format!("{} error(s)", errors),
+                (0, warnings) => format!("{} warning(s)", warnings),
+                (errors, warnings) => format!("{} error(s) and {} warning(s)", errors, warnings),
+            };
             let summary = format!(
-                "Found {} error(s) in {} file(s) ({} checked)",
-                result.total_errors, files_with_errors, total
+                "Checked {} file(s), found {} in {} file(s)",
+                total, found, files_with_errors
             );
-            output.push_str(&format!("{}\n", self.red(&summary)));
+            let summary = if errors == 0 {
+                self.yellow(&summary)
+            } else {
+                self.red(&summary)
+            };
+            writeln!(writer, "{}", summary)?;
+            if self.show_stats {
+                writeln!(writer, "{}", self.gray(&rule_stats(result)))?;
+            }
         }
 
-        output
+        Ok(())
     }
 
     fn supports_color(&self) -> bool {
@@ -112,6 +175,21 @@ impl Formatter for DefaultFormatter {
     }
 }
 
+/// Violation counts per rule, most frequent first, e.g. "MD013: 12, MD009: 5".
+fn rule_stats(result: &LintResult) -> String {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for violation in result.file_results.iter().flat_map(|f| &f.violations) {
+        *counts.entry(violation.rule.as_str()).or_insert(0) += 1;
+    }
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    counts
+        .iter()
+        .map(|(rule, count)| format!("{rule}: {count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +209,7 @@ mod tests {
         Violation {
             line,
             column: col,
+            end_column: None,
             rule: rule.to_string(),
             message: msg.to_string(),
             fix: None,
@@ -156,7 +235,7 @@ mod tests {
         assert!(output.contains("5:10"));
         assert!(output.contains("MD001"));
         assert!(output.contains("Heading levels"));
-        assert!(output.contains("Found 1 error(s)"));
+        assert!(output.contains("found 1 error(s) in 1 file(s)"));
     }
 
     #[test]
@@ -176,10 +255,44 @@ mod tests {
             vec![make_violation(3, Some(5), "MD003", "Third error")],
             vec![],
         );
+        result.record_clean_file();
         let output = formatter.format(&result);
         assert!(output.contains("file1.md"));
         assert!(output.contains("file2.md"));
-        assert!(output.contains("Found 3 error(s) in 2 file(s)"));
+        assert!(output.contains("Checked 3 file(s), found 3 error(s) in 2 file(s)"));
+    }
+
+    #[test]
+    fn test_warnings_counted_apart_from_errors() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![
+                make_violation(1, Some(1), "MD001", "First"),
+                make_violation(2, Some(1), "MD013", "Second"),
+            ],
+            vec![],
+        );
+
+        let only_md013 = DefaultFormatter::without_context(false).with_severity(|_: &Path| {
+            |rule: &str| match rule {
+                "MD013" => Severity::Warning,
+                _ => Severity::Error,
+            }
+        });
+        let output = only_md013.format(&result);
+        assert!(
+            output.contains("found 1 error(s) and 1 warning(s) in 1 file(s)"),
+            "{output}"
+        );
+
+        let all_warnings = DefaultFormatter::without_context(false)
+            .with_severity(|_: &Path| |_: &str| Severity::Warning);
+        let output = all_warnings.format(&result);
+        assert!(
+            output.contains("found 2 warning(s) in 1 file(s)"),
+            "{output}"
+        );
     }
 
     #[test]
@@ -216,4 +329,51 @@ mod tests {
         );
         assert!(output.contains('^'), "caret should appear under the column");
     }
+
+    #[test]
+    fn test_format_to_matches_format() {
+        let formatter = DefaultFormatter::new(true);
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![
+                make_violation(1, Some(3), "MD009", "Trailing spaces"),
+                make_violation(2, None, "MD041", "First line"),
+            ],
+            vec!["Hi   ".to_string(), "text".to_string()],
+        );
+
+        let mut streamed = Vec::new();
+        formatter.format_to(&result, &mut streamed).unwrap();
+        assert_eq!(streamed, formatter.format(&result).into_bytes());
+    }
+
+    #[test]
+    fn test_stats_lists_rule_counts() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("file1.md"),
+            vec![
+                make_violation(1, Some(1), "MD009", "Trailing spaces"),
+                make_violation(2, Some(1), "MD013", "Line length"),
+            ],
+            vec![],
+        );
+        result.add_file_result(
+            PathBuf::from("file2.md"),
+            vec![
+                make_violation(1, Some(1), "MD013", "Line length"),
+                make_violation(4, Some(1), "MD013", "Line length"),
+            ],
+            vec![],
+        );
+
+        let output = DefaultFormatter::without_context(false)
+            .with_stats(true)
+            .format(&result);
+        assert!(output.ends_with("MD013: 3, MD009: 1\n"), "{output}");
+
+        let terse = DefaultFormatter::without_context(false).format(&result);
+        assert!(!terse.contains("MD013: 3"), "{terse}");
+    }
 }
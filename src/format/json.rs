@@ -2,6 +2,11 @@ use crate::format::Formatter;
 use crate::lint::LintResult;
 use crate::types::FileResult;
 use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Bumped whenever the shape of the JSON output changes incompatibly.
+const SCHEMA_VERSION: u32 = 1;
 
 pub struct JsonFormatter {
     pretty: bool,
@@ -29,8 +34,50 @@ fn file_violations(file_result: &FileResult) -> Vec<JsonViolation> {
 
 #[derive(Serialize)]
 struct JsonOutput {
+    tool: JsonTool,
+    #[serde(rename = "schemaVersion")]
+    schema_version: u32,
     files: Vec<JsonFile>,
     total_errors: usize,
+    summary: JsonSummary,
+}
+
+#[derive(Serialize)]
+struct JsonTool {
+    name: &'static str,
+    version: &'static str,
+}
+
+const TOOL: JsonTool = JsonTool {
+    name: "mdlint",
+    version: env!("CARGO_PKG_VERSION"),
+};
+
+/// Aggregate counts across all files, so consumers needn't re-tally `files`.
+#[derive(Serialize)]
+struct JsonSummary {
+    /// Violation count per rule, keyed by rule name.
+    rules: BTreeMap<String, usize>,
+    fixable: usize,
+    /// Files linted, including those without violations.
+    files_checked: usize,
+    files_with_errors: usize,
+}
+
+fn summarize(result: &LintResult) -> JsonSummary {
+    let mut summary = JsonSummary {
+        rules: BTreeMap::new(),
+        fixable: 0,
+        files_checked: result.total_files_checked,
+        files_with_errors: result.file_results.len(),
+    };
+    for violation in result.file_results.iter().flat_map(|f| &f.violations) {
+        *summary.rules.entry(violation.rule.clone()).or_default() += 1;
+        if violation.fix.is_some() {
+            summary.fixable += 1;
+        }
+    }
+    summary
 }
 
 #[derive(Serialize)]
@@ -50,27 +97,55 @@ struct JsonViolation {
     fixable: Option<bool>,
 }
 
+fn json_file(file_result: &FileResult) -> JsonFile {
+    JsonFile {
+        path: file_result.path.display().to_string(),
+        violations: file_violations(file_result),
+    }
+}
+
 impl Formatter for JsonFormatter {
     fn format(&self, result: &LintResult) -> String {
+        if !self.pretty {
+            let mut output = Vec::new();
+            self.format_to(result, &mut output)
+                .expect("writing to a Vec cannot fail");
+            return String::from_utf8(output).expect("JSON output is UTF-8");
+        }
+
         let json_output = JsonOutput {
-            files: result
-                .file_results
-                .iter()
-                .map(|file_result| JsonFile {
-                    path: file_result.path.display().to_string(),
-                    violations: file_violations(file_result),
-                })
-                .collect(),
+            tool: TOOL,
+            schema_version: SCHEMA_VERSION,
+            files: result.file_results.iter().map(json_file).collect(),
             total_errors: result.total_errors,
+            summary: summarize(result),
         };
+        serde_json::to_string_pretty(&json_output)
+            .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize JSON: {}\"}}", e))
+    }
 
+    /// Compact output is streamed one file at a time and matches `format` byte for byte.
+    fn format_to(&self, result: &LintResult, writer: &mut dyn Write) -> io::Result<()> {
         if self.pretty {
-            serde_json::to_string_pretty(&json_output)
-                .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize JSON: {}\"}}", e))
-        } else {
-            serde_json::to_string(&json_output)
-                .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize JSON: {}\"}}", e))
+            return writer.write_all(self.format(result).as_bytes());
         }
+
+        writer.write_all(b"{\"tool\":")?;
+        serde_json::to_writer(&mut *writer, &TOOL)?;
+        write!(writer, ",\"schemaVersion\":{},\"files\":[", SCHEMA_VERSION)?;
+        for (index, file_result) in result.file_results.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut *writer, &json_file(file_result))?;
+        }
+        write!(
+            writer,
+            "],\"total_errors\":{},\"summary\":",
+            result.total_errors
+        )?;
+        serde_json::to_writer(&mut *writer, &summarize(result))?;
+        writer.write_all(b"}")
     }
 }
 
@@ -100,6 +175,7 @@ mod tests {
             vec![Violation {
                 line: 5,
                 column: Some(10),
+                end_column: None,
                 rule: "MD001".to_string(),
                 message: "Test message".to_string(),
                 fix: None,
@@ -126,6 +202,7 @@ mod tests {
             vec![Violation {
                 line: 1,
                 column: None,
+                end_column: None,
                 rule: "MD001".to_string(),
                 message: "Test".to_string(),
                 fix: None,
@@ -149,6 +226,7 @@ mod tests {
             vec![Violation {
                 line: 1,
                 column: Some(1),
+                end_column: None,
                 rule: "MD009".to_string(),
                 message: "Trailing spaces".to_string(),
                 fix: Some(crate::types::Fix {
@@ -167,4 +245,90 @@ mod tests {
 
         assert!(output.contains("\"fixable\":true"));
     }
+
+    #[test]
+    fn test_format_to_matches_serialized_output() {
+        let formatter = JsonFormatter::new(false);
+        let mut result = LintResult::new();
+        for path in ["a.md", "b \"quoted\".md"] {
+            result.add_file_result(
+                PathBuf::from(path),
+                vec![Violation {
+                    line: 2,
+                    column: Some(4),
+                    end_column: None,
+                    rule: "MD009".to_string(),
+                    message: "Trailing spaces".to_string(),
+                    fix: None,
+                }],
+                vec![],
+            );
+        }
+
+        let mut streamed = Vec::new();
+        formatter.format_to(&result, &mut streamed).unwrap();
+        let expected = serde_json::to_string(&JsonOutput {
+            tool: TOOL,
+            schema_version: SCHEMA_VERSION,
+            files: result.file_results.iter().map(json_file).collect(),
+            total_errors: result.total_errors,
+            summary: summarize(&result),
+        })
+        .unwrap();
+
+        assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+        assert_eq!(formatter.format(&result), expected);
+    }
+
+    #[test]
+    fn test_summary_counts() {
+        let violation = |line, rule: &str, fixable: bool| Violation {
+            line,
+            column: Some(1),
+            end_column: None,
+            rule: rule.to_string(),
+            message: "message".to_string(),
+            fix: fixable.then(|| crate::types::Fix {
+                line_start: line,
+                line_end: line,
+                column_start: None,
+                column_end: None,
+                replacement: String::new(),
+                description: "fix".to_string(),
+            }),
+        };
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("a.md"),
+            vec![violation(1, "MD041", false), violation(2, "MD009", true)],
+            vec![],
+        );
+        result.add_file_result(
+            PathBuf::from("b.md"),
+            vec![violation(4, "MD009", true)],
+            vec![],
+        );
+        result.record_clean_file();
+
+        let output = JsonFormatter::new(false).format(&result);
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["summary"]["rules"]["MD009"], 2);
+        assert_eq!(parsed["summary"]["rules"]["MD041"], 1);
+        assert_eq!(parsed["summary"]["fixable"], 2);
+        assert_eq!(parsed["summary"]["files_checked"], 3);
+        assert_eq!(parsed["summary"]["files_with_errors"], 2);
+        assert_eq!(parsed["total_errors"], 3);
+    }
+
+    #[test]
+    fn test_tool_and_schema_version_header() {
+        let output = JsonFormatter::new(true).format(&LintResult::new());
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["tool"]["name"], "mdlint");
+        assert_eq!(parsed["tool"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(parsed["schemaVersion"], SCHEMA_VERSION);
+        assert!(parsed["files"].is_array());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::format::Formatter;
 use crate::lint::LintResult;
+use crate::types::FileResult;
 
 pub struct JunitFormatter;
 
@@ -15,8 +16,159 @@ impl JunitFormatter {
     }
 }
 
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// One `<testsuite>` per file, with one failing `<testcase>` per violation so CI
+/// dashboards can show individual rule failures.
+fn write_test_suite(output: &mut String, file_result: &FileResult) {
+    let path = escape_xml(&file_result.path.display().to_string());
+    let count = file_result.violations.len();
+    output.push_str(&format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"0\">\n",
+        path, count, count
+    ));
+    for violation in &file_result.violations {
+        let rule = escape_xml(&violation.rule);
+        let message = escape_xml(&violation.message);
+        let location = match violation.column {
+            Some(col) => format!("{}:{}:{}", path, violation.line, col),
+            None => format!("{}:{}", path, violation.line),
+        };
+        output.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"{}:{}\" time=\"0\">\n",
+            path, rule, violation.line
+        ));
+        output.push_str(&format!(
+            "      <failure type=\"{}\" message=\"{}\">{} {} {}</failure>\n",
+            rule, message, location, rule, message
+        ));
+        output.push_str("    </testcase>\n");
+    }
+    output.push_str("  </testsuite>\n");
+}
+
 impl Formatter for JunitFormatter {
-    fn format(&self, _result: &LintResult) -> String {
-        todo!("Implement JUnit formatter")
+    fn format(&self, result: &LintResult) -> String {
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str(&format!(
+            "<testsuites name=\"mdlint\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"0\">\n",
+            result.total_errors, result.total_errors
+        ));
+
+        for file_result in &result.file_results {
+            write_test_suite(&mut output, file_result);
+        }
+
+        output.push_str("</testsuites>\n");
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Violation;
+    use std::path::PathBuf;
+
+    fn make_violation(line: usize, col: Option<usize>, rule: &str, msg: &str) -> Violation {
+        Violation {
+            line,
+            column: col,
+            end_column: None,
+            rule: rule.to_string(),
+            message: msg.to_string(),
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_empty_result() {
+        let output = JunitFormatter::new().format(&LintResult::new());
+
+        assert!(output.starts_with("<?xml"));
+        assert!(output.contains("<testsuites name=\"mdlint\" tests=\"0\" failures=\"0\""));
+        assert!(output.trim_end().ends_with("</testsuites>"));
+    }
+
+    #[test]
+    fn test_violations_become_failures() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![
+                make_violation(5, Some(10), "MD001", "Heading levels skipped"),
+                make_violation(7, None, "MD013", "Line length"),
+            ],
+            vec![],
+        );
+
+        let output = JunitFormatter::new().format(&result);
+
+        assert!(output.contains(
+            "<testsuite name=\"test.md\" tests=\"2\" failures=\"2\" errors=\"0\" time=\"0\">"
+        ));
+        assert!(output.contains("<testcase classname=\"test.md\" name=\"MD001:5\" time=\"0\">"));
+        assert!(output.contains("<testcase classname=\"test.md\" name=\"MD013:7\" time=\"0\">"));
+        assert!(output.contains("message=\"Heading levels skipped\""));
+        assert!(output.contains("test.md:5:10 MD001"));
+        assert!(output.contains("test.md:7 MD013"));
+    }
+
+    #[test]
+    fn test_failure_count_matches_violations() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("a.md"),
+            vec![
+                make_violation(1, Some(1), "MD041", "First line"),
+                make_violation(3, Some(4), "MD009", "Trailing spaces"),
+            ],
+            vec![],
+        );
+        result.add_file_result(
+            PathBuf::from("b.md"),
+            vec![make_violation(2, Some(1), "MD022", "Blank lines")],
+            vec![],
+        );
+
+        let output = JunitFormatter::new().format(&result);
+
+        // Every element is on its own line; tally opening tags and the declared totals.
+        let opening = |tag: &str| {
+            output
+                .lines()
+                .filter(|line| line.trim_start().starts_with(&format!("<{} ", tag)))
+                .count()
+        };
+        assert_eq!(opening("testsuite"), 2);
+        assert_eq!(opening("testcase"), 3);
+        assert_eq!(opening("failure"), result.total_errors);
+        assert!(output.contains("<testsuites name=\"mdlint\" tests=\"3\" failures=\"3\""));
+        assert_eq!(
+            output.matches("</testcase>").count(),
+            output.matches("<testcase ").count()
+        );
+    }
+
+    #[test]
+    fn test_escapes_special_characters() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("a&b.md"),
+            vec![make_violation(1, None, "MD033", "Inline HTML <div>")],
+            vec![],
+        );
+
+        let output = JunitFormatter::new().format(&result);
+
+        assert!(output.contains("a&amp;b.md"));
+        assert!(output.contains("Inline HTML &lt;div&gt;"));
+        assert!(!output.contains("<div>"));
     }
 }
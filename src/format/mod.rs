@@ -1,17 +1,77 @@
+mod concise;
 mod default;
 mod json;
 mod junit;
+mod ndjson;
 mod sarif;
 
+pub use concise::ConciseFormatter;
 pub use default::DefaultFormatter;
 pub use json::JsonFormatter;
 pub use junit::JunitFormatter;
+pub use ndjson::NdjsonFormatter;
 pub use sarif::SarifFormatter;
 
+use crate::config::Severity;
 use crate::lint::LintResult;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// A file's rule severity lookup, given its path, for formatters that tell errors
+/// from warnings.
+type SeveritiesFn<'a> = Box<dyn Fn(&Path) -> Box<dyn Fn(&str) -> Severity + 'a> + 'a>;
+
+/// Box a per-file severity lookup such as `|path| engine.severities(path)`.
+fn boxed_severities<'a, F>(severities: impl Fn(&Path) -> F + 'a) -> SeveritiesFn<'a>
+where
+    F: Fn(&str) -> Severity + 'a,
+{
+    Box::new(move |path| Box::new(severities(path)))
+}
+
+/// The lookup used until a formatter is given one: every rule is an error.
+fn all_errors<'a>() -> SeveritiesFn<'a> {
+    Box::new(|_| Box::new(|_| Severity::Error))
+}
+
+/// Which formatter `mdlint check` prints its report with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Default,
+    #[serde(alias = "simple")]
+    Concise,
+    Json,
+    Ndjson,
+    Junit,
+    Sarif,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Default => write!(f, "default"),
+            OutputFormat::Concise => write!(f, "concise"),
+            OutputFormat::Json => write!(f, "json"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+            OutputFormat::Junit => write!(f, "junit"),
+            OutputFormat::Sarif => write!(f, "sarif"),
+        }
+    }
+}
 
 pub trait Formatter {
     fn format(&self, result: &LintResult) -> String;
+
+    /// Write the formatted output to `writer`. Formatters that can emit output file by
+    /// file override this to avoid building the whole report in memory.
+    fn format_to(&self, result: &LintResult, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_all(self.format(result).as_bytes())
+    }
+
     fn supports_color(&self) -> bool {
         false
     }
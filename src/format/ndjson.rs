@@ -0,0 +1,116 @@
+use crate::format::Formatter;
+use crate::lint::LintResult;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Newline-delimited JSON: one object per violation, one per line, with no
+/// enclosing array. An empty result produces no output.
+pub struct NdjsonFormatter;
+
+impl Default for NdjsonFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NdjsonFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Serialize)]
+struct NdjsonRecord<'a> {
+    path: String,
+    line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+    rule: &'a str,
+    message: &'a str,
+    fixable: bool,
+}
+
+impl Formatter for NdjsonFormatter {
+    fn format(&self, result: &LintResult) -> String {
+        let mut output = Vec::new();
+        self.format_to(result, &mut output)
+            .expect("writing to a Vec cannot fail");
+        String::from_utf8(output).expect("JSON output is UTF-8")
+    }
+
+    fn format_to(&self, result: &LintResult, writer: &mut dyn Write) -> io::Result<()> {
+        for file_result in &result.file_results {
+            let path = file_result.path.display().to_string();
+            for violation in &file_result.violations {
+                let record = NdjsonRecord {
+                    path: path.clone(),
+                    line: violation.line,
+                    column: violation.column,
+                    rule: &violation.rule,
+                    message: &violation.message,
+                    fixable: violation.fix.is_some(),
+                };
+                serde_json::to_writer(&mut *writer, &record)?;
+                writer.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Violation;
+    use serde_json::Value;
+    use std::path::PathBuf;
+
+    fn make_violation(line: usize, col: Option<usize>, rule: &str, msg: &str) -> Violation {
+        Violation {
+            line,
+            column: col,
+            end_column: None,
+            rule: rule.to_string(),
+            message: msg.to_string(),
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_empty_result_produces_no_lines() {
+        assert_eq!(NdjsonFormatter::new().format(&LintResult::new()), "");
+    }
+
+    #[test]
+    fn test_one_line_per_violation() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("a.md"),
+            vec![
+                make_violation(1, Some(1), "MD041", "First line"),
+                make_violation(3, None, "MD012", "Multiple blank lines"),
+            ],
+            vec![],
+        );
+        result.add_file_result(
+            PathBuf::from("b.md"),
+            vec![make_violation(2, Some(5), "MD009", "Trailing \"spaces\"")],
+            vec![],
+        );
+
+        let output = NdjsonFormatter::new().format(&result);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), result.total_errors);
+
+        let records: Vec<Value> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records[0]["path"], "a.md");
+        assert_eq!(records[0]["column"], 1);
+        assert!(records[1].get("column").is_none());
+        assert_eq!(records[2]["rule"], "MD009");
+        assert_eq!(records[2]["message"], "Trailing \"spaces\"");
+        assert_eq!(records[2]["fixable"], false);
+    }
+}
@@ -1,22 +1,587 @@
-use crate::format::Formatter;
+use crate::config::Severity;
+use crate::format::{Formatter, SeveritiesFn, all_errors, boxed_severities};
 use crate::lint::LintResult;
+use crate::lint::rules::create_default_registry;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Component, Path, PathBuf};
 
-pub struct SarifFormatter;
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const RULE_DOCS_BASE: &str = "https://github.com/DavidAnson/markdownlint/blob/main/doc";
+/// Key for the fingerprint in `partialFingerprints`; bump the suffix if the inputs change.
+const FINGERPRINT_KEY: &str = "mdlint/v1";
+/// Base id that relative artifact URIs resolve against, as GitHub code scanning expects.
+const SRCROOT: &str = "%SRCROOT%";
 
-impl Default for SarifFormatter {
+pub struct SarifFormatter<'a> {
+    severities: SeveritiesFn<'a>,
+    root: Option<PathBuf>,
+}
+
+impl Default for SarifFormatter<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl SarifFormatter {
+impl<'a> SarifFormatter<'a> {
     pub fn new() -> Self {
-        Self
+        let root = std::env::current_dir().ok();
+        Self {
+            severities: all_errors(),
+            root: root.map(|root| root.canonicalize().unwrap_or(root)),
+        }
+    }
+
+    /// Report paths relative to `root` rather than the current directory.
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Files under the root get a relative URI against [`SRCROOT`]; anything else, such
+    /// as a path passed from outside the current directory, gets an absolute `file://` URI.
+    fn artifact_location(&self, path: &Path) -> SarifArtifactLocation {
+        let relative = if path.is_relative() {
+            Some(path)
+        } else {
+            self.root
+                .as_deref()
+                .and_then(|root| path.strip_prefix(root).ok())
+        };
+        let is_plain = |path: &Path| {
+            path.components()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        };
+        match relative {
+            Some(relative) if is_plain(relative) => SarifArtifactLocation {
+                uri: encode_uri_path(
+                    &relative
+                        .components()
+                        .filter(|component| *component != Component::CurDir)
+                        .map(|component| component.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                ),
+                uri_base_id: Some(SRCROOT),
+            },
+            _ => {
+                let absolute = match &self.root {
+                    Some(root) if path.is_relative() => root.join(path),
+                    _ => path.to_path_buf(),
+                };
+                SarifArtifactLocation {
+                    uri: file_uri(&absolute.canonicalize().unwrap_or(absolute)),
+                    uri_base_id: None,
+                }
+            }
+        }
+    }
+
+    /// Look up each result's `level` from the severity of its rule in its file, with
+    /// `severities` giving a file's lookup; without this every result is an error.
+    pub fn with_severity<F>(mut self, severities: impl Fn(&Path) -> F + 'a) -> Self
+    where
+        F: Fn(&str) -> Severity + 'a,
+    {
+        self.severities = boxed_severities(severities);
+        self
+    }
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    original_uri_base_ids: HashMap<&'static str, SarifArtifactLocation>,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    name: String,
+    short_description: SarifMessage,
+    help_uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    partial_fingerprints: HashMap<&'static str, String>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+    /// Exclusive, unlike `Violation::end_column`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+}
+
+/// Driver metadata for every rule that produced a result, sorted by id so that
+/// `ruleIndex` values are stable.
+fn triggered_rules(result: &LintResult) -> Vec<SarifRule> {
+    let registry = create_default_registry();
+    let ids: BTreeSet<&str> = result
+        .file_results
+        .iter()
+        .flat_map(|file_result| file_result.violations.iter())
+        .map(|violation| violation.rule.as_str())
+        .collect();
+
+    ids.into_iter()
+        .map(|id| {
+            let description = registry
+                .get(id)
+                .map(|rule| rule.description().to_string())
+                .unwrap_or_else(|| id.to_string());
+            SarifRule {
+                id: id.to_string(),
+                name: description.clone(),
+                short_description: SarifMessage { text: description },
+                help_uri: format!("{}/{}.md", RULE_DOCS_BASE, id.to_lowercase()),
+            }
+        })
+        .collect()
+}
+
+/// Percent-encode everything in a forward-slash path except unreserved characters,
+/// `/`, and the `:` of a Windows drive.
+fn encode_uri_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                encoded.push(char::from(byte))
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// `file://` URI for an absolute path, e.g. `file:///home/me/README.md` or
+/// `file:///C:/docs/README.md`.
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let path = encode_uri_path(&path);
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
+
+/// FNV-1a over the path, rule, message, and offending line's text. Line numbers are
+/// left out so unrelated edits above a violation don't change its fingerprint.
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+impl Formatter for SarifFormatter<'_> {
+    fn format(&self, result: &LintResult) -> String {
+        let rules = triggered_rules(result);
+        let rule_index: HashMap<&str, usize> = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| (rule.id.as_str(), index))
+            .collect();
+
+        let results = result
+            .file_results
+            .iter()
+            .flat_map(|file_result| {
+                let artifact_location = self.artifact_location(&file_result.path);
+                let rule_index = &rule_index;
+                let severity = (self.severities)(&file_result.path);
+                file_result.violations.iter().map(move |violation| {
+                    let line_text = file_result
+                        .source_lines
+                        .get(violation.line.wrapping_sub(1))
+                        .map_or("", |line| line.trim());
+                    SarifResult {
+                        rule_id: violation.rule.clone(),
+                        rule_index: rule_index[violation.rule.as_str()],
+                        level: sarif_level(severity(&violation.rule)),
+                        message: SarifMessage {
+                            text: violation.message.clone(),
+                        },
+                        locations: vec![SarifLocation {
+                            physical_location: SarifPhysicalLocation {
+                                artifact_location: SarifArtifactLocation {
+                                    uri: artifact_location.uri.clone(),
+                                    uri_base_id: artifact_location.uri_base_id,
+                                },
+                                region: SarifRegion {
+                                    start_line: violation.line,
+                                    start_column: violation.column,
+                                    end_column: violation.end_column.map(|column| column + 1),
+                                },
+                            },
+                        }],
+                        partial_fingerprints: HashMap::from([(
+                            FINGERPRINT_KEY,
+                            fingerprint(&[
+                                &artifact_location.uri,
+                                &violation.rule,
+                                &violation.message,
+                                line_text,
+                            ]),
+                        )]),
+                    }
+                })
+            })
+            .collect();
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "mdlint",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules,
+                    },
+                },
+                original_uri_base_ids: self
+                    .root
+                    .iter()
+                    .map(|root| {
+                        let uri = file_uri(root);
+                        let uri = if uri.ends_with('/') { uri } else { uri + "/" };
+                        (
+                            SRCROOT,
+                            SarifArtifactLocation {
+                                uri,
+                                uri_base_id: None,
+                            },
+                        )
+                    })
+                    .collect(),
+                results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log)
+            .unwrap_or_else(|e| format!("{{\"error\": \"Failed to serialize SARIF: {}\"}}", e))
     }
 }
 
-impl Formatter for SarifFormatter {
-    fn format(&self, _result: &LintResult) -> String {
-        todo!("Implement SARIF formatter")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Violation;
+    use serde_json::Value;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_empty_result() {
+        let output = SarifFormatter::new().format(&LintResult::new());
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(parsed["version"], "2.1.0");
+        assert_eq!(parsed["runs"][0]["tool"]["driver"]["name"], "mdlint");
+        assert_eq!(parsed["runs"][0]["results"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_single_violation() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("docs/test.md"),
+            vec![Violation {
+                line: 5,
+                column: Some(10),
+                end_column: None,
+                rule: "MD001".to_string(),
+                message: "Heading levels should increment by one".to_string(),
+                fix: None,
+            }],
+            vec![],
+        );
+
+        let output = SarifFormatter::new().format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let sarif_result = &parsed["runs"][0]["results"][0];
+
+        assert_eq!(sarif_result["ruleId"], "MD001");
+        assert_eq!(sarif_result["level"], "error");
+        assert_eq!(
+            sarif_result["message"]["text"],
+            "Heading levels should increment by one"
+        );
+        let location = &sarif_result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "docs/test.md");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 5);
+        assert_eq!(location["region"]["startColumn"], 10);
+    }
+
+    #[test]
+    fn test_uris_are_relative_to_root() {
+        let root = if cfg!(windows) {
+            PathBuf::from(r"C:\work")
+        } else {
+            PathBuf::from("/work")
+        };
+        let mut result = LintResult::new();
+        for path in [
+            root.join("docs").join("my notes.md"),
+            root.parent().unwrap().join("elsewhere.md"),
+        ] {
+            result.add_file_result(path, vec![Violation::new(1, Some(1), "MD041", "m")], vec![]);
+        }
+
+        let output = SarifFormatter::new().with_root(&root).format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let run = &parsed["runs"][0];
+        let base = &run["originalUriBaseIds"]["%SRCROOT%"]["uri"];
+        let uris: Vec<&Value> = run["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| &result["locations"][0]["physicalLocation"]["artifactLocation"])
+            .collect();
+
+        assert_eq!(uris[0]["uri"], "docs/my%20notes.md");
+        assert_eq!(uris[0]["uriBaseId"], "%SRCROOT%");
+        assert!(uris[1].get("uriBaseId").is_none());
+        if cfg!(windows) {
+            assert_eq!(base, "file:///C:/work/");
+            assert_eq!(uris[1]["uri"], "file:///C:/elsewhere.md");
+        } else {
+            assert_eq!(base, "file:///work/");
+            assert_eq!(uris[1]["uri"], "file:///elsewhere.md");
+        }
+    }
+
+    #[test]
+    fn test_level_follows_severity() {
+        let violation = |rule: &str| Violation::new(1, Some(1), rule, "message");
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![violation("MD013"), violation("MD009")],
+            vec![],
+        );
+
+        let formatter = SarifFormatter::new().with_severity(|_| {
+            |rule: &str| {
+                if rule == "MD013" {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                }
+            }
+        });
+        let output = formatter.format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let level = |rule: &str| {
+            parsed["runs"][0]["results"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|result| result["ruleId"] == rule)
+                .map(|result| result["level"].clone())
+                .unwrap()
+        };
+
+        assert_eq!(level("MD013"), "warning");
+        assert_eq!(level("MD009"), "error");
+    }
+
+    #[test]
+    fn test_missing_column_omitted() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![Violation {
+                line: 1,
+                column: None,
+                end_column: None,
+                rule: "MD041".to_string(),
+                message: "First line should be a top-level heading".to_string(),
+                fix: None,
+            }],
+            vec![],
+        );
+
+        let output = SarifFormatter::new().format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let region = &parsed["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"];
+
+        assert_eq!(region["startLine"], 1);
+        assert!(region.get("startColumn").is_none());
+        assert!(region.get("endColumn").is_none());
+    }
+
+    #[test]
+    fn test_end_column_is_exclusive() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![Violation {
+                line: 1,
+                column: Some(81),
+                end_column: Some(95),
+                rule: "MD013".to_string(),
+                message: "Line exceeds maximum length (95 > 80)".to_string(),
+                fix: None,
+            }],
+            vec![],
+        );
+
+        let output = SarifFormatter::new().format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let region = &parsed["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["region"];
+
+        assert_eq!(region["startColumn"], 81);
+        assert_eq!(region["endColumn"], 96);
+    }
+
+    #[test]
+    fn test_rules_metadata_matches_rule_index() {
+        let violation = |line, rule: &str, message: &str| Violation {
+            line,
+            column: Some(1),
+            end_column: None,
+            rule: rule.to_string(),
+            message: message.to_string(),
+            fix: None,
+        };
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("test.md"),
+            vec![
+                violation(1, "MD041", "First line should be a top-level heading"),
+                violation(2, "MD009", "Trailing spaces (3 spaces)"),
+                violation(3, "MD009", "Trailing spaces (4 spaces)"),
+            ],
+            vec!["Intro".to_string(), "a   ".to_string(), "b    ".to_string()],
+        );
+
+        let output = SarifFormatter::new().format(&result);
+        let parsed: Value = serde_json::from_str(&output).unwrap();
+        let rules = parsed["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[1]["id"], "MD041");
+        assert_eq!(
+            rules[1]["name"],
+            "First line in file should be a top-level heading"
+        );
+        assert_eq!(
+            rules[1]["helpUri"],
+            "https://github.com/DavidAnson/markdownlint/blob/main/doc/md041.md"
+        );
+
+        for sarif_result in parsed["runs"][0]["results"].as_array().unwrap() {
+            let index = sarif_result["ruleIndex"].as_u64().unwrap() as usize;
+            assert_eq!(rules[index]["id"], sarif_result["ruleId"]);
+            assert!(sarif_result["partialFingerprints"]["mdlint/v1"].is_string());
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_number() {
+        let format_at = |line: usize| {
+            let mut source = vec![String::new(); line - 1];
+            source.push("text   ".to_string());
+            let mut result = LintResult::new();
+            result.add_file_result(
+                PathBuf::from("test.md"),
+                vec![Violation {
+                    line,
+                    column: Some(5),
+                    end_column: None,
+                    rule: "MD009".to_string(),
+                    message: "Trailing spaces (3 spaces)".to_string(),
+                    fix: None,
+                }],
+                source,
+            );
+            let output = SarifFormatter::new().format(&result);
+            let parsed: Value = serde_json::from_str(&output).unwrap();
+            parsed["runs"][0]["results"][0]["partialFingerprints"]["mdlint/v1"].clone()
+        };
+
+        assert_eq!(format_at(2), format_at(7));
     }
 }
@@ -337,16 +337,14 @@ impl FormatterState {
                     }
                 }
             }
-            TagEnd::Item => {
-                // Tight list item: the content was never wrapped in Paragraph.
-                if self.in_tight_item {
-                    let text = std::mem::take(&mut self.inline);
-                    if !text.is_empty() {
-                        let prefix = "  ".repeat(self.list_depth);
-                        self.flush_inline_text(&text, &prefix);
-                    }
-                    self.in_tight_item = false;
+            // Tight list item: the content was never wrapped in Paragraph.
+            TagEnd::Item if self.in_tight_item => {
+                let text = std::mem::take(&mut self.inline);
+                if !text.is_empty() {
+                    let prefix = "  ".repeat(self.list_depth);
+                    self.flush_inline_text(&text, &prefix);
                 }
+                self.in_tight_item = false;
             }
             TagEnd::Emphasis => self.inline.push('*'),
             TagEnd::Strong => self.inline.push_str("**"),
@@ -1,9 +1,34 @@
 use crate::error::{MarkdownlintError, Result};
-use globset::{Glob, GlobSet, GlobSetBuilder};
-use std::path::Path;
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+
+/// Whether `pattern` contains glob metacharacters rather than naming a literal path.
+pub fn is_glob_pattern(pattern: &str) -> bool {
+    pattern.contains('*') || pattern.contains('?') || pattern.contains('[')
+}
+
+/// Split a glob pattern into the literal directory prefix to walk from and the
+/// remaining pattern to match relative to it, e.g. `docs/**/*.md` → (`docs`, `**/*.md`).
+pub fn split_glob_pattern(pattern: &str) -> (PathBuf, String) {
+    let mut root = PathBuf::new();
+    let mut components = Path::new(pattern).components().peekable();
+    while let Some(component) =
+        components.next_if(|c| !is_glob_pattern(&c.as_os_str().to_string_lossy()))
+    {
+        root.push(component);
+    }
+
+    let rest: Vec<String> = components
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if root.as_os_str().is_empty() {
+        root.push(".");
+    }
+    (root, rest.join("/"))
+}
 
 fn normalize_exclude_pattern(pattern: &str) -> String {
-    if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
+    if is_glob_pattern(pattern) {
         pattern.to_string()
     } else {
         format!("**/{pattern}/**")
@@ -66,6 +91,38 @@ impl GlobMatcher {
     }
 }
 
+/// Config `ignores`, matched as written against a file's canonical path. Unlike exclude
+/// patterns, a bare name is not widened to a directory, and `*` stops at `/`.
+pub struct IgnoreMatcher {
+    globs: GlobSet,
+}
+
+impl IgnoreMatcher {
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| {
+                    MarkdownlintError::InvalidGlob(format!(
+                        "Invalid ignores pattern '{}': {}",
+                        pattern, e
+                    ))
+                })?;
+            builder.add(glob);
+        }
+        let globs = builder.build().map_err(|e| {
+            MarkdownlintError::InvalidGlob(format!("Failed to build ignores glob set: {}", e))
+        })?;
+        Ok(Self { globs })
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.globs.is_match(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,6 +178,35 @@ mod tests {
         assert!(matcher.matches(Path::new("any/file.txt")));
     }
 
+    #[test]
+    fn test_is_glob_pattern() {
+        assert!(is_glob_pattern("**/*.md"));
+        assert!(is_glob_pattern("doc?.md"));
+        assert!(is_glob_pattern("[ab].md"));
+        assert!(!is_glob_pattern("docs/README.md"));
+        assert!(!is_glob_pattern("docs"));
+    }
+
+    #[test]
+    fn test_split_glob_pattern() {
+        assert_eq!(
+            split_glob_pattern("docs/**/*.md"),
+            (PathBuf::from("docs"), "**/*.md".to_string())
+        );
+        assert_eq!(
+            split_glob_pattern("**/*.md"),
+            (PathBuf::from("."), "**/*.md".to_string())
+        );
+        assert_eq!(
+            split_glob_pattern("/tmp/project/*.md"),
+            (PathBuf::from("/tmp/project"), "*.md".to_string())
+        );
+        assert_eq!(
+            split_glob_pattern("docs/*/guide/*.md"),
+            (PathBuf::from("docs"), "*/guide/*.md".to_string())
+        );
+    }
+
     #[test]
     fn test_has_patterns() {
         let empty_matcher = GlobMatcher::new(&[]).unwrap();
@@ -132,4 +218,20 @@ mod tests {
         let exclude_matcher = GlobMatcher::new(&["#node_modules".to_string()]).unwrap();
         assert!(exclude_matcher.has_patterns());
     }
+
+    #[test]
+    fn test_ignore_literal_file_name() {
+        let matcher = IgnoreMatcher::new(&["/project/CHANGELOG.md".to_string()]).unwrap();
+
+        assert!(matcher.is_ignored(Path::new("/project/CHANGELOG.md")));
+        assert!(!matcher.is_ignored(Path::new("/project/docs/CHANGELOG.md")));
+    }
+
+    #[test]
+    fn test_ignore_star_stays_in_directory() {
+        let matcher = IgnoreMatcher::new(&["/project/*.md".to_string()]).unwrap();
+
+        assert!(matcher.is_ignored(Path::new("/project/README.md")));
+        assert!(!matcher.is_ignored(Path::new("/project/docs/guide.md")));
+    }
 }
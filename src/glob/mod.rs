@@ -1,5 +1,5 @@
 mod matcher;
 mod walker;
 
-pub use matcher::GlobMatcher;
+pub use matcher::{GlobMatcher, IgnoreMatcher, is_glob_pattern, split_glob_pattern};
 pub use walker::FileWalker;
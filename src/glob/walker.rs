@@ -3,17 +3,52 @@ use crate::glob::GlobMatcher;
 use ignore::WalkBuilder;
 use std::path::{Path, PathBuf};
 
+/// markdownlint's own ignore file, honored regardless of `respect_gitignore`.
+const MARKDOWNLINT_IGNORE_FILENAME: &str = ".markdownlintignore";
+
 const MARKDOWN_EXTENSIONS: &[&str] = &[
     "md", "markdown", "mdown", "mkdn", "mkd", "mdwn", "mdtxt", "mdtext",
 ];
 
 pub struct FileWalker {
     respect_gitignore: bool,
+    follow_symlinks: bool,
+    max_depth: Option<usize>,
+    extensions: Vec<String>,
 }
 
 impl FileWalker {
     pub fn new(respect_gitignore: bool) -> Self {
-        Self { respect_gitignore }
+        Self {
+            respect_gitignore,
+            follow_symlinks: false,
+            max_depth: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Treat files with these extensions (with or without a leading `.`) as Markdown
+    /// instead of the built-in list. An empty list keeps the built-in list.
+    pub fn extensions(mut self, extensions: &[String]) -> Self {
+        self.extensions = extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.').to_string())
+            .collect();
+        self
+    }
+
+    /// Follow symbolic links while walking. Off by default; the walker detects
+    /// symlink loops and reports them as walk errors.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Limit how many directories below the root are descended into. Depth 0 finds
+    /// only the root's direct children.
+    pub fn max_depth(mut self, depth: Option<usize>) -> Self {
+        self.max_depth = depth;
+        self
     }
 
     pub fn find_markdown_files(&self, root: &Path) -> Result<Vec<PathBuf>> {
@@ -32,25 +67,48 @@ impl FileWalker {
         self.walk_files(root, Some(matcher))
     }
 
+    /// Walks `root`, warning on stderr about entries that could not be read (for example
+    /// a permission-denied directory) rather than aborting the whole walk.
     fn walk_files(&self, root: &Path, matcher: Option<&GlobMatcher>) -> Result<Vec<PathBuf>> {
+        let (files, errors) = self.collect_files(root, matcher)?;
+        for error in &errors {
+            eprintln!("Warning: {}", error);
+        }
+        Ok(files)
+    }
+
+    fn collect_files(
+        &self,
+        root: &Path,
+        matcher: Option<&GlobMatcher>,
+    ) -> Result<(Vec<PathBuf>, Vec<ignore::Error>)> {
         let root = root.canonicalize().map_err(MarkdownlintError::Io)?;
         let mut builder = WalkBuilder::new(&root);
         builder.git_ignore(self.respect_gitignore);
         builder.git_global(self.respect_gitignore);
         builder.git_exclude(self.respect_gitignore);
         builder.hidden(false);
+        builder.follow_links(self.follow_symlinks);
+        // `WalkBuilder` counts the root itself as depth 0.
+        builder.max_depth(self.max_depth.map(|depth| depth + 1));
+        builder.add_custom_ignore_filename(MARKDOWNLINT_IGNORE_FILENAME);
 
         let mut files = Vec::new();
+        let mut errors = Vec::new();
         for entry in builder.build() {
-            let entry = entry.map_err(|e| {
-                MarkdownlintError::Io(std::io::Error::other(format!("Walk error: {}", e)))
-            })?;
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    errors.push(e);
+                    continue;
+                }
+            };
             if !(entry.file_type().is_some_and(|ft| ft.is_file())) {
                 continue;
             }
 
             let path = entry.path();
-            if !is_markdown_file(path) {
+            if !self.is_markdown_file(path) {
                 continue;
             }
 
@@ -63,15 +121,19 @@ impl FileWalker {
                 files.push(path.to_path_buf());
             }
         }
-        Ok(files)
+        Ok((files, errors))
     }
-}
 
-fn is_markdown_file(path: &Path) -> bool {
-    path.extension()
-        .and_then(|ext| ext.to_str())
-        .map(|ext| MARKDOWN_EXTENSIONS.contains(&ext))
-        .unwrap_or(false)
+    fn is_markdown_file(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+        if self.extensions.is_empty() {
+            MARKDOWN_EXTENSIONS.contains(&ext)
+        } else {
+            self.extensions.iter().any(|configured| configured == ext)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -139,6 +201,28 @@ mod tests {
         assert!(files[0].ends_with("README.md"));
     }
 
+    #[test]
+    fn test_markdownlintignore_respected() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut ignore_file =
+            fs::File::create(temp_dir.path().join(".markdownlintignore")).unwrap();
+        writeln!(ignore_file, "CHANGELOG.md").unwrap();
+        drop(ignore_file);
+
+        fs::File::create(temp_dir.path().join("README.md")).unwrap();
+        fs::File::create(temp_dir.path().join("CHANGELOG.md")).unwrap();
+
+        // Independent of gitignore handling: applies with and without it.
+        for respect_gitignore in [false, true] {
+            let walker = FileWalker::new(respect_gitignore);
+            let files = walker.find_markdown_files(temp_dir.path()).unwrap();
+
+            assert_eq!(files.len(), 1);
+            assert!(files[0].ends_with("README.md"));
+        }
+    }
+
     #[test]
     fn test_find_files_with_matcher() {
         let temp_dir = TempDir::new().unwrap();
@@ -159,13 +243,106 @@ mod tests {
         assert!(files[0].ends_with("docs/guide.md"));
     }
 
+    #[test]
+    fn test_max_depth() {
+        let temp_dir = TempDir::new().unwrap();
+        let docs_dir = temp_dir.path().join("docs");
+        let vendor_dir = docs_dir.join("vendor");
+        fs::create_dir_all(&vendor_dir).unwrap();
+
+        fs::File::create(temp_dir.path().join("README.md")).unwrap();
+        fs::File::create(docs_dir.join("guide.md")).unwrap();
+        fs::File::create(vendor_dir.join("third_party.md")).unwrap();
+
+        let files = FileWalker::new(false)
+            .max_depth(Some(0))
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("README.md"));
+
+        let files = FileWalker::new(false)
+            .max_depth(Some(1))
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(!files.iter().any(|p| p.ends_with("third_party.md")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        fs::File::create(target_dir.path().join("shared.md")).unwrap();
+        std::os::unix::fs::symlink(
+            target_dir.path().join("shared.md"),
+            temp_dir.path().join("linked.md"),
+        )
+        .unwrap();
+        fs::File::create(temp_dir.path().join("README.md")).unwrap();
+
+        let files = FileWalker::new(false)
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("README.md"));
+
+        let files = FileWalker::new(false)
+            .follow_symlinks(true)
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|p| p.ends_with("linked.md")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_error_does_not_abort_walk() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::File::create(temp_dir.path().join("README.md")).unwrap();
+        // Following a dangling link fails the same way for every user, root included.
+        std::os::unix::fs::symlink(
+            temp_dir.path().join("missing"),
+            temp_dir.path().join("broken.md"),
+        )
+        .unwrap();
+
+        let walker = FileWalker::new(false).follow_symlinks(true);
+        let (files, errors) = walker.collect_files(temp_dir.path(), None).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("README.md"));
+        assert_eq!(errors.len(), 1, "{errors:?}");
+    }
+
     #[test]
     fn test_is_markdown_file() {
-        assert!(is_markdown_file(Path::new("README.md")));
-        assert!(is_markdown_file(Path::new("guide.markdown")));
-        assert!(is_markdown_file(Path::new("doc.mdown")));
-        assert!(is_markdown_file(Path::new("file.mkd")));
-        assert!(!is_markdown_file(Path::new("README.txt")));
-        assert!(!is_markdown_file(Path::new("README")));
+        let walker = FileWalker::new(false);
+        assert!(walker.is_markdown_file(Path::new("README.md")));
+        assert!(walker.is_markdown_file(Path::new("guide.markdown")));
+        assert!(walker.is_markdown_file(Path::new("doc.mdown")));
+        assert!(walker.is_markdown_file(Path::new("file.mkd")));
+        assert!(!walker.is_markdown_file(Path::new("README.txt")));
+        assert!(!walker.is_markdown_file(Path::new("README")));
+    }
+
+    #[test]
+    fn test_configured_extensions() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::File::create(temp_dir.path().join("README.md")).unwrap();
+        fs::File::create(temp_dir.path().join("page.mdx")).unwrap();
+
+        let files = FileWalker::new(false)
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("README.md"));
+
+        let files = FileWalker::new(false)
+            .extensions(&["md".to_string(), ".mdx".to_string()])
+            .find_markdown_files(temp_dir.path())
+            .unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|p| p.ends_with("page.mdx")));
     }
 }
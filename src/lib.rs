@@ -10,3 +10,24 @@ pub mod logger;
 pub mod markdown;
 pub mod server;
 pub mod types;
+
+pub use config::Config;
+pub use error::Result;
+pub use lint::rules::create_default_registry;
+pub use lint::{LintEngine, Rule, RuleRegistry};
+pub use markdown::MarkdownParser;
+pub use types::{Fix, Violation};
+
+/// Lint a Markdown string with the built-in rules under `config`.
+///
+/// A shortcut for `LintEngine::new(config.clone()).lint_content(content)` when
+/// linting a single document; build a [`LintEngine`] directly to reuse it.
+///
+/// ```
+/// let config = mdlint::Config::default();
+/// let violations = mdlint::lint_str("# Title\n\nText   \n", &config).unwrap();
+/// assert!(violations.iter().any(|v| v.rule == "MD009"));
+/// ```
+pub fn lint_str(content: &str, config: &Config) -> Result<Vec<Violation>> {
+    LintEngine::new(config.clone()).lint_content(content)
+}
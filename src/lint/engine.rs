@@ -1,33 +1,97 @@
-use crate::config::{Config, RuleConfig};
+use crate::config::{Config, RuleConfig, Severity, merge_rule_configs};
 use crate::error::Result;
+use crate::glob::GlobMatcher;
 use crate::lint::{Rule, RuleRegistry};
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+/// Path handed to filename-aware rules when linting content without a file.
+const CONTENT_PATH: &str = "<content>";
+
+type RuleConfigs = HashMap<String, RuleConfig>;
 
 pub struct LintEngine {
     config: Config,
     registry: RuleRegistry,
+    /// Compiled `config.overrides`: files each applies to and its canonicalized rules.
+    overrides: Vec<(GlobMatcher, RuleConfigs)>,
+    /// Canonical working directory, which override globs not anchored to a config
+    /// file are relative to.
+    cwd: Option<PathBuf>,
 }
 
 impl LintEngine {
     pub fn new(config: Config) -> Self {
-        let registry = crate::lint::rules::create_default_registry();
-        Self { config, registry }
+        Self::with_registry(config, crate::lint::rules::create_default_registry())
+    }
+
+    /// Build an engine over a custom set of rules, e.g. the built-in registry
+    /// from `create_default_registry` with project-specific rules registered on top.
+    pub fn with_registry(config: Config, registry: RuleRegistry) -> Self {
+        let config = canonicalize_rule_names(config, &registry);
+        let overrides = config
+            .overrides
+            .iter()
+            // Globs are validated when the config is loaded; a bad one matches nothing.
+            .filter_map(|config_override| {
+                let matcher = GlobMatcher::new(&config_override.files).ok()?;
+                Some((
+                    matcher,
+                    canonicalize_rules(config_override.rules.clone(), &registry),
+                ))
+            })
+            .collect();
+        Self {
+            config,
+            registry,
+            overrides,
+            cwd: std::env::current_dir()
+                .and_then(|cwd| cwd.canonicalize())
+                .ok(),
+        }
     }
 
+    /// Severity configured for the rule with canonical name `rule` in the file at
+    /// `path`, including any `overrides` that match it.
+    pub fn severity(&self, path: &Path, rule: &str) -> Severity {
+        self.severities(path)(rule)
+    }
+
+    /// Like [`LintEngine::severity`], but resolves the settings for `path` once for
+    /// looking up every rule reported in that file.
+    pub fn severities<'a>(&'a self, path: &Path) -> impl Fn(&str) -> Severity + use<'a> {
+        let rules = self.rules_for(path);
+        move |rule| {
+            rules
+                .get(rule)
+                .map_or(Severity::Error, RuleConfig::severity)
+        }
+    }
+
+    /// Lint content that has no file of its own (e.g. stdin or an editor buffer).
     pub fn lint_content(&self, content: &str) -> Result<Vec<Violation>> {
-        let parser = MarkdownParser::new(content);
+        self.lint_file(Path::new(CONTENT_PATH), content)
+    }
+
+    /// Lint `content` read from `path`, letting filename-aware rules see the path.
+    pub fn lint_file(&self, path: &Path, content: &str) -> Result<Vec<Violation>> {
+        let parser = MarkdownParser::new(content)
+            .with_math(self.config.math)
+            .with_commonmark(&self.config.commonmark);
+        let rules = self.rules_for(path);
         let mut violations: Vec<Violation> = self
             .registry
             .all_rules()
-            .flat_map(|rule| self.violations(&parser, rule))
+            .flat_map(|rule| self.violations(&rules, path, &parser, rule))
             .collect();
 
         if !self.config.no_inline_config {
-            let suppressed = parse_inline_config(content);
+            let suppressed = self.canonicalize_suppressions(parse_inline_config(content));
             if !suppressed.is_empty() {
                 violations.retain(|v| {
                     let line = v.line;
@@ -43,8 +107,37 @@ impl LintEngine {
         Ok(violations)
     }
 
-    fn violations(&self, parser: &MarkdownParser, rule: &dyn Rule) -> Vec<Violation> {
-        let rule_config = self.config.config().get(rule.name());
+    /// The base rule settings merged with every override whose globs match `path`.
+    fn rules_for(&self, path: &Path) -> Cow<'_, RuleConfigs> {
+        if self.overrides.is_empty() {
+            return Cow::Borrowed(&self.config.rules);
+        }
+        let relative = override_match_path(path, self.cwd.as_deref());
+        // Globs from a config file are anchored to its directory, so they need the
+        // absolute path.
+        let canonical = path.canonicalize().ok();
+        self.overrides
+            .iter()
+            .filter(|(matcher, _)| {
+                matcher.matches(relative)
+                    || canonical
+                        .as_deref()
+                        .is_some_and(|canonical| matcher.matches(canonical))
+            })
+            .fold(
+                Cow::Borrowed(&self.config.rules),
+                |rules, (_, override_rules)| Cow::Owned(merge_rule_configs(&rules, override_rules)),
+            )
+    }
+
+    fn violations(
+        &self,
+        rules: &RuleConfigs,
+        path: &Path,
+        parser: &MarkdownParser,
+        rule: &dyn Rule,
+    ) -> Vec<Violation> {
+        let rule_config = rules.get(rule.name());
         let config_value = match rule_config {
             Some(RuleConfig::Enabled(false)) => return Vec::new(),
             Some(RuleConfig::Enabled(true)) => None,
@@ -62,22 +155,130 @@ impl LintEngine {
                 }
                 Some(json_value)
             }
-            None => {
+            None => match tag_setting(rules, rule) {
+                Some(true) => None,
+                Some(false) => return Vec::new(),
                 // If default_enabled is true and no specific config exists, enable the rule
-                if self.config.default_enabled {
-                    None
-                } else {
-                    return Vec::new();
-                }
-            }
+                None if self.config.default_enabled => None,
+                None => return Vec::new(),
+            },
         };
 
-        rule.check(parser, config_value.as_ref())
+        let config_value = self.with_global_defaults(rule, config_value);
+
+        // A bug in one rule shouldn't take down the whole run; report it and move on.
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            rule.check_file(path, parser, config_value.as_ref())
+        }))
+        .unwrap_or_else(|payload| vec![rule_panic_violation(rule, payload.as_ref())])
     }
 
-    pub fn lint_file(&self, path: &Path) -> Result<Vec<Violation>> {
-        let content = std::fs::read_to_string(path)?;
-        self.lint_content(&content)
+    /// Fill in options that top-level settings provide for a rule, unless the
+    /// rule's own config already sets them (e.g. `line_length` for MD013).
+    fn with_global_defaults(&self, rule: &dyn Rule, config: Option<Value>) -> Option<Value> {
+        let Some(line_length) = self.config.line_length else {
+            return config;
+        };
+        if rule.name() != "MD013" {
+            return config;
+        }
+        let mut config = config.unwrap_or_else(|| Value::Object(Default::default()));
+        if let Some(options) = config.as_object_mut() {
+            options
+                .entry("line_length")
+                .or_insert_with(|| Value::from(line_length));
+        }
+        Some(config)
+    }
+
+    /// Key inline suppressions by canonical rule name so directives may use aliases.
+    fn canonicalize_suppressions(
+        &self,
+        suppressed: HashMap<String, HashSet<usize>>,
+    ) -> HashMap<String, HashSet<usize>> {
+        let mut canonical: HashMap<String, HashSet<usize>> = HashMap::new();
+        for (name, lines) in suppressed {
+            let key = self.registry.resolve(&name).unwrap_or(&name).to_string();
+            canonical.entry(key).or_default().extend(lines);
+        }
+        canonical
+    }
+}
+
+/// Resolve tag-level settings (e.g. `table = false`) for a rule without its own config.
+/// Disabling any of the rule's tags wins over enabling another.
+fn tag_setting(rules: &RuleConfigs, rule: &dyn Rule) -> Option<bool> {
+    let settings: Vec<bool> = rule
+        .tags()
+        .iter()
+        .filter_map(|tag| rules.get(*tag))
+        .filter_map(rule_config_enabled)
+        .collect();
+    if settings.is_empty() {
+        None
+    } else {
+        Some(settings.iter().all(|&enabled| enabled))
+    }
+}
+
+/// `path` as unanchored override globs see it: relative to the working directory
+/// `cwd` when it lies inside it, without a leading `./`.
+fn override_match_path<'a>(path: &'a Path, cwd: Option<&Path>) -> &'a Path {
+    if path.is_absolute()
+        && let Some(relative) = cwd.and_then(|cwd| path.strip_prefix(cwd).ok())
+    {
+        return relative;
+    }
+    path.strip_prefix(".").unwrap_or(path)
+}
+
+/// Re-key rule settings given by alias (e.g. `line-length`) under the rule's id.
+/// Settings keyed by the id itself take precedence over alias settings.
+fn canonicalize_rule_names(mut config: Config, registry: &RuleRegistry) -> Config {
+    config.rules = canonicalize_rules(config.rules, registry);
+    config
+}
+
+fn canonicalize_rules(rules: RuleConfigs, registry: &RuleRegistry) -> RuleConfigs {
+    let (aliased, by_id): (HashMap<_, _>, HashMap<_, _>) =
+        rules.into_iter().partition(|(name, _)| {
+            registry
+                .resolve(name)
+                .is_some_and(|canonical| canonical != name)
+        });
+    let aliased: HashMap<String, RuleConfig> = aliased
+        .into_iter()
+        .filter_map(|(name, cfg)| registry.resolve(&name).map(|c| (c.to_string(), cfg)))
+        .collect();
+    merge_rule_configs(&aliased, &by_id)
+}
+
+fn rule_panic_violation(rule: &dyn Rule, payload: &(dyn std::any::Any + Send)) -> Violation {
+    let reason = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+    Violation {
+        line: 1,
+        column: None,
+        end_column: None,
+        rule: rule.name().to_string(),
+        message: format!(
+            "Internal error: {} panicked ({}); its results for this file were skipped",
+            rule.name(),
+            reason
+        ),
+        fix: None,
+    }
+}
+
+fn rule_config_enabled(rule_config: &RuleConfig) -> Option<bool> {
+    match rule_config {
+        RuleConfig::Enabled(enabled) => Some(*enabled),
+        RuleConfig::Config(cfg) => {
+            Some(cfg.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true))
+        }
     }
 }
 
@@ -215,6 +416,338 @@ mod tests {
         })
     }
 
+    fn engine_with_rules(default_enabled: bool, rules: &[(&str, RuleConfig)]) -> LintEngine {
+        LintEngine::new(Config {
+            default_enabled,
+            rules: rules
+                .iter()
+                .map(|(name, cfg)| (name.to_string(), cfg.clone()))
+                .collect(),
+            ..Config::default()
+        })
+    }
+
+    struct PanickingRule;
+
+    impl Rule for PanickingRule {
+        fn name(&self) -> &str {
+            "MD999"
+        }
+
+        fn description(&self) -> &str {
+            "Always panics"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &[]
+        }
+
+        fn check(&self, _parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+            panic!("unexpected byte offset")
+        }
+    }
+
+    #[test]
+    fn test_rule_panic_is_reported_and_other_rules_still_run() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(PanickingRule));
+        registry.register(Box::new(crate::lint::rules::MD009));
+        let engine = LintEngine {
+            config: Config {
+                default_enabled: true,
+                ..Config::default()
+            },
+            registry,
+            overrides: Vec::new(),
+            cwd: None,
+        };
+
+        let violations = engine.lint_content("Trailing   \n").unwrap();
+
+        assert!(
+            violations.iter().any(|v| v.rule == "MD009"),
+            "{violations:?}"
+        );
+        let panic_report = violations.iter().find(|v| v.rule == "MD999").unwrap();
+        assert!(panic_report.message.contains("unexpected byte offset"));
+        assert!(panic_report.fix.is_none());
+    }
+
+    struct FilenameRule;
+
+    impl Rule for FilenameRule {
+        fn name(&self) -> &str {
+            "MD998"
+        }
+
+        fn description(&self) -> &str {
+            "Reports the filename"
+        }
+
+        fn tags(&self) -> &[&str] {
+            &[]
+        }
+
+        fn check(&self, _parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+            Vec::new()
+        }
+
+        fn check_file(
+            &self,
+            path: &Path,
+            _parser: &MarkdownParser,
+            _config: Option<&Value>,
+        ) -> Vec<Violation> {
+            vec![Violation {
+                line: 1,
+                column: None,
+                end_column: None,
+                rule: self.name().to_string(),
+                message: path.display().to_string(),
+                fix: None,
+            }]
+        }
+    }
+
+    #[test]
+    fn test_lint_file_passes_path_to_rules() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(FilenameRule));
+        let engine = LintEngine {
+            config: Config {
+                default_enabled: true,
+                ..Config::default()
+            },
+            registry,
+            overrides: Vec::new(),
+            cwd: None,
+        };
+
+        let violations = engine
+            .lint_file(Path::new("docs/guide.md"), "# Guide\n")
+            .unwrap();
+        assert_eq!(violations[0].message, "docs/guide.md");
+
+        let violations = engine.lint_content("# Guide\n").unwrap();
+        assert_eq!(violations[0].message, CONTENT_PATH);
+    }
+
+    const TABLE_AND_HEADING_DOC: &str =
+        "# Title\n\n### Skipped level\n\n| a | b |\n| --- | --- |\n| 1 |\n";
+
+    #[test]
+    fn test_tag_disables_table_rules() {
+        let engine = engine_with_rules(true, &[("table", RuleConfig::Enabled(false))]);
+        let violations = engine.lint_content(TABLE_AND_HEADING_DOC).unwrap();
+        let table_rules = ["MD055", "MD056", "MD058", "MD060"];
+        assert!(
+            violations
+                .iter()
+                .all(|v| !table_rules.contains(&v.rule.as_str())),
+            "table rules should be disabled by tag: {violations:?}"
+        );
+        assert!(
+            violations.iter().any(|v| v.rule == "MD001"),
+            "headings rules should still fire: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_tag_enabled_without_default() {
+        let engine = engine_with_rules(false, &[("table", RuleConfig::Enabled(true))]);
+        let violations = engine.lint_content(TABLE_AND_HEADING_DOC).unwrap();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD056"),
+            "table rules should be enabled by tag: {violations:?}"
+        );
+        assert!(
+            violations.iter().all(|v| v.rule != "MD001"),
+            "untagged rules stay disabled: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_rule_config_overrides_tag() {
+        let engine = engine_with_rules(
+            true,
+            &[
+                ("table", RuleConfig::Enabled(false)),
+                ("MD056", RuleConfig::Enabled(true)),
+            ],
+        );
+        let violations = engine.lint_content(TABLE_AND_HEADING_DOC).unwrap();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD056"),
+            "rule-specific config should win over its tag: {violations:?}"
+        );
+        assert!(
+            violations.iter().all(|v| v.rule != "MD058"),
+            "other table rules stay disabled: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_alias_configures_rule() {
+        let mut options = HashMap::new();
+        options.insert("line_length".to_string(), toml::Value::Integer(50));
+        let engine = engine_with_rules(true, &[("line-length", RuleConfig::Config(options))]);
+        let content = format!("# Title\n\n{}\n", "word ".repeat(12).trim_end());
+        let violations = engine.lint_content(&content).unwrap();
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.rule == "MD013" && v.message.contains("> 50")),
+            "line-length alias should configure MD013: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_top_level_line_length_is_md013_default() {
+        let content = format!("# Title\n\n{}\n", "x".repeat(90));
+        let engine = LintEngine::new(Config {
+            line_length: Some(100),
+            ..Config::default()
+        });
+        let violations = engine.lint_content(&content).unwrap();
+        assert!(
+            violations.iter().all(|v| v.rule != "MD013"),
+            "a 90-char line is within the top-level limit: {violations:?}"
+        );
+
+        let mut options = HashMap::new();
+        options.insert("line_length".to_string(), toml::Value::Integer(80));
+        let engine = LintEngine::new(Config {
+            line_length: Some(100),
+            rules: HashMap::from([("MD013".to_string(), RuleConfig::Config(options))]),
+            ..Config::default()
+        });
+        let violations = engine.lint_content(&content).unwrap();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD013"),
+            "MD013's own line_length wins over the top-level one: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_overrides_apply_to_matching_files() {
+        let mut base = HashMap::new();
+        base.insert("line_length".to_string(), toml::Value::Integer(80));
+        let mut docs = HashMap::new();
+        docs.insert("line_length".to_string(), toml::Value::Integer(200));
+        let engine = LintEngine::new(Config {
+            rules: HashMap::from([("MD013".to_string(), RuleConfig::Config(base))]),
+            overrides: vec![crate::config::ConfigOverride {
+                files: vec!["docs/**".to_string()],
+                rules: HashMap::from([("line-length".to_string(), RuleConfig::Config(docs))]),
+            }],
+            ..Config::default()
+        });
+        let content = format!("# Title\n\n{}\n", "word ".repeat(30).trim_end());
+        let has_md013 = |path: &str| {
+            engine
+                .lint_file(Path::new(path), &content)
+                .unwrap()
+                .iter()
+                .any(|v| v.rule == "MD013")
+        };
+
+        assert!(has_md013("README.md"));
+        assert!(!has_md013("docs/guide.md"));
+        assert!(!has_md013("./docs/nested/guide.md"));
+    }
+
+    #[test]
+    fn test_overrides_set_severity_for_matching_files() {
+        let mut legacy = HashMap::new();
+        legacy.insert(
+            "severity".to_string(),
+            toml::Value::String("warning".to_string()),
+        );
+        let engine = LintEngine::new(Config {
+            overrides: vec![crate::config::ConfigOverride {
+                files: vec!["legacy/**".to_string()],
+                rules: HashMap::from([(
+                    "no-trailing-spaces".to_string(),
+                    RuleConfig::Config(legacy),
+                )]),
+            }],
+            ..Config::default()
+        });
+
+        assert_eq!(
+            engine.severity(Path::new("legacy/old.md"), "MD009"),
+            Severity::Warning
+        );
+        assert_eq!(
+            engine.severity(Path::new("README.md"), "MD009"),
+            Severity::Error
+        );
+    }
+
+    #[test]
+    fn test_math_config_enables_math_parsing() {
+        let content = "# Title\n\nProduct $a_i * b_j * c$ here.\n";
+        let violations = engine_all_rules().lint_content(content).unwrap();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD037"),
+            "{violations:?}"
+        );
+
+        let engine = LintEngine::new(Config {
+            math: true,
+            ..Config::default()
+        });
+        let violations = engine.lint_content(content).unwrap();
+        assert!(
+            violations.iter().all(|v| v.rule != "MD037"),
+            "{violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_commonmark_config_disables_tables() {
+        let content = "# Title\n\nIntro\n| a | b |\n| --- | --- |\n| 1 | 2 |\n";
+        let violations = engine_all_rules().lint_content(content).unwrap();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD058"),
+            "{violations:?}"
+        );
+
+        let engine = LintEngine::new(Config {
+            commonmark: crate::config::CommonMarkOptions {
+                tables: false,
+                ..Default::default()
+            },
+            ..Config::default()
+        });
+        let violations = engine.lint_content(content).unwrap();
+        assert!(
+            violations.iter().all(|v| v.rule != "MD058"),
+            "{violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_alias_disables_rule() {
+        let engine = engine_with_rules(true, &[("no-trailing-spaces", RuleConfig::Enabled(false))]);
+        let violations = engine.lint_content("# Title\n\nText   \n").unwrap();
+        assert!(
+            violations.iter().all(|v| v.rule != "MD009"),
+            "MD009 should be disabled via alias: {violations:?}"
+        );
+    }
+
+    #[test]
+    fn test_inline_directive_accepts_alias() {
+        let content =
+            "<!-- mdlint-disable-next-line no-missing-space-atx -->\n#Heading without space\n";
+        let violations = engine_all_rules().lint_content(content).unwrap();
+        assert!(
+            violations.iter().all(|v| v.rule != "MD018"),
+            "MD018 should be suppressed via alias: {violations:?}"
+        );
+    }
+
     #[test]
     fn test_disable_next_line_specific_rule() {
         // MD018: no space after hash. Line 2 has `#Heading` — suppressed by disable-next-line on line 1.
@@ -1,5 +1,6 @@
+use crate::config::{FailOn, Severity};
 use crate::types::{FileResult, Violation};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Default)]
 pub struct LintResult {
@@ -14,12 +15,26 @@ impl LintResult {
         Self::default()
     }
 
+    /// Record a file's violations, sorted by line, column, then rule so every
+    /// formatter produces stable output regardless of rule execution order.
+    /// Identical violations (same rule, position, and message) are reported once, and
+    /// a path recorded twice has its violations merged into the existing entry.
     pub fn add_file_result(
         &mut self,
         path: PathBuf,
         violations: Vec<Violation>,
         source_lines: Vec<String>,
     ) {
+        if let Some(existing) = self.file_results.iter_mut().find(|r| r.path == path) {
+            let before = existing.violations.len();
+            existing.violations.extend(violations);
+            normalize_violations(&mut existing.violations);
+            self.total_errors += existing.violations.len() - before;
+            return;
+        }
+
+        let mut violations = violations;
+        normalize_violations(&mut violations);
         self.total_errors += violations.len();
         self.total_files_checked += 1;
         self.file_results.push(FileResult {
@@ -37,4 +52,206 @@ impl LintResult {
     pub fn has_errors(&self) -> bool {
         self.total_errors > 0
     }
+
+    /// Number of violations that should fail the run under `fail_on`. `severities`
+    /// gives a file's rule severity lookup, and is called once per file.
+    pub fn failing_violations<F: Fn(&str) -> Severity>(
+        &self,
+        fail_on: FailOn,
+        severities: impl Fn(&Path) -> F,
+    ) -> usize {
+        match fail_on {
+            FailOn::Warning => self.total_errors,
+            FailOn::None => 0,
+            FailOn::Error => self
+                .file_results
+                .iter()
+                .map(|file_result| {
+                    let severity = severities(&file_result.path);
+                    file_result
+                        .violations
+                        .iter()
+                        .filter(|v| severity(&v.rule) == Severity::Error)
+                        .count()
+                })
+                .sum(),
+        }
+    }
+
+    /// Rewrite file paths under `base` relative to it; other paths are left as-is.
+    pub fn relativize_paths(&mut self, base: &Path) {
+        for file_result in &mut self.file_results {
+            let absolute = file_result
+                .path
+                .canonicalize()
+                .unwrap_or_else(|_| file_result.path.clone());
+            if let Ok(relative) = absolute.strip_prefix(base) {
+                file_result.path = relative.to_path_buf();
+            }
+        }
+    }
+}
+
+fn normalize_violations(violations: &mut Vec<Violation>) {
+    violations.sort_by(|a, b| {
+        (a.line, a.column, &a.rule, &a.message).cmp(&(b.line, b.column, &b.rule, &b.message))
+    });
+    violations.dedup_by(|a, b| {
+        (a.line, a.column, &a.rule, &a.message) == (b.line, b.column, &b.rule, &b.message)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(line: usize, column: Option<usize>, rule: &str) -> Violation {
+        violation_with_message(line, column, rule, "")
+    }
+
+    fn violation_with_message(
+        line: usize,
+        column: Option<usize>,
+        rule: &str,
+        message: &str,
+    ) -> Violation {
+        Violation {
+            line,
+            column,
+            end_column: None,
+            rule: rule.to_string(),
+            message: message.to_string(),
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_add_file_result_sorts_violations() {
+        let shuffled = vec![
+            violation(3, Some(1), "MD009"),
+            violation(1, Some(5), "MD013"),
+            violation(3, Some(1), "MD004"),
+            violation(1, None, "MD041"),
+            violation(2, Some(1), "MD022"),
+            violation(1, Some(2), "MD013"),
+        ];
+
+        let mut result = LintResult::new();
+        result.add_file_result(PathBuf::from("doc.md"), shuffled, Vec::new());
+
+        let order: Vec<_> = result.file_results[0]
+            .violations
+            .iter()
+            .map(|v| (v.line, v.column, v.rule.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (1, None, "MD041"),
+                (1, Some(2), "MD013"),
+                (1, Some(5), "MD013"),
+                (2, Some(1), "MD022"),
+                (3, Some(1), "MD004"),
+                (3, Some(1), "MD009"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_file_result_dedupes_identical_violations() {
+        let violations = vec![
+            violation_with_message(2, Some(1), "MD022", "missing before"),
+            violation_with_message(4, Some(3), "MD009", "Trailing spaces"),
+            violation_with_message(2, Some(1), "MD022", "missing after"),
+            violation_with_message(2, Some(1), "MD022", "missing before"),
+            violation_with_message(4, Some(3), "MD009", "Trailing spaces"),
+        ];
+
+        let mut result = LintResult::new();
+        result.add_file_result(PathBuf::from("doc.md"), violations, Vec::new());
+
+        // Violations differing only in message are kept.
+        assert_eq!(result.total_errors, 3);
+        assert_eq!(result.file_results[0].violations.len(), 3);
+    }
+
+    #[test]
+    fn test_add_file_result_merges_same_path() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("doc.md"),
+            vec![violation(1, Some(1), "MD041")],
+            Vec::new(),
+        );
+        result.add_file_result(
+            PathBuf::from("doc.md"),
+            vec![
+                violation(1, Some(1), "MD041"),
+                violation(3, Some(1), "MD009"),
+            ],
+            Vec::new(),
+        );
+
+        assert_eq!(result.file_results.len(), 1);
+        assert_eq!(result.file_results[0].violations.len(), 2);
+        assert_eq!(result.total_errors, 2);
+        assert_eq!(result.total_files_checked, 1);
+    }
+
+    #[test]
+    fn test_relativize_paths() {
+        let base = std::env::temp_dir().canonicalize().unwrap();
+        let mut result = LintResult::new();
+        result.add_file_result(
+            base.join("docs").join("guide.md"),
+            vec![violation(1, Some(1), "MD041")],
+            Vec::new(),
+        );
+        result.add_file_result(
+            PathBuf::from("/elsewhere/notes.md"),
+            vec![violation(1, Some(1), "MD041")],
+            Vec::new(),
+        );
+
+        result.relativize_paths(&base);
+
+        assert_eq!(result.file_results[0].path, Path::new("docs/guide.md"));
+        assert_eq!(
+            result.file_results[1].path,
+            Path::new("/elsewhere/notes.md")
+        );
+    }
+
+    #[test]
+    fn test_failing_violations_by_fail_on() {
+        let mut result = LintResult::new();
+        result.add_file_result(
+            PathBuf::from("doc.md"),
+            vec![
+                violation(1, Some(1), "MD013"),
+                violation(2, Some(1), "MD009"),
+            ],
+            Vec::new(),
+        );
+        result.add_file_result(
+            PathBuf::from("legacy.md"),
+            vec![violation(3, Some(1), "MD009")],
+            Vec::new(),
+        );
+        // MD013 is a warning everywhere; MD009 only in legacy.md.
+        let severity = |path: &Path| {
+            let legacy = path == Path::new("legacy.md");
+            move |rule: &str| {
+                if rule == "MD013" || legacy {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                }
+            }
+        };
+
+        assert_eq!(result.failing_violations(FailOn::Error, severity), 1);
+        assert_eq!(result.failing_violations(FailOn::Warning, severity), 3);
+        assert_eq!(result.failing_violations(FailOn::None, severity), 0);
+    }
 }
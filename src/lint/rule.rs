@@ -2,15 +2,37 @@ use crate::markdown::MarkdownParser;
 use crate::types::Violation;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::Path;
 
 pub trait Rule: Send + Sync {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
     fn tags(&self) -> &[&str];
 
+    /// Human-readable names (e.g. `line-length`) accepted wherever the rule id is
+    fn aliases(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Configuration options and their default values, shown by `--explain`
+    fn default_config(&self) -> Value {
+        Value::Object(serde_json::Map::new())
+    }
+
     /// Check the markdown content for violations
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation>;
 
+    /// Check the markdown content of the file at `path`; rules that depend on the
+    /// filename override this instead of `check`
+    fn check_file(
+        &self,
+        _path: &Path,
+        parser: &MarkdownParser,
+        config: Option<&Value>,
+    ) -> Vec<Violation> {
+        self.check(parser, config)
+    }
+
     /// Whether this rule can automatically fix violations
     fn fixable(&self) -> bool {
         false
@@ -20,6 +42,8 @@ pub trait Rule: Send + Sync {
 #[derive(Default)]
 pub struct RuleRegistry {
     rules: HashMap<String, Box<dyn Rule + Send + Sync>>,
+    /// Lowercased alias -> rule name
+    aliases: HashMap<String, String>,
 }
 
 impl RuleRegistry {
@@ -28,14 +52,53 @@ impl RuleRegistry {
     }
 
     pub fn register(&mut self, rule: Box<dyn Rule + Send + Sync>) {
+        for alias in rule.aliases() {
+            self.aliases
+                .insert(alias.to_lowercase(), rule.name().to_string());
+        }
         self.rules.insert(rule.name().to_string(), rule);
     }
 
+    /// Look up a rule by id or alias, case-insensitively.
     pub fn get(&self, name: &str) -> Option<&dyn Rule> {
-        self.rules.get(name).map(|r| r.as_ref() as &dyn Rule)
+        self.resolve(name)
+            .and_then(|canonical| self.rules.get(canonical))
+            .map(|r| r.as_ref() as &dyn Rule)
+    }
+
+    /// Resolve a rule id or alias to the rule's canonical name.
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        [name.to_string(), name.to_uppercase()]
+            .iter()
+            .find_map(|candidate| self.rules.get_key_value(candidate))
+            .map(|(canonical, _)| canonical.as_str())
+            .or_else(|| self.aliases.get(&name.to_lowercase()).map(String::as_str))
     }
 
     pub fn all_rules(&self) -> impl Iterator<Item = &dyn Rule> {
         self.rules.values().map(|r| r.as_ref() as &dyn Rule)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::lint::rules::create_default_registry;
+
+    #[test]
+    fn test_resolve_id_and_alias() {
+        let registry = create_default_registry();
+        assert_eq!(registry.resolve("MD013"), Some("MD013"));
+        assert_eq!(registry.resolve("md013"), Some("MD013"));
+        assert_eq!(registry.resolve("line-length"), Some("MD013"));
+        assert_eq!(registry.resolve("No-Hard-Tabs"), Some("MD010"));
+        assert_eq!(registry.resolve("single-h1"), Some("MD025"));
+        assert_eq!(registry.resolve("not-a-rule"), None);
+    }
+
+    #[test]
+    fn test_get_by_alias() {
+        let registry = create_default_registry();
+        let rule = registry.get("line-length").unwrap();
+        assert_eq!(rule.name(), "MD013");
+    }
+}
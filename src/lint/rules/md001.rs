@@ -15,6 +15,10 @@ impl Rule for MD001 {
         "Heading levels should only increment by one level at a time"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["heading-increment"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers"]
     }
@@ -34,6 +38,7 @@ impl Rule for MD001 {
                         violations.push(Violation {
                             line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "Heading level skipped from h{} to h{}",
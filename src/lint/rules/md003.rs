@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
-use serde_json::Value;
+use crate::types::{Fix, Violation};
+use serde_json::{Value, json};
 
 pub struct MD003;
 
@@ -21,10 +21,18 @@ impl Rule for MD003 {
         "Heading style should be consistent throughout the document"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["heading-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "atx" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -79,12 +87,13 @@ impl Rule for MD003 {
                             violations.push(Violation {
                                 line: line_number,
                                 column: Some(1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "Heading style should be consistent (expected {:?}, found {:?})",
                                     first, current
                                 ),
-                                fix: None,
+                                fix: heading_fix(parser, line_num, current, first),
                             });
                         }
                     } else {
@@ -102,12 +111,13 @@ impl Rule for MD003 {
                         violations.push(Violation {
                             line: line_number,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "Heading style should be {:?} but found {:?}",
                                 required_style, current
                             ),
-                            fix: None,
+                            fix: heading_fix(parser, line_num, current, required_style),
                         });
                     }
                 }
@@ -118,13 +128,228 @@ impl Rule for MD003 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
+    }
+}
+
+/// Build a fix rewriting the heading starting at `line_idx` (0-indexed) from `current`
+/// to `target` style. Returns `None` when the target cannot express the heading
+/// (setext only supports levels 1 and 2, ATX only a single line).
+///
+/// The fix only touches the heading markers where it can (the `#` runs, or the text
+/// line's end through the underline) so other rules' fixes on the line still apply.
+fn heading_fix(
+    parser: &MarkdownParser,
+    line_idx: usize,
+    current: HeadingStyle,
+    target: HeadingStyle,
+) -> Option<Fix> {
+    let lines = parser.lines();
+    let line = lines[line_idx];
+    let line_number = line_idx + 1;
+    let chars: Vec<char> = line.chars().collect();
+    let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+    let content_end = line.trim_end().chars().count();
+    let description = format!("Convert heading to {:?} style", target);
+
+    match current {
+        HeadingStyle::Atx | HeadingStyle::AtxClosed => {
+            let level = chars[indent..].iter().take_while(|&&c| c == '#').count();
+            let hashes = "#".repeat(level);
+            // Character columns (0-indexed, exclusive end) of the heading text.
+            let mut text_end = content_end;
+            if current == HeadingStyle::AtxClosed {
+                while text_end > indent + level && chars[text_end - 1] == '#' {
+                    text_end -= 1;
+                }
+                while text_end > indent + level && chars[text_end - 1].is_whitespace() {
+                    text_end -= 1;
+                }
+            }
+            let gap = chars[indent + level..text_end]
+                .iter()
+                .take_while(|c| c.is_whitespace())
+                .count();
+            let text: String = chars[indent + level + gap..text_end].iter().collect();
+
+            let (column_start, column_end, replacement) = match target {
+                HeadingStyle::Atx => (text_end + 1, content_end, String::new()),
+                HeadingStyle::AtxClosed => (text_end + 1, text_end, format!(" {hashes}")),
+                HeadingStyle::Setext if level <= 2 && !text.is_empty() => {
+                    let marker = if level == 1 { "=" } else { "-" };
+                    let underline = marker.repeat(text.chars().count().max(3));
+                    let indent: String = chars[..indent].iter().collect();
+                    (
+                        indent.chars().count() + 1,
+                        content_end,
+                        format!("{text}\n{indent}{underline}"),
+                    )
+                }
+                HeadingStyle::Setext => return None,
+            };
+            Some(Fix {
+                line_start: line_number,
+                line_end: line_number,
+                column_start: Some(column_start),
+                column_end: Some(column_end),
+                replacement,
+                description,
+            })
+        }
+        HeadingStyle::Setext => {
+            // A setext heading may span several text lines, which ATX can't express.
+            if !parser.headings().iter().any(|h| h.line == line_number) {
+                return None;
+            }
+            let underline = lines.get(line_idx + 1)?;
+            let level = if underline.trim().starts_with('=') {
+                1
+            } else {
+                2
+            };
+            let hashes = "#".repeat(level);
+            let text: String = chars[indent..content_end].iter().collect();
+            let replacement = match target {
+                HeadingStyle::AtxClosed => format!("{hashes} {text} {hashes}"),
+                _ => format!("{hashes} {text}"),
+            };
+            // From the text through the underline, leaving trailing whitespace alone.
+            Some(Fix {
+                line_start: line_number,
+                line_end: line_number + 1,
+                column_start: Some(indent + 1),
+                column_end: Some(underline.trim_end().chars().count()),
+                replacement,
+                description,
+            })
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
+
+    fn fix_with_style(content: &str, style: &str) -> String {
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": style });
+        let violations = MD003.check(&parser, Some(&config));
+        apply_fixes(content, &violations)
+    }
+
+    #[test]
+    fn test_fix_closed_atx_to_atx() {
+        assert_eq!(
+            fix_with_style("# Title\n\n## Heading ##\n", "atx"),
+            "# Title\n\n## Heading\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_atx_to_closed_atx() {
+        assert_eq!(
+            fix_with_style("# Title #\n\n## Heading\n", "atx_closed"),
+            "# Title #\n\n## Heading ##\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_setext_to_atx() {
+        assert_eq!(
+            fix_with_style("Title\n=====\n\nSection\n-------\n\nText\n", "atx"),
+            "# Title\n\n## Section\n\nText\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_atx_to_setext() {
+        assert_eq!(
+            fix_with_style("# Title\n\n## Section\n\nText\n", "setext"),
+            "Title\n=====\n\nSection\n-------\n\nText\n"
+        );
+    }
+
+    fn fix_with_spacing_rules(content: &str, style: &str) -> String {
+        use crate::lint::rules::md009::MD009;
+        use crate::lint::rules::md021::MD021;
+        use crate::lint::rules::md022::MD022;
+
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": style });
+        let mut violations = MD003.check(&parser, Some(&config));
+        violations.extend(MD009.check(&parser, None));
+        violations.extend(MD021.check(&parser, None));
+        violations.extend(MD022.check(&parser, None));
+        apply_fixes(content, &violations)
+    }
+
+    #[test]
+    fn test_fix_setext_combines_with_md022() {
+        assert_eq!(
+            fix_with_spacing_rules("Title\n=====\nSome text   \n", "atx"),
+            "# Title\n\nSome text\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_closed_atx_combines_with_md021() {
+        assert_eq!(
+            fix_with_spacing_rules("# T\n\n## Title  ##\n\nSome text   \n", "atx"),
+            "# T\n\n## Title\n\nSome text\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_closed_atx_combines_with_md022() {
+        assert_eq!(
+            fix_with_spacing_rules("Text\n# Title #\n\nMore\n", "atx"),
+            "Text\n\n# Title\n\nMore\n"
+        );
+        assert_eq!(
+            fix_with_spacing_rules("Text\n## Title\nMore\n", "atx_closed"),
+            "Text\n\n## Title ##\n\nMore\n"
+        );
+        assert_eq!(
+            fix_with_spacing_rules("Text\n# Title\nMore\n", "setext"),
+            "Text\n\nTitle\n=====\n\nMore\n"
+        );
+    }
+
+    #[test]
+    fn test_no_atx_fix_for_multiline_setext() {
+        let content = "First line\nsecond line\n===========\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "atx" });
+        let violations = MD003.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_no_setext_fix_for_deep_headings() {
+        let content = "### Deep\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "setext" });
+        let violations = MD003.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_fix_consistent_uses_first_style() {
+        assert_eq!(
+            fix_with_style("# Title #\n\n## Heading\n", "consistent"),
+            "# Title #\n\n## Heading ##\n"
+        );
+    }
 
     #[test]
     fn test_consistent_atx() {
@@ -1,7 +1,8 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use pulldown_cmark::{Event, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD004;
 
@@ -21,16 +22,28 @@ impl Rule for MD004 {
         "Unordered list style should be consistent"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["ul-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["bullet", "ul"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "dash" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
             .and_then(|v| v.as_str())
             .unwrap_or("dash");
 
+        if style == "sublist" {
+            return self.check_sublist(parser);
+        }
+
         let mut violations = Vec::new();
         let mut first_marker: Option<ListMarker> = None;
         let code_block_lines = parser.get_code_block_line_numbers();
@@ -61,23 +74,16 @@ impl Rule for MD004 {
                     if let Some(first) = first_marker {
                         if current_marker != first {
                             let indent_len = line.len() - trimmed.len();
-                            let replacement = format!("{}- {}", &line[..indent_len], &trimmed[2..]);
                             violations.push(Violation {
                                 line: line_number,
                                 column: Some(indent_len + 1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "List marker style should be consistent (expected {:?}, found {:?})",
                                     first, current_marker
                                 ),
-                                fix: Some(Fix {
-                                    line_start: line_number,
-                                    line_end: line_number,
-                                    column_start: None,
-                                    column_end: None,
-                                    replacement,
-                                    description: "Replace list marker with dash".to_string(),
-                                }),
+                                fix: Some(marker_fix(line_number, indent_len, first)),
                             });
                         }
                     } else {
@@ -93,29 +99,13 @@ impl Rule for MD004 {
 
                     if current_marker != required_marker {
                         let indent_len = line.len() - trimmed.len();
-                        let replacement = format!(
-                            "{}{} {}",
-                            &line[..indent_len],
-                            match required_marker {
-                                ListMarker::Asterisk => "*",
-                                ListMarker::Plus => "+",
-                                ListMarker::Dash => "-",
-                            },
-                            &trimmed[2..]
-                        );
                         violations.push(Violation {
                             line: line_number,
                             column: Some(indent_len + 1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!("List marker style should be {:?}", required_marker),
-                            fix: Some(Fix {
-                                line_start: line_number,
-                                line_end: line_number,
-                                column_start: None,
-                                column_end: None,
-                                replacement,
-                                description: "Replace list marker with required style".to_string(),
-                            }),
+                            fix: Some(marker_fix(line_number, indent_len, required_marker)),
                         });
                     }
                 }
@@ -130,6 +120,107 @@ impl Rule for MD004 {
     }
 }
 
+impl MD004 {
+    /// Each nesting level of unordered lists uses its own marker, different from its
+    /// parent's; the first item seen at a level sets that level's marker.
+    fn check_sublist(&self, parser: &MarkdownParser) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let content = parser.content();
+        // Whether each open list is unordered, innermost last.
+        let mut lists: Vec<bool> = Vec::new();
+        let mut level_markers: Vec<ListMarker> = Vec::new();
+
+        for (event, range) in parser.parse_with_offsets() {
+            match event {
+                Event::Start(Tag::List(start)) => lists.push(start.is_none()),
+                Event::End(TagEnd::List(_)) => {
+                    lists.pop();
+                }
+                Event::Start(Tag::Item) if lists.last() == Some(&true) => {
+                    let depth = lists.iter().filter(|&&unordered| unordered).count();
+                    // The item's range may start with its indentation.
+                    let item = &content[range.start..];
+                    let marker_offset = range.start + (item.len() - item.trim_start().len());
+                    let Some(current) = content[marker_offset..]
+                        .chars()
+                        .next()
+                        .and_then(ListMarker::from_char)
+                    else {
+                        continue;
+                    };
+
+                    let expected = match level_markers.get(depth - 1) {
+                        Some(&marker) => marker,
+                        None => {
+                            let parent = depth.checked_sub(2).map(|i| level_markers[i]);
+                            let marker = if parent == Some(current) {
+                                // Reusing the parent's marker; take the first one unused above.
+                                [ListMarker::Asterisk, ListMarker::Plus, ListMarker::Dash]
+                                    .into_iter()
+                                    .find(|m| !level_markers.contains(m))
+                                    .unwrap_or(current)
+                            } else {
+                                current
+                            };
+                            level_markers.push(marker);
+                            marker
+                        }
+                    };
+
+                    if current != expected {
+                        let (line_number, byte_column) = parser.offset_to_position(marker_offset);
+                        let line = parser.lines()[line_number - 1];
+                        let indent_len = line[..byte_column - 1].chars().count();
+                        violations.push(Violation {
+                            line: line_number,
+                            column: Some(indent_len + 1),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: format!(
+                                "List marker style should be {:?} at nesting level {} (found {:?})",
+                                expected, depth, current
+                            ),
+                            fix: Some(marker_fix(line_number, indent_len, expected)),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        violations
+    }
+}
+
+impl ListMarker {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '*' => Some(ListMarker::Asterisk),
+            '+' => Some(ListMarker::Plus),
+            '-' => Some(ListMarker::Dash),
+            _ => None,
+        }
+    }
+}
+
+/// Replace just the marker character, leaving the rest of the line to other rules' fixes.
+fn marker_fix(line_number: usize, indent_len: usize, marker: ListMarker) -> Fix {
+    let column = indent_len + 1;
+    Fix {
+        line_start: line_number,
+        line_end: line_number,
+        column_start: Some(column),
+        column_end: Some(column),
+        replacement: match marker {
+            ListMarker::Asterisk => "*",
+            ListMarker::Plus => "+",
+            ListMarker::Dash => "-",
+        }
+        .to_string(),
+        description: format!("Replace list marker with {:?}", marker),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,4 +361,52 @@ value = 10 - 5  # subtraction
         assert_eq!(violations[0].line, 1); // Line with "* List item 1"
         assert_eq!(violations[1].line, 8); // Line with "+ List item 2"
     }
+
+    #[test]
+    fn test_fix_consistent_uses_first_marker() {
+        let content = "* Item 1\n- Item 2   \n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "consistent" });
+        let violations = MD004.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "* Item 1\n* Item 2   \n");
+    }
+
+    #[test]
+    fn test_sublist_alternating_levels() {
+        let content = "* One\n  + Two\n    - Three\n  + Two again\n* One again\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "style": "sublist" });
+        let violations = MD004.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_sublist_reused_parent_marker() {
+        let content = "* One\n  * Two\n* Three\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "style": "sublist" });
+        let violations = MD004.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (2, Some(3)));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "* One\n  + Two\n* Three\n");
+    }
+
+    #[test]
+    fn test_sublist_inconsistent_level() {
+        let content = "- One\n  * Two\n- Three\n  + Four\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "style": "sublist" });
+        let violations = MD004.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+        assert!(
+            violations[0]
+                .message
+                .contains("Asterisk at nesting level 2")
+        );
+    }
 }
@@ -1,11 +1,49 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag, TagEnd};
 use serde_json::Value;
-use std::collections::HashMap;
 
 pub struct MD005;
 
+/// The first item of a list sets the indentation its siblings must match.
+struct ListIndent {
+    ordered: bool,
+    expected: Option<ItemIndent>,
+}
+
+/// Where an item's marker and content sit on its line, as 0-based columns.
+#[derive(Clone, Copy)]
+struct ItemIndent {
+    /// Leading spaces before the marker.
+    indent: usize,
+    /// Column just past the marker.
+    marker_end: usize,
+    /// Column where the item's text starts, or `None` for an empty item.
+    content: Option<usize>,
+}
+
+impl ItemIndent {
+    /// Spaces between the marker and the item's text.
+    fn gap(&self) -> Option<usize> {
+        self.content.map(|content| content - self.marker_end)
+    }
+
+    /// Ordered markers grow as numbers gain digits (`9.` to `10.`), so an ordered
+    /// sibling matches either by lining its text up with the first item's or by
+    /// starting its marker in the same place with the same gap after it.
+    fn matches(&self, expected: &ItemIndent, ordered: bool) -> bool {
+        if !ordered {
+            return self.indent == expected.indent;
+        }
+        (self.content.is_some() && self.content == expected.content)
+            || (self.indent == expected.indent
+                && (self.gap().is_none()
+                    || expected.gap().is_none()
+                    || self.gap() == expected.gap()))
+    }
+}
+
 impl Rule for MD005 {
     fn name(&self) -> &str {
         "MD005"
@@ -15,85 +53,132 @@ impl Rule for MD005 {
         "Inconsistent indentation for list items at the same level"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["list-indent"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["bullet", "ul", "indentation"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
-        let mut level_indents: HashMap<usize, usize> = HashMap::new();
-        let mut prev_indent = 0;
-        let mut current_level = 0;
-
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-
-            // Check if this is a list item
-            let is_list_item = line.trim_start().starts_with("* ")
-                || line.trim_start().starts_with("+ ")
-                || line.trim_start().starts_with("- ")
-                || line
-                    .trim_start()
-                    .chars()
-                    .next()
-                    .map(|c| c.is_ascii_digit())
-                    .unwrap_or(false);
-
-            if !is_list_item {
-                continue;
-            }
+        let lines = parser.lines();
+        let mut lists: Vec<ListIndent> = Vec::new();
 
-            // Calculate indentation
-            let indent = line.len() - line.trim_start().len();
-
-            // Determine list level based on indentation
-            // Only consider it a new level if indented by at least 2 spaces more
-            if indent >= prev_indent + 2 {
-                current_level += 1;
-            } else if indent < prev_indent {
-                // Find the level for this indentation
-                current_level = level_indents
-                    .iter()
-                    .filter(|(_, i)| **i == indent)
-                    .map(|(l, _)| *l)
-                    .next()
-                    .unwrap_or(0);
-            }
-            // If indent is between prev_indent and prev_indent + 2, stay at same level
+        for (event, range) in parser.parse_with_offsets() {
+            match event {
+                Event::Start(Tag::List(start)) => lists.push(ListIndent {
+                    ordered: start.is_some(),
+                    expected: None,
+                }),
+                Event::End(TagEnd::List(_)) => {
+                    lists.pop();
+                }
+                Event::Start(Tag::Item) => {
+                    let Some(list) = lists.last_mut() else {
+                        continue;
+                    };
+                    let (line_number, column) = parser.offset_to_position(range.start);
+                    let line = lines[line_number - 1];
+                    let prefix = &line[..column - 1];
+                    // Items inside blockquotes or sharing a line with a parent item
+                    // aren't indented with plain whitespace; leave them alone.
+                    if !prefix.chars().all(|c| c == ' ') {
+                        continue;
+                    }
+                    // The item's range may start before or after its leading spaces.
+                    let rest = &line[column - 1..];
+                    let indent = prefix.len() + (rest.len() - rest.trim_start_matches(' ').len());
+                    let marker_end = indent + marker_len(&line[indent..]);
+                    let after_marker = &line[marker_end..];
+                    let text = after_marker.trim_start_matches([' ', '\t']);
+                    let item = ItemIndent {
+                        indent,
+                        marker_end,
+                        content: (!text.is_empty())
+                            .then(|| marker_end + after_marker.len() - text.len()),
+                    };
+
+                    let Some(expected) = list.expected else {
+                        list.expected = Some(item);
+                        continue;
+                    };
+                    if item.matches(&expected, list.ordered) {
+                        continue;
+                    }
 
-            // Check if this level has a recorded indentation
-            if let Some(&expected_indent) = level_indents.get(&current_level) {
-                if indent != expected_indent {
+                    if item.indent == expected.indent {
+                        // Only the gap after an ordered marker is off. Equal indents
+                        // always match when either item is empty, so both gaps exist.
+                        let (gap, expected_gap) = (item.gap().unwrap(), expected.gap().unwrap());
+                        violations.push(Violation {
+                            line: line_number,
+                            column: Some(marker_end + 1),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: format!(
+                                "List item content indentation mismatch: expected {} spaces after the marker, found {}",
+                                expected_gap, gap
+                            ),
+                            // Spacing after the marker is MD030's to fix.
+                            fix: None,
+                        });
+                        continue;
+                    }
+
+                    // Reindent by rewriting the leading spaces. An unindented item would
+                    // need its marker rewritten, which could collide with MD004's fix.
+                    let expected = expected.indent;
+                    let fix = (indent > 0).then(|| Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(1),
+                        column_end: Some(indent),
+                        replacement: " ".repeat(expected),
+                        description: format!("Indent list item by {} spaces", expected),
+                    });
                     violations.push(Violation {
                         line: line_number,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: format!(
                             "List item indentation mismatch: expected {} spaces, found {}",
-                            expected_indent, indent
+                            expected, indent
                         ),
-                        fix: None,
+                        fix,
                     });
                 }
-            } else {
-                // Record this level's indentation
-                level_indents.insert(current_level, indent);
+                _ => {}
             }
-
-            prev_indent = indent;
         }
 
         violations
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
     }
 }
 
+/// Length of the list marker (`-`, `*`, `+`, `1.`, `10)`) at the start of `text`.
+fn marker_len(text: &str) -> usize {
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 { digits + 1 } else { 1 }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_consistent_indentation() {
@@ -124,4 +209,75 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_fix_aligns_sibling_indented_by_one() {
+        let content = "* Item 1\n * Item 2\n* Item 3\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD005.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[0].message.contains("expected 0 spaces, found 1"));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "* Item 1\n* Item 2\n* Item 3\n");
+    }
+
+    #[test]
+    fn test_fix_nested_level() {
+        let content = "* Item\n  * Nested 1\n   * Nested 2\n* Item\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD005.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "* Item\n  * Nested 1\n  * Nested 2\n* Item\n");
+    }
+
+    #[test]
+    fn test_ordered_list_double_digit_content_aligned() {
+        // Left-aligned numbers with one space, and text aligned past a wider marker.
+        for content in ["9. Nine\n10. Ten\n", "9.  Nine\n10. Ten\n11. Eleven\n"] {
+            let parser = MarkdownParser::new(content);
+            let violations = MD005.check(&parser, None);
+            assert!(violations.is_empty(), "{content:?}: {violations:?}");
+        }
+    }
+
+    #[test]
+    fn test_ordered_list_double_digit_content_misaligned() {
+        let content = "8. Eight\n9. Nine\n10.  Ten\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD005.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(violations[0].column, Some(4));
+        assert!(
+            violations[0]
+                .message
+                .contains("expected 1 spaces after the marker, found 2")
+        );
+        assert!(violations[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_ordered_list_indented_double_digit_marker() {
+        let content = "9. Nine\n 10. Ten\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD005.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("expected 0 spaces, found 1"));
+    }
+
+    #[test]
+    fn test_ordered_list_right_aligned_numbers() {
+        let content = " 9. Nine\n10. Ten\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD005.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
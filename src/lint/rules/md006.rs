@@ -14,6 +14,10 @@ impl Rule for MD006 {
         "Consider starting bulleted lists at the beginning of the line"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["ul-start-left"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["bullet", "ul", "indentation"]
     }
@@ -1,7 +1,8 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use serde_json::Value;
+use pulldown_cmark::{Event, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD007;
 
@@ -14,71 +15,80 @@ impl Rule for MD007 {
         "Unordered list indentation"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["ul-indent"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["bullet", "ul", "indentation"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "indent": 2, "tab_width": 2 })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let indent_size = config
             .and_then(|c| c.get("indent"))
             .and_then(|v| v.as_u64())
             .unwrap_or(2) as usize;
 
-        let mut violations = Vec::new();
-        let mut list_depth = 0;
-        let mut prev_indent = 0;
-        let code_block_lines = parser.get_code_block_line_numbers();
-
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-
-            if code_block_lines.contains(&line_number) {
-                continue;
-            }
-
-            let trimmed = line.trim_start();
+        // Tabs in the indentation advance to the next tab stop; by default a tab
+        // counts as one nesting level.
+        let tab_width = config
+            .and_then(|c| c.get("tab_width"))
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(indent_size)
+            .max(1);
 
-            // Check if this is an unordered list item
-            let is_ul_item =
-                trimmed.starts_with("* ") || trimmed.starts_with("+ ") || trimmed.starts_with("- ");
+        let mut violations = Vec::new();
+        let lines = parser.lines();
+        // Whether each open list is ordered, and the open items enclosing the current one.
+        let mut lists: Vec<bool> = Vec::new();
+        let mut items: Vec<ItemPosition> = Vec::new();
 
-            if !is_ul_item {
-                if !line.trim().is_empty() && !trimmed.starts_with("  ") {
-                    // Reset depth when we leave the list
-                    list_depth = 0;
-                    prev_indent = 0;
+        for (event, range) in parser.parse_with_offsets() {
+            match event {
+                Event::Start(Tag::List(start)) => lists.push(start.is_some()),
+                Event::End(TagEnd::List(_)) => {
+                    lists.pop();
                 }
-                continue;
-            }
+                Event::Start(Tag::Item) => {
+                    let ordered = lists.last().copied().unwrap_or(false);
+                    let (line_number, item) =
+                        item_position(parser, lines, range.start, ordered, tab_width);
 
-            // Calculate indentation
-            let indent = line.len() - trimmed.len();
+                    if !ordered {
+                        // A bullet nests under its parent's content: past an ordered
+                        // marker such as `1. `, or `indent` past a bullet marker.
+                        let expected_indent = match items.last() {
+                            None => 0,
+                            Some(parent) if parent.ordered => parent.content,
+                            Some(parent) => parent.marker + indent_size,
+                        };
+                        if item.marker != expected_indent {
+                            violations.push(Violation {
+                                line: line_number,
+                                column: Some(item.column),
+                                end_column: None,
+                                rule: self.name().to_string(),
+                                message: format!(
+                                    "Unordered list indentation should be {} spaces (found {})",
+                                    expected_indent, item.marker
+                                ),
+                                fix: None,
+                            });
+                        }
+                    }
 
-            // Determine expected indentation based on depth
-            if indent > prev_indent {
-                // Going deeper
-                list_depth += 1;
-            } else if indent < prev_indent {
-                // Going shallower
-                list_depth = indent / indent_size;
-            }
-
-            let expected_indent = list_depth * indent_size;
-
-            if indent != expected_indent {
-                violations.push(Violation {
-                    line: line_number,
-                    column: Some(1),
-                    rule: self.name().to_string(),
-                    message: format!(
-                        "Unordered list indentation should be {} spaces (found {})",
-                        expected_indent, indent
-                    ),
-                    fix: None,
-                });
+                    items.push(item);
+                }
+                Event::End(TagEnd::Item) => {
+                    items.pop();
+                }
+                _ => {}
             }
-
-            prev_indent = indent;
         }
 
         violations
@@ -89,6 +99,80 @@ impl Rule for MD007 {
     }
 }
 
+/// Where a list item's marker and content sit, as visual widths from the start of
+/// its container (so a blockquote's `> ` is not counted).
+struct ItemPosition {
+    ordered: bool,
+    marker: usize,
+    content: usize,
+    /// 1-indexed char column of the marker on its line.
+    column: usize,
+}
+
+/// Locate the item starting at byte `offset`, returning its line number and position.
+fn item_position(
+    parser: &MarkdownParser,
+    lines: &[&str],
+    offset: usize,
+    ordered: bool,
+    tab_width: usize,
+) -> (usize, ItemPosition) {
+    // pulldown-cmark starts an item indented by a tab at the preceding newline.
+    let offset = if parser.content().as_bytes().get(offset) == Some(&b'\n') {
+        offset + 1
+    } else {
+        offset
+    };
+    let (line_number, byte_column) = parser.offset_to_position(offset);
+    let line = lines[line_number - 1];
+    let rest = &line[byte_column - 1..];
+    let marker_start = line.len() - rest.trim_start().len();
+
+    let before = &line[..marker_start];
+    let container = before
+        .rfind('>')
+        .map_or(0, |i| i + 1 + usize::from(before[i + 1..].starts_with(' ')));
+    let marker = expanded_indent(&before[container..], tab_width);
+
+    let after_marker = &line[marker_start..];
+    let marker_len = if ordered {
+        after_marker
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .count()
+            + 1
+    } else {
+        1
+    };
+    let spaces = after_marker[marker_len.min(after_marker.len())..]
+        .chars()
+        .take_while(|&c| c == ' ')
+        .count();
+    // Five or more spaces make the content an indented code block one space in.
+    let spaces = if (1..=4).contains(&spaces) { spaces } else { 1 };
+
+    (
+        line_number,
+        ItemPosition {
+            ordered,
+            marker,
+            content: marker + marker_len + spaces,
+            column: before.chars().count() + 1,
+        },
+    )
+}
+
+/// Width of leading whitespace with tabs expanded to `tab_width` stops.
+fn expanded_indent(whitespace: &str, tab_width: usize) -> usize {
+    whitespace.chars().fold(0, |width, c| {
+        if c == '\t' {
+            (width / tab_width + 1) * tab_width
+        } else {
+            width + 1
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,4 +217,67 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_tab_indented_nested_list() {
+        let content = "* Item\n\t* Nested\n\t\t* Double nested\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD007.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_tab_width_wider_than_indent() {
+        let content = "* Item\n\t* Nested\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "indent": 2, "tab_width": 4 });
+        let violations = MD007.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Unordered list indentation should be 2 spaces (found 4)"
+        );
+    }
+
+    #[test]
+    fn test_tab_after_spaces_advances_to_next_stop() {
+        assert_eq!(expanded_indent(" \t", 4), 4);
+        assert_eq!(expanded_indent("\t  ", 4), 6);
+        assert_eq!(expanded_indent("\t\t", 2), 4);
+    }
+
+    #[test]
+    fn test_bullet_under_ordered_parent() {
+        let content = "1. One\n   - Nested\n10. Ten\n    - Nested\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD007.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_bullet_under_ordered_parent_misaligned() {
+        let content = "1. One\n    - Nested\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD007.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(5));
+        assert_eq!(
+            violations[0].message,
+            "Unordered list indentation should be 3 spaces (found 4)"
+        );
+    }
+
+    #[test]
+    fn test_nested_list_in_blockquote() {
+        let content = "> * Item\n>   * Nested\n>    * Wrong\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD007.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD009;
 
@@ -14,10 +14,18 @@ impl Rule for MD009 {
         "Trailing spaces"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-trailing-spaces"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "br_spaces": 2, "strict": false, "code_blocks": false })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let br_spaces = config
             .and_then(|c| c.get("br_spaces"))
@@ -29,11 +37,25 @@ impl Rule for MD009 {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
+        // Trailing whitespace in code is usually content, so code blocks are skipped
+        // unless asked for.
+        let code_blocks = config
+            .and_then(|c| c.get("code_blocks"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let code_block_lines = parser.get_code_block_line_numbers();
+
         let mut violations = Vec::new();
 
         for (line_num, line) in parser.lines().iter().enumerate() {
+            if !code_blocks && code_block_lines.contains(&(line_num + 1)) {
+                continue;
+            }
+
             let trimmed = line.trim_end();
-            let trailing_spaces = line.len() - trimmed.len();
+            // Counted in chars to match fix columns; the run may hold non-ASCII
+            // whitespace such as a no-break space.
+            let trailing_spaces = line[trimmed.len()..].chars().count();
 
             if trailing_spaces > 0 {
                 // Allow br_spaces for line breaks unless strict mode
@@ -41,16 +63,19 @@ impl Rule for MD009 {
                     continue;
                 }
 
+                let content_chars = trimmed.chars().count();
+
                 violations.push(Violation {
                     line: line_num + 1,
-                    column: Some(trimmed.len() + 1),
+                    column: Some(content_chars + 1),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: format!("Trailing spaces ({} spaces)", trailing_spaces),
                     fix: Some(Fix {
                         line_start: line_num + 1,
                         line_end: line_num + 1,
-                        column_start: Some(trimmed.len() + 1),
-                        column_end: Some(line.len()),
+                        column_start: Some(content_chars + 1),
+                        column_end: Some(content_chars + trailing_spaces),
                         replacement: String::new(),
                         description: "Remove trailing spaces".to_string(),
                     }),
@@ -132,4 +157,61 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "Line 1\nLine 2\n");
     }
+
+    #[test]
+    fn test_message_reports_count() {
+        let content = "Line 1     \n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD009.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "Trailing spaces (5 spaces)");
+    }
+
+    #[test]
+    fn test_fix_preserves_br_spaces_line() {
+        let content = "Break here  \nToo many   \nEnd\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD009.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Break here  \nToo many\nEnd\n");
+    }
+
+    #[test]
+    fn test_fix_multibyte_line() {
+        let content = "Café über   \n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD009.check(&parser, None);
+        assert_eq!(violations[0].column, Some(10));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Café über\n");
+    }
+
+    #[test]
+    fn test_code_blocks_option() {
+        let content = "Prose   \n\n```\nkeep   \n```\n\n    indented   \n";
+        let parser = MarkdownParser::new(content);
+
+        let violations = MD009.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+
+        let config = serde_json::json!({ "code_blocks": true });
+        let violations = MD009.check(&parser, Some(&config));
+        let lines: Vec<_> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn test_fix_trailing_no_break_space() {
+        let content = "Text\u{a0}\u{a0} \nNext\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD009.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(5));
+        assert!(violations[0].message.contains("3 spaces"));
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!((fix.column_start, fix.column_end), (Some(5), Some(7)));
+        assert_eq!(apply_fixes(content, &violations), "Text\nNext\n");
+    }
 }
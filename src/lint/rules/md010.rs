@@ -1,8 +1,9 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use pulldown_cmark::{CodeBlockKind, Event, Tag};
+use serde_json::{Value, json};
+use std::collections::HashSet;
 
 pub struct MD010;
 
@@ -15,40 +16,41 @@ impl Rule for MD010 {
         "Hard tabs"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-hard-tabs"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace", "hard_tab"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "code_blocks": true, "spaces_per_tab": 4 })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let code_blocks = config
             .and_then(|c| c.get("code_blocks"))
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
+        let spaces_per_tab = config
+            .and_then(|c| c.get("spaces_per_tab"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(4)
+            .max(1) as usize;
 
         let mut violations = Vec::new();
-        let mut in_code_block = false;
-
-        // Track code blocks using the parser
-        let mut code_block_lines = std::collections::HashSet::new();
-
-        if !code_blocks {
-            for (event, range) in parser.parse_with_offsets() {
-                let current_line = parser.offset_to_line(range.start);
-
-                match event {
-                    Event::Start(Tag::CodeBlock(_)) => {
-                        in_code_block = true;
-                    }
-                    Event::End(TagEnd::CodeBlock) => {
-                        in_code_block = false;
-                    }
-                    Event::Text(_) if in_code_block => {
-                        code_block_lines.insert(current_line);
-                    }
-                    _ => {}
+        let code_block_lines = parser.get_code_block_line_numbers();
+        let inline_code = parser.get_inline_code_ranges();
+        let fence_lines: HashSet<usize> = parser
+            .parse_with_offsets()
+            .filter_map(|(event, range)| match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => {
+                    Some(parser.offset_to_line(range.start))
                 }
-            }
-        }
+                _ => None,
+            })
+            .collect();
 
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
@@ -58,18 +60,44 @@ impl Rule for MD010 {
                 continue;
             }
 
-            if let Some(tab_pos) = line.find('\t') {
+            // Tabs inside code spans and after an opening fence (its info string) are
+            // content rather than layout.
+            let line_start = parser.line_offset_to_absolute(line_number, 0);
+            let info_start = if fence_lines.contains(&line_number) {
+                line.len() - line.trim_start().len()
+            } else {
+                line.len()
+            };
+            let tabs: Vec<usize> = line
+                .char_indices()
+                .enumerate()
+                .filter(|&(_, (offset, c))| {
+                    c == '\t'
+                        && offset < info_start
+                        && !inline_code
+                            .iter()
+                            .any(|range| range.contains(&(line_start + offset)))
+                })
+                .map(|(index, _)| index)
+                .collect();
+
+            let chars: Vec<char> = line.chars().collect();
+            if let (Some(&first), Some(&last)) = (tabs.first(), tabs.last()) {
+                // Only the span from the first to the last tab is rewritten, so fixes
+                // from other rules elsewhere on the line (e.g. MD009) still apply.
+                let span = expand_tabs(&chars, first, last, spaces_per_tab, &tabs);
                 violations.push(Violation {
                     line: line_number,
-                    column: Some(tab_pos + 1),
+                    column: Some(first + 1),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: "Hard tabs found".to_string(),
                     fix: Some(Fix {
                         line_start: line_number,
                         line_end: line_number,
-                        column_start: None,
-                        column_end: None,
-                        replacement: line.replace('\t', "    "),
+                        column_start: Some(first + 1),
+                        column_end: Some(last + 1),
+                        replacement: span,
                         description: "Replace tabs with spaces".to_string(),
                     }),
                 });
@@ -84,6 +112,35 @@ impl Rule for MD010 {
     }
 }
 
+/// `chars[first..=last]` with each tab listed in `tabs` expanded to the next multiple
+/// of `tab_width`, measured from the start of the line. Other tabs are kept.
+fn expand_tabs(
+    chars: &[char],
+    first: usize,
+    last: usize,
+    tab_width: usize,
+    tabs: &[usize],
+) -> String {
+    // Visual column before `first`, counting each earlier char (even a kept tab in a
+    // code span) as one column.
+    let mut column = first;
+    let mut expanded = String::new();
+    for (index, &c) in chars.iter().enumerate().take(last + 1).skip(first) {
+        if c == '\t' && !tabs.contains(&index) {
+            expanded.push(c);
+            column = (column / tab_width + 1) * tab_width;
+        } else if c == '\t' {
+            let width = tab_width - column % tab_width;
+            expanded.push_str(&" ".repeat(width));
+            column += width;
+        } else {
+            expanded.push(c);
+            column += 1;
+        }
+    }
+    expanded
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +210,61 @@ mod tests {
             "tab should become 4 spaces"
         );
     }
+
+    #[test]
+    fn test_mid_line_tab_column() {
+        let content = "Some\ttext\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD010.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(5));
+    }
+
+    #[test]
+    fn test_fix_expands_every_tab_to_tab_stops() {
+        let content = "a\tbc\td\t\te\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD010.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(apply_fixes(content, &violations), "a   bc  d       e\n");
+    }
+
+    #[test]
+    fn test_spaces_per_tab() {
+        let content = "\tx\ty\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "spaces_per_tab": 2 });
+        let violations = MD010.check(&parser, Some(&config));
+        assert_eq!(apply_fixes(content, &violations), "  x y\n");
+    }
+
+    #[test]
+    fn test_tab_in_code_span_is_ignored() {
+        let content = "Use `a\tb` here\n\tIndented prose\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD010.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+    }
+
+    #[test]
+    fn test_fix_keeps_code_span_tabs() {
+        let content = "a\t`x\ty`\tb\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD010.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(apply_fixes(content, &violations), "a   `x\ty`  b\n");
+    }
+
+    #[test]
+    fn test_tab_in_fence_info_string_is_ignored() {
+        let content = "```rust\tignore\ncode\n```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD010.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
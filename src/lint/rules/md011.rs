@@ -1,9 +1,10 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
-use pulldown_cmark::{Event, Tag, TagEnd};
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, LinkType, Tag};
 use regex::Regex;
 use serde_json::Value;
+use std::collections::HashSet;
 
 pub struct MD011;
 
@@ -16,6 +17,10 @@ impl Rule for MD011 {
         "Reversed link syntax"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-reversed-links"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
@@ -23,56 +28,61 @@ impl Rule for MD011 {
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
 
-        // Track code blocks to exclude them from checking
-        let mut code_block_lines = std::collections::HashSet::new();
-        let mut in_code_block = false;
-
-        for (event, range) in parser.parse_with_offsets() {
-            match event {
-                Event::Start(Tag::CodeBlock(_)) => {
-                    in_code_block = true;
-                }
-                Event::End(TagEnd::CodeBlock) => {
-                    in_code_block = false;
-                }
-                Event::Text(_) if in_code_block => {
-                    // Mark all lines that this text event spans
-                    let start_line = parser.offset_to_line(range.start);
-                    let end_line = parser.offset_to_line(range.end.saturating_sub(1));
-                    for line in start_line..=end_line {
-                        code_block_lines.insert(line);
-                    }
-                }
-                _ => {}
-            }
-        }
+        // Byte offsets where a reference-style link starts. `(see)[ref]` with a defined
+        // `[ref]` is parenthesized text followed by a real link, not a reversed link.
+        let reference_link_starts: HashSet<usize> = parser
+            .parse_with_offsets()
+            .filter_map(|(event, range)| match event {
+                Event::Start(Tag::Link {
+                    link_type: LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut,
+                    ..
+                }) => Some(range.start),
+                _ => None,
+            })
+            .collect();
+        let code_ranges = parser.get_code_ranges();
 
         // Pattern for reversed link syntax: (text)[url]
         // Capture the bracket content so we can exclude GFM task list checkboxes ([ ], [x], [X])
-        let re = Regex::new(r"\([^)]+\)\[([^\]]+)\]").unwrap();
+        let re = Regex::new(r"\(([^)]+)\)\[([^\]]+)\]").unwrap();
 
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
 
-            // Skip code blocks
-            if code_block_lines.contains(&line_number) {
-                continue;
-            }
-
             for caps in re.captures_iter(line) {
                 // Skip GFM task list checkboxes: [ ] and [x]/[X]
-                let bracket_content = &caps[1];
-                if matches!(bracket_content, " " | "x" | "X") {
+                let (text, url) = (&caps[1], &caps[2]);
+                if matches!(url, " " | "x" | "X") {
                     continue;
                 }
                 let m = caps.get(0).unwrap();
+                let start = parser.line_offset_to_absolute(line_number, m.start());
+                if code_ranges.iter().any(|r| r.contains(&start)) {
+                    continue;
+                }
+                let bracket = start + text.len() + 2;
+                if reference_link_starts.contains(&bracket) {
+                    continue;
+                }
+
+                // Fix columns are char-based and inclusive.
+                let column_start = line[..m.start()].chars().count() + 1;
+                let column_end = line[..m.end()].chars().count();
                 violations.push(Violation {
                     line: line_number,
-                    column: Some(m.start() + 1),
+                    column: Some(column_start),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: "Reversed link syntax (found '(text)[url]', should be '[text](url)')"
                         .to_string(),
-                    fix: None,
+                    fix: Some(Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(column_start),
+                        column_end: Some(column_end),
+                        replacement: format!("[{}]({})", text, url),
+                        description: "Swap brackets and parentheses".to_string(),
+                    }),
                 });
             }
         }
@@ -81,13 +91,21 @@ impl Rule for MD011 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_correct_link_syntax() {
@@ -171,4 +189,33 @@ This (is)[wrong] though.
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].line, 8);
     }
+
+    #[test]
+    fn test_fix_swaps_reversed_link() {
+        let content = "Café (a link)[http://example.com] here.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD011.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(6));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Café [a link](http://example.com) here.\n");
+    }
+
+    #[test]
+    fn test_reference_link_not_flagged() {
+        let content = "See [the docs][ref] and (details)[ref].\n\n[ref]: https://example.com\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD011.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_inline_code_not_flagged() {
+        let content = "Call `items(0)[key]` to index.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD011.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD012;
 
@@ -14,16 +14,29 @@ impl Rule for MD012 {
         "Multiple consecutive blank lines"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-multiple-blanks"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace", "blank_lines"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "maximum": 1, "allow_trailing": false })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let maximum = config
             .and_then(|c| c.get("maximum"))
             .and_then(|v| v.as_u64())
             .unwrap_or(1) as usize;
 
+        let allow_trailing = config
+            .and_then(|c| c.get("allow_trailing"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         let mut violations = Vec::new();
         let mut consecutive_blank = 0;
         let mut blank_start_line = 0;
@@ -43,8 +56,12 @@ impl Rule for MD012 {
                         violations.push(Violation {
                             line: blank_start_line + i,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
-                            message: format!("Expected: {}; Actual: {}", 1, consecutive_blank),
+                            message: format!(
+                                "Expected: {}; Actual: {}",
+                                maximum, consecutive_blank
+                            ),
                             fix: Some(Fix {
                                 line_start: blank_start_line + i,
                                 line_end: blank_start_line + i,
@@ -60,25 +77,21 @@ impl Rule for MD012 {
             }
         }
 
-        // Check if file ends with too many blank lines
-        if consecutive_blank > maximum {
-            // Report a violation for each excess blank line
-            for i in maximum..consecutive_blank {
-                violations.push(Violation {
-                    line: blank_start_line + i,
-                    column: Some(1),
-                    rule: self.name().to_string(),
-                    message: format!("Expected: {}; Actual: {}", 1, consecutive_blank),
-                    fix: Some(Fix {
-                        line_start: blank_start_line + i,
-                        line_end: blank_start_line + i,
-                        column_start: None,
-                        column_end: None,
-                        replacement: String::new(),
-                        description: "Remove excess blank line".to_string(),
-                    }),
-                });
-            }
+        // `lines()` drops the final newline, so a run still open here is made of real
+        // blank lines at EOF. Removing them is MD047's fix; reporting the run once
+        // without a fix keeps the two rules from producing overlapping fixes.
+        if !allow_trailing && consecutive_blank > maximum {
+            violations.push(Violation {
+                line: blank_start_line + maximum,
+                column: Some(1),
+                end_column: None,
+                rule: self.name().to_string(),
+                message: format!(
+                    "Expected: {}; Actual: {} (at end of file)",
+                    maximum, consecutive_blank
+                ),
+                fix: None,
+            });
         }
 
         violations
@@ -153,4 +166,48 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "Line 1\n\nLine 2\n");
     }
+
+    #[test]
+    fn test_single_trailing_newline_is_clean() {
+        let content = "Line 1\n\nLine 2\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD012.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_three_trailing_blank_lines() {
+        let content = "Line 1\n\n\n\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD012.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        // Blank lines are 2-4; line 3 is the first beyond the maximum of one.
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].line <= parser.lines().len());
+        assert!(violations[0].message.contains("Actual: 3"));
+        // Left to MD047 so the two rules' fixes don't overlap.
+        assert!(violations[0].fix.is_none());
+    }
+
+    #[test]
+    fn test_allow_trailing() {
+        let content = "Line 1\n\n\n\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "allow_trailing": true });
+        let violations = MD012.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_fix_alongside_other_rule_fixes() {
+        let content = "# Title\n\nA\n\n  \n\nB   \n";
+        let parser = MarkdownParser::new(content);
+        let mut violations = MD012.check(&parser, None);
+        violations.extend(crate::lint::rules::MD009.check(&parser, None));
+
+        assert_eq!(apply_fixes(content, &violations), "# Title\n\nA\n\nB\n");
+    }
 }
@@ -2,7 +2,7 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
 use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 use std::collections::HashSet;
 
 pub struct MD013;
@@ -16,10 +16,26 @@ impl Rule for MD013 {
         "Line length"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["line-length"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["line_length"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({
+            "line_length": 120,
+            "heading_line_length": 80,
+            "code_blocks": true,
+            "tables": true,
+            "headings": true,
+            "strict": false,
+            "stern": false,
+        })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let line_length = config
             .and_then(|c| c.get("line_length"))
@@ -47,6 +63,16 @@ impl Rule for MD013 {
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
 
+        let strict = config
+            .and_then(|c| c.get("strict"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let stern = config
+            .and_then(|c| c.get("stern"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         let mut violations = Vec::new();
 
         // Track special lines (headings, code blocks, tables, links/images)
@@ -63,7 +89,10 @@ impl Rule for MD013 {
 
             match event {
                 Event::Start(Tag::Heading { .. }) => {
-                    heading_lines.insert(line);
+                    // A setext heading's range also covers its `===`/`---` underline.
+                    let end_line =
+                        parser.offset_to_line(range.end.saturating_sub(1).max(range.start));
+                    heading_lines.extend(line..=end_line);
                 }
                 Event::Start(Tag::CodeBlock(_)) => {
                     in_code_block = true;
@@ -107,8 +136,17 @@ impl Rule for MD013 {
             let is_table = table_lines.contains(&line_number);
             let is_link_only = link_only_lines.contains(&line_number);
 
-            // Skip lines that only contain links or images (can't be shortened)
-            if is_link_only {
+            // Determine the limit for this line
+            let limit = if is_heading {
+                heading_line_length
+            } else {
+                line_length
+            };
+
+            // Skip lines that only contain links or images (can't be shortened),
+            // and in stern mode any line without whitespace to wrap at past the
+            // limit. Strict mode reports every overlong line.
+            if !strict && (is_link_only || (stern && !has_break_point(line, limit))) {
                 continue;
             }
 
@@ -123,17 +161,12 @@ impl Rule for MD013 {
                 continue;
             }
 
-            // Determine the limit for this line
-            let limit = if is_heading {
-                heading_line_length
-            } else {
-                line_length
-            };
-
             if line_len > limit {
+                // Columns count characters, so multibyte text isn't over-reported.
                 violations.push(Violation {
                     line: line_number,
                     column: Some(limit + 1),
+                    end_column: Some(line_len),
                     rule: self.name().to_string(),
                     message: format!("Line exceeds maximum length ({} > {})", line_len, limit),
                     fix: None,
@@ -149,6 +182,12 @@ impl Rule for MD013 {
     }
 }
 
+/// Whether the line has whitespace past its first `limit` characters, where it
+/// could be wrapped to bring the overflow onto the next line.
+fn has_break_point(line: &str, limit: usize) -> bool {
+    line.trim_end().chars().skip(limit).any(char::is_whitespace)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,4 +318,100 @@ mod tests {
             "Lines with text and links should still be checked"
         );
     }
+
+    const LONG_URL: &str =
+        "https://example.com/a/very/long/path/that/cannot/be/wrapped/anywhere/at/all";
+
+    #[test]
+    fn test_link_only_line_flagged_when_strict() {
+        let content = format!("[Reference]({LONG_URL})\n");
+        let parser = MarkdownParser::new(&content);
+        let check = |config: Value| MD013.check(&parser, Some(&config)).len();
+
+        assert_eq!(check(json!({ "line_length": 40 })), 0);
+        assert_eq!(check(json!({ "line_length": 40, "stern": true })), 0);
+        assert_eq!(check(json!({ "line_length": 40, "strict": true })), 1);
+    }
+
+    #[test]
+    fn test_unbreakable_line_allowed_when_stern() {
+        let content = format!("{LONG_URL}\n\n- {LONG_URL}\n\n> {LONG_URL}\n");
+        let parser = MarkdownParser::new(&content);
+        let check = |config: Value| MD013.check(&parser, Some(&config)).len();
+
+        assert_eq!(check(json!({ "line_length": 40 })), 3);
+        assert_eq!(check(json!({ "line_length": 40, "stern": true })), 0);
+        assert_eq!(check(json!({ "line_length": 40, "strict": true })), 3);
+    }
+
+    #[test]
+    fn test_breakable_long_line_flagged_in_all_modes() {
+        let content = format!("{LONG_URL} and more\n");
+        let parser = MarkdownParser::new(&content);
+        let check = |config: Value| MD013.check(&parser, Some(&config)).len();
+
+        assert_eq!(check(json!({ "line_length": 40 })), 1);
+        assert_eq!(check(json!({ "line_length": 40, "stern": true })), 1);
+        assert_eq!(check(json!({ "line_length": 40, "strict": true })), 1);
+    }
+
+    #[test]
+    fn test_whitespace_before_limit_allowed_when_stern() {
+        let content = format!("See {LONG_URL}\n");
+        let parser = MarkdownParser::new(&content);
+        let check = |config: Value| MD013.check(&parser, Some(&config)).len();
+
+        assert_eq!(check(json!({ "line_length": 40 })), 1);
+        assert_eq!(check(json!({ "line_length": 40, "stern": true })), 0);
+        assert_eq!(check(json!({ "line_length": 40, "strict": true })), 1);
+    }
+
+    #[test]
+    fn test_setext_underline_uses_heading_limit() {
+        let text = "word ".repeat(18).trim_end().to_string();
+        let content = format!("{}\n{}\n\nBody\n", text, "=".repeat(text.len()));
+        let parser = MarkdownParser::new(&content);
+        let config = serde_json::json!({ "line_length": 50, "heading_line_length": 100 });
+        let violations = MD013.check(&parser, Some(&config));
+        assert!(violations.is_empty(), "{violations:?}");
+
+        let config = serde_json::json!({ "line_length": 120, "heading_line_length": 80 });
+        let violations = MD013.check(&parser, Some(&config));
+        let lines: Vec<_> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![1, 2]);
+        assert!(violations.iter().all(|v| v.message.contains("> 80")));
+
+        let config = serde_json::json!({ "line_length": 50, "headings": false });
+        assert!(MD013.check(&parser, Some(&config)).is_empty());
+    }
+
+    #[test]
+    fn test_multiline_setext_heading_skipped_when_headings_disabled() {
+        let text = "word ".repeat(18).trim_end().to_string();
+        let content = format!(
+            "{}\n{}\n{}\n\n{}\n",
+            text,
+            text,
+            "-".repeat(text.len()),
+            text
+        );
+        let parser = MarkdownParser::new(&content);
+        let config = serde_json::json!({ "line_length": 50, "headings": false });
+        let violations = MD013.check(&parser, Some(&config));
+        let lines: Vec<_> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![5]);
+    }
+
+    #[test]
+    fn test_multibyte_line_columns_are_characters() {
+        let content = format!("{}🎉🎉🎉", "é".repeat(8));
+        let parser = MarkdownParser::new(&content);
+        let config = serde_json::json!({ "line_length": 10 });
+        let violations = MD013.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(11));
+        assert_eq!(violations[0].end_column, Some(11));
+        assert!(violations[0].message.contains("(11 > 10)"));
+    }
 }
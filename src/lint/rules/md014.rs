@@ -2,7 +2,7 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
 use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD014;
 
@@ -15,11 +15,34 @@ impl Rule for MD014 {
         "Dollar signs used before commands without showing output"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["commands-show-output"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["code"]
     }
 
-    fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+    fn default_config(&self) -> Value {
+        json!({
+            "languages": DEFAULT_LANGUAGES,
+            "prompts": ["$"],
+            "console": true,
+        })
+    }
+
+    fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        let languages: Vec<String> = string_list(config, "languages")
+            .unwrap_or_else(|| DEFAULT_LANGUAGES.iter().map(|l| l.to_string()).collect())
+            .iter()
+            .map(|l| l.to_lowercase())
+            .collect();
+        let prompts = string_list(config, "prompts").unwrap_or_else(|| vec!["$".to_string()]);
+        let check_console = config
+            .and_then(|c| c.get("console"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
         let mut violations = Vec::new();
         let mut in_shell_code_block = false;
         let mut code_block_start_line = 0;
@@ -27,12 +50,15 @@ impl Rule for MD014 {
 
         for (event, range) in parser.parse_with_offsets() {
             match event {
-                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(lang))) => {
-                    let lang_str = lang.to_string().to_lowercase();
-                    in_shell_code_block = lang_str == "bash"
-                        || lang_str == "sh"
-                        || lang_str == "shell"
-                        || lang_str == "console";
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                    // The language is the first word of the info string.
+                    let lang = info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_lowercase();
+                    in_shell_code_block =
+                        languages.contains(&lang) && (check_console || lang != "console");
                     if in_shell_code_block {
                         code_block_start_line = parser.offset_to_line(range.start);
                         code_block_lines.clear();
@@ -52,30 +78,28 @@ impl Rule for MD014 {
                         .collect();
 
                     if !non_empty_lines.is_empty() {
-                        let all_start_with_dollar = non_empty_lines
+                        let all_start_with_prompt = non_empty_lines
                             .iter()
-                            .all(|line| line.trim_start().starts_with('$'));
-
-                        if all_start_with_dollar {
-                            // Report a violation for each line that starts with $
-                            let mut current_line = code_block_start_line + 1;
-                            for line in &lines {
-                                if !line.trim().is_empty() && line.trim_start().starts_with('$') {
-                                    // Remove leading $ and any spaces after it
-                                    let trimmed = line.trim_start();
-                                    let after_dollar = trimmed.strip_prefix('$').unwrap();
-                                    let after_dollar_trimmed = after_dollar.trim_start();
-                                    // Preserve leading whitespace before $
-                                    let leading_spaces = line.len() - trimmed.len();
+                            .all(|line| strip_prompt(line, &prompts).is_some());
+
+                        if all_start_with_prompt {
+                            // Report a violation for each line that starts with a prompt
+                            for (current_line, line) in
+                                (code_block_start_line + 1..).zip(lines.iter())
+                            {
+                                if let Some(command) = strip_prompt(line, &prompts) {
+                                    // Preserve leading whitespace before the prompt
+                                    let leading_spaces = line.len() - line.trim_start().len();
                                     let replacement = format!(
                                         "{}{}",
                                         " ".repeat(leading_spaces),
-                                        after_dollar_trimmed
+                                        command.trim_start()
                                     );
 
                                     violations.push(Violation {
                                         line: current_line,
                                         column: Some(1),
+                                        end_column: None,
                                         rule: self.name().to_string(),
                                         message:
                                             "Dollar signs should not be used before commands without showing output"
@@ -90,7 +114,6 @@ impl Rule for MD014 {
                                         }),
                                     });
                                 }
-                                current_line += 1;
                             }
                         }
                     }
@@ -110,6 +133,31 @@ impl Rule for MD014 {
     }
 }
 
+const DEFAULT_LANGUAGES: [&str; 4] = ["bash", "sh", "shell", "console"];
+
+fn string_list(config: Option<&Value>, key: &str) -> Option<Vec<String>> {
+    config
+        .and_then(|c| c.get(key))
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+}
+
+/// The rest of a non-blank line after its prompt, or `None` if it has no prompt.
+fn strip_prompt<'a>(line: &'a str, prompts: &[String]) -> Option<&'a str> {
+    let trimmed = line.trim_start();
+    if trimmed.trim().is_empty() {
+        return None;
+    }
+    prompts
+        .iter()
+        .filter(|prompt| !prompt.is_empty())
+        .find_map(|prompt| trimmed.strip_prefix(prompt.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +222,46 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "```bash\nls -la\necho hello\n```\n");
     }
+
+    #[test]
+    fn test_custom_language_list() {
+        let content = "```zsh\n$ ls\n```\n\n```bash\n$ ls\n```\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "languages": ["zsh"] });
+        let violations = MD014.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+    }
+
+    #[test]
+    fn test_custom_prompt() {
+        let content = "```zsh\n% ls -la\n% echo hello\n```\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "languages": ["zsh"], "prompts": ["%"] });
+        let violations = MD014.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "```zsh\nls -la\necho hello\n```\n");
+    }
+
+    #[test]
+    fn test_console_blocks_can_be_skipped() {
+        let content = "```console\n$ ls\n```\n\n```sh\n$ ls\n```\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "console": false });
+        let violations = MD014.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 6);
+    }
+
+    #[test]
+    fn test_language_from_info_string_first_word() {
+        let content = "```bash title=\"setup\"\n$ make\n```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD014.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+    }
 }
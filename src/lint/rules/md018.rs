@@ -14,6 +14,10 @@ impl Rule for MD018 {
         "No space after hash on atx style heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-missing-space-atx"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers", "atx", "spaces"]
     }
@@ -37,9 +41,11 @@ impl Rule for MD018 {
                 // Valid heading should have 1-6 hashes
                 if hash_count > 0 && hash_count <= 6 {
                     // Check character after the hashes
+                    // Closed headings missing the opening space are reported by MD020.
                     if let Some(next_char) = trimmed.chars().nth(hash_count)
                         && !next_char.is_whitespace()
                         && next_char != '#'
+                        && !trimmed.ends_with('#')
                     {
                         // Insert space after the hashes
                         let hashes = "#".repeat(hash_count);
@@ -49,6 +55,7 @@ impl Rule for MD018 {
                         violations.push(Violation {
                             line: line_number,
                             column: Some(hash_count + 1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: "No space after hash on atx style heading".to_string(),
                             fix: Some(Fix {
@@ -146,4 +153,14 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Heading\n\n## Another\n");
     }
+
+    #[test]
+    fn test_closed_heading_without_space_left_to_md020() {
+        let content = "###Heading ###";
+        let parser = MarkdownParser::new(content);
+        let rule = MD018;
+        let violations = rule.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
@@ -1,7 +1,9 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag};
 use serde_json::Value;
+use std::collections::BTreeSet;
 
 pub struct MD019;
 
@@ -14,60 +16,65 @@ impl Rule for MD019 {
         "Multiple spaces after hash on atx style heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-multiple-space-atx"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers", "atx", "spaces"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
-        let code_block_lines = parser.get_code_block_line_numbers();
-
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-            if code_block_lines.contains(&line_number) {
+        let lines = parser.lines();
+
+        // Only lines the AST confirms as headings; closed-ATX spacing before the
+        // closing hashes belongs to MD021.
+        let heading_lines: BTreeSet<usize> = parser
+            .parse_with_offsets()
+            .filter(|(event, _)| matches!(event, Event::Start(Tag::Heading { .. })))
+            .map(|(_, range)| parser.offset_to_line(range.start))
+            .collect();
+
+        for line_number in heading_lines {
+            let line = lines[line_number - 1];
+            let trimmed = line.trim_start();
+            let indent = line.chars().count() - trimmed.chars().count();
+
+            let hash_count = trimmed.chars().take_while(|&c| c == '#').count();
+            if hash_count == 0 || hash_count > 6 {
                 continue;
             }
-            let trimmed = line.trim();
-
-            // Check for ATX heading with multiple spaces after hash
-            if trimmed.starts_with('#') {
-                // Count leading hashes
-                let hash_count = trimmed.chars().take_while(|&c| c == '#').count();
-
-                // Valid heading should have 1-6 hashes
-                if hash_count > 0 && hash_count <= 6 && trimmed.len() > hash_count {
-                    let after_hashes = &trimmed[hash_count..];
-
-                    // Count spaces after hashes
-                    let space_count = after_hashes.chars().take_while(|&c| c == ' ').count();
-
-                    if space_count > 1 {
-                        // Replace multiple spaces with single space
-                        let hashes = "#".repeat(hash_count);
-                        let rest = after_hashes[space_count..].trim_start();
-                        let replacement = format!("{} {}", hashes, rest);
-
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(hash_count + 2),
-                            rule: self.name().to_string(),
-                            message: format!(
-                                "Multiple spaces after hash on atx style heading ({} spaces)",
-                                space_count
-                            ),
-                            fix: Some(Fix {
-                                line_start: line_number,
-                                line_end: line_number,
-                                column_start: None,
-                                column_end: None,
-                                replacement,
-                                description: "Replace multiple spaces with single space"
-                                    .to_string(),
-                            }),
-                        });
-                    }
-                }
+
+            let after_hashes = &trimmed[hash_count..];
+            let space_count = after_hashes
+                .chars()
+                .take_while(|&c| c == ' ' || c == '\t')
+                .count();
+            // An empty heading has no text to separate from the hashes.
+            if space_count <= 1 || after_hashes.trim().is_empty() {
+                continue;
             }
+
+            let first_space = indent + hash_count + 1;
+            violations.push(Violation {
+                line: line_number,
+                column: Some(first_space),
+                end_column: None,
+                rule: self.name().to_string(),
+                message: format!(
+                    "Multiple spaces after hash on atx style heading ({} spaces)",
+                    space_count
+                ),
+                fix: Some(Fix {
+                    line_start: line_number,
+                    line_end: line_number,
+                    column_start: Some(first_space),
+                    column_end: Some(first_space + space_count - 1),
+                    replacement: " ".to_string(),
+                    description: "Replace multiple spaces with single space".to_string(),
+                }),
+            });
         }
 
         violations
@@ -142,4 +149,44 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Too many spaces\n\n### Even more\n");
     }
+
+    #[test]
+    fn test_fix_two_spaces_to_one() {
+        let content = "##  Heading\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD019.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(3));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "## Heading\n");
+    }
+
+    #[test]
+    fn test_single_space_heading_is_clean() {
+        let content = "## Heading\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD019.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_fix_does_not_touch_closing_hashes() {
+        let content = "#  Heading  #\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD019.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "# Heading  #\n");
+    }
+
+    #[test]
+    fn test_non_heading_not_flagged() {
+        // Seven hashes are not a heading.
+        let content = "#######  Not a heading\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD019.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
@@ -14,54 +14,85 @@ impl Rule for MD020 {
         "No space inside hashes on closed atx style heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-missing-space-closed-atx"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "atx_closed", "spaces"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
+        let code_block_lines = parser.get_code_block_line_numbers();
 
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
-            let trimmed = line.trim();
+            if code_block_lines.contains(&line_number) {
+                continue;
+            }
+
+            let chars: Vec<char> = line.trim_end().chars().collect();
+            let indent = chars.iter().take_while(|c| c.is_whitespace()).count();
+            let heading = &chars[indent..];
 
             // Check if this is a closed ATX heading (starts and ends with #)
-            if trimmed.starts_with('#') && trimmed.ends_with('#') {
-                // Count opening hashes
-                let opening_hashes = trimmed.chars().take_while(|&c| c == '#').count();
-
-                // Count closing hashes
-                let closing_hashes = trimmed.chars().rev().take_while(|&c| c == '#').count();
-
-                // Make sure there's content between opening and closing hashes
-                if opening_hashes + closing_hashes < trimmed.len() {
-                    // Get the character before the closing hashes
-                    let chars: Vec<char> = trimmed.chars().collect();
-                    let pos_before_closing = chars.len() - closing_hashes - 1;
-
-                    if chars[pos_before_closing] != ' ' {
-                        // Insert space before closing hashes
-                        let before_closing: String = chars[..=pos_before_closing].iter().collect();
-                        let closing: String = chars[(pos_before_closing + 1)..].iter().collect();
-                        let replacement = format!("{} {}", before_closing, closing);
-
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: "No space inside hashes on closed atx style heading"
-                                .to_string(),
-                            fix: Some(Fix {
-                                line_start: line_number,
-                                line_end: line_number,
-                                column_start: None,
-                                column_end: None,
-                                replacement,
-                                description: "Add space before closing hashes".to_string(),
-                            }),
-                        });
-                    }
-                }
+            let opening_hashes = heading.iter().take_while(|&&c| c == '#').count();
+            let closing_hashes = heading.iter().rev().take_while(|&&c| c == '#').count();
+            if opening_hashes == 0 || opening_hashes > 6 || closing_hashes == 0 {
+                continue;
+            }
+
+            // Make sure there's content between opening and closing hashes
+            let content = &heading[opening_hashes..heading.len() - closing_hashes];
+            if content.iter().all(|c| c.is_whitespace()) {
+                continue;
+            }
+            let first = content[0];
+            let last = content[content.len() - 1];
+            // An escaped closing hash is part of the heading text.
+            if last == '\\' {
+                continue;
+            }
+
+            // Columns are 1-indexed and count characters.
+            let last_opening_column = indent + opening_hashes;
+            let last_content_column = last_opening_column + content.len();
+
+            if !first.is_whitespace() {
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(last_opening_column + 1),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: "No space inside hashes on closed atx style heading".to_string(),
+                    fix: Some(Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(last_opening_column),
+                        column_end: Some(last_opening_column),
+                        replacement: "# ".to_string(),
+                        description: "Add space after opening hashes".to_string(),
+                    }),
+                });
+            }
+
+            if !last.is_whitespace() {
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(last_content_column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: "No space inside hashes on closed atx style heading".to_string(),
+                    fix: Some(Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(last_content_column),
+                        column_end: Some(last_content_column),
+                        replacement: format!("{} ", last),
+                        description: "Add space before closing hashes".to_string(),
+                    }),
+                });
             }
         }
 
@@ -135,4 +166,45 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Heading #\n");
     }
+
+    #[test]
+    fn test_fix_missing_space_before_closing_hashes() {
+        let content = "### Heading###\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD020.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(11));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "### Heading ###\n");
+    }
+
+    #[test]
+    fn test_fix_missing_space_after_opening_hashes() {
+        let content = "###Heading ###\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD020.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(4));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "### Heading ###\n");
+    }
+
+    #[test]
+    fn test_fix_both_sides() {
+        let content = "##Heading##\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD020.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "## Heading ##\n");
+    }
+
+    #[test]
+    fn test_escaped_closing_hash_and_code_block() {
+        let content = "# Heading \\#\n\n```\n#Code#\n```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD020.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
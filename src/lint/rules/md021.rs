@@ -14,6 +14,10 @@ impl Rule for MD021 {
         "Multiple spaces inside hashes on closed atx style heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-multiple-space-closed-atx"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "atx_closed", "spaces"]
     }
@@ -29,46 +33,38 @@ impl Rule for MD021 {
             if trimmed.starts_with('#') && trimmed.ends_with('#') {
                 let parts: Vec<&str> = trimmed.split_whitespace().collect();
                 if parts.len() >= 2 && parts.last().unwrap().chars().all(|c| c == '#') {
-                    let closing_hashes = parts.last().unwrap();
-
-                    // Find position of closing hashes
-                    if let Some(pos) = trimmed.rfind(closing_hashes) {
-                        // Count spaces before closing hashes
-                        let mut space_count = 0;
-                        let mut check_pos = pos;
-                        while check_pos > 0 {
-                            check_pos -= 1;
-                            if trimmed.chars().nth(check_pos) == Some(' ') {
-                                space_count += 1;
-                            } else {
-                                break;
-                            }
-                        }
-
-                        if space_count > 1 {
-                            // Replace multiple spaces with single space
-                            let before_spaces = &trimmed[..check_pos + 1];
-                            let after_spaces = &trimmed[pos..];
-                            let replacement = format!("{} {}", before_spaces, after_spaces);
-
-                            violations.push(Violation {
-                                line: line_number,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message:
-                                    "Multiple spaces inside hashes on closed atx style heading"
-                                        .to_string(),
-                                fix: Some(Fix {
-                                    line_start: line_number,
-                                    line_end: line_number,
-                                    column_start: None,
-                                    column_end: None,
-                                    replacement,
-                                    description: "Replace multiple spaces with single space"
-                                        .to_string(),
-                                }),
-                            });
-                        }
+                    let chars: Vec<char> = line.trim_end().chars().collect();
+                    let closing_hashes = parts.last().unwrap().len();
+                    let closing_start = chars.len() - closing_hashes;
+
+                    // Count spaces before closing hashes
+                    let space_count = chars[..closing_start]
+                        .iter()
+                        .rev()
+                        .take_while(|&&c| c == ' ')
+                        .count();
+
+                    if space_count > 1 {
+                        // Columns are 1-indexed; the spaces end just before the closing hashes.
+                        let first_space = closing_start - space_count + 1;
+
+                        violations.push(Violation {
+                            line: line_number,
+                            column: Some(first_space),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: "Multiple spaces inside hashes on closed atx style heading"
+                                .to_string(),
+                            fix: Some(Fix {
+                                line_start: line_number,
+                                line_end: line_number,
+                                column_start: Some(first_space),
+                                column_end: Some(closing_start),
+                                replacement: " ".to_string(),
+                                description: "Replace multiple spaces with single space"
+                                    .to_string(),
+                            }),
+                        });
                     }
                 }
             }
@@ -144,4 +140,17 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Heading ##\n");
     }
+
+    #[test]
+    fn test_fix_combines_with_md019() {
+        use crate::lint::rules::md019::MD019;
+
+        let content = "#  Heading  #\n";
+        let parser = MarkdownParser::new(content);
+        let mut violations = MD019.check(&parser, None);
+        violations.extend(MD021.check(&parser, None));
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "# Heading #\n");
+    }
 }
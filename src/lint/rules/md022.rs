@@ -1,11 +1,18 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use pulldown_cmark::{Event, Tag};
-use serde_json::Value;
+use pulldown_cmark::{Event, HeadingLevel, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD022;
 
+/// A heading's 1-indexed line span (setext headings span their underline) and level.
+struct HeadingSpan {
+    start: usize,
+    end: usize,
+    level: HeadingLevel,
+}
+
 impl Rule for MD022 {
     fn name(&self) -> &str {
         "MD022"
@@ -15,77 +22,65 @@ impl Rule for MD022 {
         "Headings should be surrounded by blank lines"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["blanks-around-headings"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers", "blank_lines"]
     }
 
-    fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        let lines = parser.lines();
+    fn default_config(&self) -> Value {
+        json!({ "lines_above": 1, "lines_below": 1 })
+    }
 
-        // Find all heading lines using the AST
-        let mut heading_lines = Vec::new();
-        for (event, range) in parser.parse_with_offsets() {
-            if let Event::Start(Tag::Heading { .. }) = event {
-                let line = parser.offset_to_line(range.start);
-                heading_lines.push(line);
-            }
-        }
+    fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        let lines = parser.lines();
+        let headings = heading_spans(parser);
+        let mut violations = Vec::new();
 
-        for &heading_line in &heading_lines {
-            let line_idx = heading_line - 1;
-
-            // Check if there's a blank line before (skip if first line or after blank)
-            if line_idx > 0 {
-                let prev_line = lines[line_idx - 1].trim();
-                if !prev_line.is_empty() {
-                    // Replace the heading line with "\n<heading>" — the embedded newline
-                    // causes the Fixer to produce a blank line before the heading.
-                    violations.push(Violation {
-                        line: heading_line,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Heading should be surrounded by blank lines (missing before)"
-                            .to_string(),
-                        fix: Some(Fix {
-                            line_start: heading_line,
-                            line_end: heading_line,
-                            column_start: None,
-                            column_end: None,
-                            replacement: format!("\n{}", lines[line_idx]),
-                            description: "Add blank line before heading".to_string(),
-                        }),
-                    });
+        for (idx, heading) in headings.iter().enumerate() {
+            let line_idx = heading.start - 1;
+            if line_idx > 0
+                && let Some(required) = required_blank_lines(config, "lines_above", heading.level)
+            {
+                let actual = lines[..line_idx]
+                    .iter()
+                    .rev()
+                    .take_while(|l| l.trim().is_empty())
+                    .count();
+                // At the top of the document there is nothing to separate from.
+                if actual < line_idx && actual < required {
+                    // A gap shared with the previous heading needs only as many blanks as
+                    // the larger of the two requirements; this fix covers both.
+                    let missing = match idx.checked_sub(1).map(|i| &headings[i]) {
+                        Some(prev) if prev.end + actual + 1 == heading.start => {
+                            required_blank_lines(config, "lines_below", prev.level)
+                                .map_or(required, |below| below.max(required))
+                                - actual
+                        }
+                        _ => required - actual,
+                    };
+                    violations.push(self.violation(
+                        heading.start,
+                        "before",
+                        Some(insert_before(heading.start, missing)),
+                    ));
                 }
             }
 
-            // Check if there's a blank line after (skip if last line)
-            if line_idx + 1 < lines.len() {
-                let next_line = lines[line_idx + 1].trim();
-                // Allow another heading right after (for closed headings or setext underlines)
-                if !next_line.is_empty()
-                    && !next_line.starts_with('#')
-                    && !next_line
-                        .chars()
-                        .all(|c| c == '=' || c == '-' || c.is_whitespace())
-                {
-                    // Replace the heading line with "<heading>\n" — the embedded newline
-                    // causes the Fixer to produce a blank line after the heading.
-                    violations.push(Violation {
-                        line: heading_line,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Heading should be surrounded by blank lines (missing after)"
-                            .to_string(),
-                        fix: Some(Fix {
-                            line_start: heading_line,
-                            line_end: heading_line,
-                            column_start: None,
-                            column_end: None,
-                            replacement: format!("{}\n", lines[line_idx]),
-                            description: "Add blank line after heading".to_string(),
-                        }),
-                    });
+            let end_idx = heading.end - 1;
+            if let Some(required) = required_blank_lines(config, "lines_below", heading.level) {
+                let following = &lines[end_idx + 1..];
+                let actual = following.iter().take_while(|l| l.trim().is_empty()).count();
+                // Trailing blank lines up to the end of the document need no separation.
+                if actual < following.len() && actual < required {
+                    let shared_with_next = headings
+                        .get(idx + 1)
+                        .is_some_and(|next| heading.end + actual + 1 == next.start);
+                    let fix =
+                        (!shared_with_next).then(|| insert_after(heading.end, required - actual));
+                    violations.push(self.violation(heading.start, "after", fix));
                 }
             }
         }
@@ -98,6 +93,81 @@ impl Rule for MD022 {
     }
 }
 
+impl MD022 {
+    fn violation(&self, line: usize, position: &str, fix: Option<Fix>) -> Violation {
+        Violation {
+            line,
+            column: Some(1),
+            end_column: None,
+            rule: self.name().to_string(),
+            message: format!(
+                "Heading should be surrounded by blank lines (missing {})",
+                position
+            ),
+            fix,
+        }
+    }
+}
+
+fn heading_spans(parser: &MarkdownParser) -> Vec<HeadingSpan> {
+    let mut spans = Vec::new();
+    let mut open: Option<(usize, HeadingLevel)> = None;
+    for (event, range) in parser.parse_with_offsets() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                open = Some((parser.offset_to_line(range.start), level));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((start, level)) = open.take() {
+                    // The range may end just past the trailing newline.
+                    let end = parser.offset_to_line(range.end.saturating_sub(1).max(range.start));
+                    spans.push(HeadingSpan { start, end, level });
+                }
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+/// Blank lines required on one side of a heading. The option may be a single number or
+/// an array indexed by heading level; a negative value disables the check.
+fn required_blank_lines(config: Option<&Value>, key: &str, level: HeadingLevel) -> Option<usize> {
+    let value = match config.and_then(|c| c.get(key)) {
+        Some(Value::Array(per_level)) => per_level.get(level as usize - 1),
+        other => other,
+    };
+    match value.and_then(Value::as_i64) {
+        Some(n) => usize::try_from(n).ok(),
+        None => Some(1),
+    }
+}
+
+// Blank lines are inserted at the start of the heading's first line and of the line
+// after the heading, so the two fixes never share a line even for a heading as short
+// as `#`, and leave the heading's own text to other rules' fixes.
+fn insert_before(line_num: usize, missing: usize) -> Fix {
+    Fix {
+        line_start: line_num,
+        line_end: line_num,
+        column_start: Some(1),
+        column_end: Some(0),
+        replacement: "\n".repeat(missing),
+        description: "Add blank line before heading".to_string(),
+    }
+}
+
+fn insert_after(line_num: usize, missing: usize) -> Fix {
+    Fix {
+        line_start: line_num + 1,
+        line_end: line_num + 1,
+        column_start: Some(1),
+        column_end: Some(0),
+        replacement: "\n".repeat(missing),
+        description: "Add blank line after heading".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +245,95 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Heading\n\nContent\n");
     }
+
+    #[test]
+    fn test_heading_preceded_by_text() {
+        let content = "Some text\n# Heading\n\nMore text\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[0].message.contains("before"));
+    }
+
+    #[test]
+    fn test_heading_followed_by_text() {
+        let content = "Some text\n\n# Heading\nMore text\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("after"));
+    }
+
+    #[test]
+    fn test_fix_inserts_blanks_on_both_sides() {
+        let content = "Before\n# Heading\nAfter\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Before\n\n# Heading\n\nAfter\n");
+    }
+
+    #[test]
+    fn test_fix_empty_heading_on_both_sides() {
+        let content = "text\n#\ntext\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "text\n\n#\n\ntext\n");
+    }
+
+    #[test]
+    fn test_setext_heading_checks_after_underline() {
+        let content = "Intro\n\nHeading\n=======\nText\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("after"));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Intro\n\nHeading\n=======\n\nText\n");
+    }
+
+    #[test]
+    fn test_lines_above_and_below_counts() {
+        let content = "Text\n\n# Heading\n\nMore\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "lines_above": 2, "lines_below": 2 });
+        let violations = MD022.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Text\n\n\n# Heading\n\n\nMore\n");
+    }
+
+    #[test]
+    fn test_lines_above_per_level() {
+        let content = "Text\n# One\n\nText\n## Two\n\nText\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "lines_above": [1, 0] });
+        let violations = MD022.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+    }
+
+    #[test]
+    fn test_negative_disables_check() {
+        let content = "Text\n# Heading\nMore\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "lines_above": -1, "lines_below": -1 });
+        assert!(MD022.check(&parser, Some(&config)).is_empty());
+    }
+
+    #[test]
+    fn test_fix_adjacent_headings_shares_gap() {
+        let content = "# One\n## Two\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD022.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "# One\n\n## Two\n");
+    }
 }
@@ -1,6 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag, TagEnd};
 use serde_json::Value;
 
 pub struct MD023;
@@ -14,51 +15,62 @@ impl Rule for MD023 {
         "Headings must start at the beginning of the line"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["heading-start-left"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers", "spaces"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
-        let code_block_lines = parser.get_code_block_line_numbers();
+        let lines = parser.lines();
+
+        // ATX heading lines confirmed by the AST, outside list items and blockquotes
+        // (whose container indentation is expected).
+        let mut heading_lines = Vec::new();
+        let mut container_depth = 0usize;
+        for (event, range) in parser.parse_with_offsets() {
+            match event {
+                Event::Start(Tag::Item | Tag::BlockQuote(_)) => container_depth += 1,
+                Event::End(TagEnd::Item | TagEnd::BlockQuote(_)) => {
+                    container_depth = container_depth.saturating_sub(1)
+                }
+                Event::Start(Tag::Heading { .. }) if container_depth == 0 => {
+                    heading_lines.push(parser.offset_to_line(range.start));
+                }
+                _ => {}
+            }
+        }
 
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-            if code_block_lines.contains(&line_number) {
+        for line_number in heading_lines {
+            let line = lines[line_number - 1];
+            let trimmed = line.trim_start();
+            let indent = line.len() - trimmed.len();
+            // Setext headings and unindented ATX headings are fine.
+            if indent == 0 || !trimmed.starts_with('#') {
                 continue;
             }
 
-            // Check if line starts with whitespace followed by hash
-            if line.starts_with(' ') || line.starts_with('\t') {
-                let trimmed = line.trim_start();
-                if trimmed.starts_with('#') {
-                    // Count leading hashes to verify it's a heading
-                    let hash_count = trimmed.chars().take_while(|&c| c == '#').count();
-
-                    // Valid heading should have 1-6 hashes
-                    if hash_count > 0 && hash_count <= 6 {
-                        let indent = line.len() - trimmed.len();
-                        // Remove leading whitespace
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: format!(
-                                "Heading must start at the beginning of the line ({} space(s) before)",
-                                indent
-                            ),
-                            fix: Some(Fix {
-                                line_start: line_number,
-                                line_end: line_number,
-                                column_start: None,
-                                column_end: None,
-                                replacement: trimmed.to_string(),
-                                description: "Remove leading whitespace".to_string(),
-                            }),
-                        });
-                    }
-                }
-            }
+            violations.push(Violation {
+                line: line_number,
+                column: Some(1),
+                end_column: None,
+                rule: self.name().to_string(),
+                message: format!(
+                    "Heading must start at the beginning of the line ({} space(s) before)",
+                    indent
+                ),
+                fix: Some(Fix {
+                    line_start: line_number,
+                    line_end: line_number,
+                    column_start: Some(1),
+                    column_end: Some(indent),
+                    replacement: String::new(),
+                    description: "Remove leading whitespace".to_string(),
+                }),
+            });
         }
 
         violations
@@ -147,4 +159,32 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Indented heading\n\nParagraph.\n");
     }
+
+    #[test]
+    fn test_fix_one_and_two_space_indents() {
+        let content = "Intro\n\n # One space\n\n  ## Two spaces\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD023.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "Intro\n\n# One space\n\n## Two spaces\n");
+    }
+
+    #[test]
+    fn test_indented_hash_in_code_block() {
+        let content = "# Title\n\n```sh\n  # comment\n```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD023.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_non_heading_hash_and_list_heading() {
+        let content = "# Title\n\n  #hashtag is not a heading\n\n- Item\n\n  ## In list\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD023.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
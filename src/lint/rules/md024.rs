@@ -1,8 +1,8 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use pulldown_cmark::{Event, HeadingLevel, Tag, TagEnd};
-use serde_json::Value;
+use pulldown_cmark::HeadingLevel;
+use serde_json::{Value, json};
 use std::collections::HashMap;
 
 pub struct MD024;
@@ -16,97 +16,89 @@ impl Rule for MD024 {
         "Multiple headings with the same content"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-duplicate-heading"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "siblings_only": false, "allow_different_nesting": false })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let siblings_only = config
             .and_then(|c| c.get("siblings_only"))
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let allow_different_nesting = config
+            .and_then(|c| c.get("allow_different_nesting"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if allow_different_nesting {
+            return self.check_by_nesting(parser);
+        }
 
         let mut violations = Vec::new();
         let mut heading_texts: HashMap<String, (usize, HeadingLevel)> = HashMap::new();
         let mut sibling_headings: HashMap<(HeadingLevel, String), usize> = HashMap::new();
         let mut last_heading_level: Option<HeadingLevel> = None;
-        let mut in_heading = false;
-        let mut current_heading_text = String::new();
-        let mut current_heading_line = 0;
-        let mut current_heading_level = HeadingLevel::H1;
-
-        for (event, range) in parser.parse_with_offsets() {
-            match event {
-                Event::Start(Tag::Heading { level, .. }) => {
-                    in_heading = true;
-                    current_heading_text.clear();
-                    current_heading_line = parser.offset_to_line(range.start);
-                    current_heading_level = level;
-                }
-                Event::Text(text) if in_heading => {
-                    current_heading_text.push_str(&text);
+
+        for heading in parser.headings() {
+            let text = heading.text.clone();
+            let current_heading_line = heading.line;
+            let current_heading_level = heading.level;
+
+            if siblings_only {
+                // Check if same level heading with same text exists
+                if let Some(&prev_level) = last_heading_level.as_ref()
+                    && prev_level != current_heading_level
+                {
+                    // Different level, clear sibling tracking
+                    sibling_headings.clear();
                 }
-                Event::Code(code) if in_heading => {
-                    // Include inline code in heading text
-                    current_heading_text.push('`');
-                    current_heading_text.push_str(&code);
-                    current_heading_text.push('`');
+
+                if let Some(&first_line) =
+                    sibling_headings.get(&(current_heading_level, text.clone()))
+                {
+                    violations.push(Violation {
+                        line: current_heading_line,
+                        column: Some(1),
+                        end_column: None,
+                        rule: self.name().to_string(),
+                        message: format!(
+                            "Multiple sibling headings with the same content: \"{}\" (first at line {})",
+                            text, first_line
+                        ),
+                        fix: None,
+                    });
+                } else {
+                    sibling_headings
+                        .insert((current_heading_level, text.clone()), current_heading_line);
                 }
-                Event::End(TagEnd::Heading(_)) if in_heading => {
-                    let text = current_heading_text.trim().to_string();
-
-                    if siblings_only {
-                        // Check if same level heading with same text exists
-                        if let Some(&prev_level) = last_heading_level.as_ref()
-                            && prev_level != current_heading_level
-                        {
-                            // Different level, clear sibling tracking
-                            sibling_headings.clear();
-                        }
-
-                        if let Some(&first_line) =
-                            sibling_headings.get(&(current_heading_level, text.clone()))
-                        {
-                            violations.push(Violation {
-                                line: current_heading_line,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Multiple sibling headings with the same content: \"{}\" (first at line {})",
-                                    text, first_line
-                                ),
-                                fix: None,
-                            });
-                        } else {
-                            sibling_headings.insert(
-                                (current_heading_level, text.clone()),
-                                current_heading_line,
-                            );
-                        }
-                    } else {
-                        // Check globally
-                        if let Some(&(first_line, _first_level)) = heading_texts.get(&text) {
-                            violations.push(Violation {
-                                line: current_heading_line,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Multiple headings with the same content: \"{}\" (first at line {})",
-                                    text, first_line
-                                ),
-                                fix: None,
-                            });
-                        } else {
-                            heading_texts
-                                .insert(text, (current_heading_line, current_heading_level));
-                        }
-                    }
-
-                    last_heading_level = Some(current_heading_level);
-                    in_heading = false;
+            } else {
+                // Check globally
+                if let Some(&(first_line, _first_level)) = heading_texts.get(&text) {
+                    violations.push(Violation {
+                        line: current_heading_line,
+                        column: Some(1),
+                        end_column: None,
+                        rule: self.name().to_string(),
+                        message: format!(
+                            "Multiple headings with the same content: \"{}\" (first at line {})",
+                            text, first_line
+                        ),
+                        fix: None,
+                    });
+                } else {
+                    heading_texts.insert(text, (current_heading_line, current_heading_level));
                 }
-                _ => {}
             }
+
+            last_heading_level = Some(current_heading_level);
         }
 
         violations
@@ -117,6 +109,46 @@ impl Rule for MD024 {
     }
 }
 
+impl MD024 {
+    /// Flag a heading only when an earlier one has the same text under the same chain
+    /// of parent headings.
+    fn check_by_nesting(&self, parser: &MarkdownParser) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        // Open ancestor headings, outermost first.
+        let mut stack: Vec<(HeadingLevel, &str)> = Vec::new();
+        let mut seen: HashMap<Vec<&str>, usize> = HashMap::new();
+
+        for heading in parser.headings() {
+            while stack
+                .last()
+                .is_some_and(|&(level, _)| level >= heading.level)
+            {
+                stack.pop();
+            }
+            stack.push((heading.level, heading.text.as_str()));
+            let path: Vec<&str> = stack.iter().map(|&(_, text)| text).collect();
+
+            if let Some(&first_line) = seen.get(&path) {
+                violations.push(Violation {
+                    line: heading.line,
+                    column: Some(1),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: format!(
+                        "Multiple headings with the same content at the same nesting: \"{}\" (first at line {})",
+                        heading.text, first_line
+                    ),
+                    fix: None,
+                });
+            } else {
+                seen.insert(path, heading.line);
+            }
+        }
+
+        violations
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +226,27 @@ mod tests {
         );
         assert!(violations[0].message.contains("`mdlint check`"));
     }
+
+    #[test]
+    fn test_allow_different_nesting() {
+        let content = "# Guide\n## Install\n### Overview\n## Usage\n### Overview\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "allow_different_nesting": true });
+
+        assert_eq!(MD024.check(&parser, Some(&config)).len(), 0);
+        let violations = MD024.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 5);
+    }
+
+    #[test]
+    fn test_allow_different_nesting_flags_same_parent() {
+        let content = "# Guide\n## Install\n### Overview\n### Overview\n";
+        let parser = MarkdownParser::new(content);
+        let config = json!({ "allow_different_nesting": true });
+        let violations = MD024.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+    }
 }
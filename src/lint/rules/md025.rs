@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use pulldown_cmark::{Event, HeadingLevel, Tag};
+use pulldown_cmark::HeadingLevel;
 use serde_json::Value;
 
 pub struct MD025;
@@ -15,6 +15,10 @@ impl Rule for MD025 {
         "Multiple top-level headings in the same document"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["single-title", "single-h1"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "headers"]
     }
@@ -23,28 +27,25 @@ impl Rule for MD025 {
         let mut violations = Vec::new();
         let mut first_h1_line: Option<usize> = None;
 
-        for (event, range) in parser.parse_with_offsets() {
-            if let Event::Start(Tag::Heading {
-                level: HeadingLevel::H1,
-                ..
-            }) = event
-            {
-                let line = parser.offset_to_line(range.start);
-
-                if let Some(first_line) = first_h1_line {
-                    violations.push(Violation {
-                        line,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: format!(
-                            "Multiple top-level headings (first h1 at line {})",
-                            first_line
-                        ),
-                        fix: None,
-                    });
-                } else {
-                    first_h1_line = Some(line);
-                }
+        for heading in parser.headings() {
+            if heading.level != HeadingLevel::H1 {
+                continue;
+            }
+
+            if let Some(first_line) = first_h1_line {
+                violations.push(Violation {
+                    line: heading.line,
+                    column: Some(1),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: format!(
+                        "Multiple top-level headings (first h1 at line {})",
+                        first_line
+                    ),
+                    fix: None,
+                });
+            } else {
+                first_h1_line = Some(heading.line);
             }
         }
 
@@ -1,8 +1,12 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
+use crate::types::{Fix, Violation};
 use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
+use std::ops::Range;
+
+/// markdownlint's default set: ASCII and full-width sentence punctuation, but not `?`.
+const DEFAULT_PUNCTUATION: &str = ".,;:!。，；：！";
 
 pub struct MD026;
 
@@ -15,49 +19,48 @@ impl Rule for MD026 {
         "Trailing punctuation in heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-trailing-punctuation"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "punctuation": DEFAULT_PUNCTUATION })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let punctuation = config
             .and_then(|c| c.get("punctuation"))
             .and_then(|v| v.as_str())
-            .unwrap_or(".,;:!");
+            .unwrap_or(DEFAULT_PUNCTUATION);
 
         let mut violations = Vec::new();
         let mut in_heading = false;
-        let mut current_heading_text = String::new();
-        let mut current_heading_line = 0;
+        // Text and source byte range of the heading's final inline, when it is plain text
+        let mut last_text: Option<(String, Range<usize>)> = None;
 
         for (event, range) in parser.parse_with_offsets() {
             match event {
                 Event::Start(Tag::Heading { .. }) => {
                     in_heading = true;
-                    current_heading_text.clear();
-                    current_heading_line = parser.offset_to_line(range.start);
+                    last_text = None;
                 }
                 Event::Text(text) if in_heading => {
-                    current_heading_text.push_str(&text);
-                }
-                Event::Code(text) if in_heading => {
-                    current_heading_text.push_str(&text);
+                    last_text = Some((text.to_string(), range));
                 }
                 Event::End(TagEnd::Heading(_)) if in_heading => {
-                    let trimmed = current_heading_text.trim();
-                    if let Some(last_char) = trimmed.chars().last()
-                        && punctuation.contains(last_char)
+                    in_heading = false;
+                    if let Some((text, range)) = last_text.take()
+                        && let Some(violation) =
+                            self.trailing_punctuation(parser, &text, range, punctuation)
                     {
-                        violations.push(Violation {
-                            line: current_heading_line,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: format!("Trailing punctuation in heading: '{}'", last_char),
-                            fix: None,
-                        });
+                        violations.push(violation);
                     }
-                    in_heading = false;
                 }
+                _ if in_heading => last_text = None,
                 _ => {}
             }
         }
@@ -66,13 +69,132 @@ impl Rule for MD026 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
+    }
+}
+
+impl MD026 {
+    fn trailing_punctuation(
+        &self,
+        parser: &MarkdownParser,
+        text: &str,
+        range: Range<usize>,
+        punctuation: &str,
+    ) -> Option<Violation> {
+        let trimmed = text.trim_end();
+        let stripped = trimmed.trim_end_matches(|c| punctuation.contains(c));
+        let trailing = &trimmed[stripped.len()..];
+        // The source only mirrors the text when no escapes or entities were decoded.
+        let source = parser.content().get(range.clone())?;
+        if trailing.is_empty() || !source.trim_end().ends_with(trailing) {
+            return None;
+        }
+
+        let trailing_start = range.start + source.trim_end().len() - trailing.len();
+        // `\.` renders as a literal `.` but can't be removed without leaving the
+        // backslash behind, so escaped punctuation is left alone.
+        let content = parser.content();
+        let escaped = trailing.char_indices().any(|(i, _)| {
+            let before = &content[..trailing_start + i];
+            (before.len() - before.trim_end_matches('\\').len()) % 2 == 1
+        });
+        if escaped {
+            return None;
+        }
+        let (line, byte_col) = parser.offset_to_position(trailing_start);
+        let line_text = parser.get_line(line)?;
+        let column = line_text[..byte_col - 1].chars().count() + 1;
+        let column_end = column + trailing.chars().count() - 1;
+
+        Some(Violation {
+            line,
+            column: Some(column),
+            end_column: None,
+            rule: self.name().to_string(),
+            message: format!("Trailing punctuation in heading: '{}'", trailing),
+            fix: Some(Fix {
+                line_start: line,
+                line_end: line,
+                column_start: Some(column),
+                column_end: Some(column_end),
+                replacement: String::new(),
+                description: "Remove trailing punctuation".to_string(),
+            }),
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_fix_strips_trailing_period() {
+        let content = "# Hello.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD026.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(8));
+        assert_eq!(apply_fixes(content, &violations), "# Hello\n");
+    }
+
+    #[test]
+    fn test_ready_question_is_clean() {
+        let parser = MarkdownParser::new("# Ready?\n");
+        assert!(MD026.check(&parser, None).is_empty());
+    }
+
+    #[test]
+    fn test_full_width_punctuation() {
+        let content = "# 你好。\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD026.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(5));
+        assert_eq!(apply_fixes(content, &violations), "# 你好\n");
+    }
+
+    #[test]
+    fn test_fix_closed_atx_and_setext() {
+        let content = "# Closed! #\n\nSetext:\n-------\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD026.check(&parser, None);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            apply_fixes(content, &violations),
+            "# Closed #\n\nSetext\n-------\n"
+        );
+    }
+
+    #[test]
+    fn test_trailing_code_span_ignored() {
+        let parser = MarkdownParser::new("# Call `foo.`\n");
+        assert!(MD026.check(&parser, None).is_empty());
+    }
+
+    #[test]
+    fn test_escaped_punctuation_ignored() {
+        let parser = MarkdownParser::new("# Hello\\.\n\n## Done!\\?\n");
+        assert!(MD026.check(&parser, None).is_empty());
+
+        // An escaped backslash leaves the period unescaped.
+        let content = "# Path\\\\.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD026.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(apply_fixes(content, &violations), "# Path\\\\\n");
+    }
 
     #[test]
     fn test_no_trailing_punctuation() {
@@ -1,7 +1,9 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag, TagEnd};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 
 pub struct MD027;
 
@@ -14,51 +16,59 @@ impl Rule for MD027 {
         "Multiple spaces after blockquote symbol"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-multiple-space-blockquote"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["blockquote", "whitespace", "indentation"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
+        // Spaces after `>` are code indentation in a code block, and list indentation
+        // on an item's continuation lines.
+        let code_block_lines = parser.get_code_block_line_numbers();
+        let list_lines = quoted_list_continuation_lines(parser);
 
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
-            let trimmed = line.trim_start();
-
-            // Check if line starts with blockquote marker
-            if let Some(after_gt) = trimmed.strip_prefix('>') {
-                // Count spaces after the >
-                let space_count = after_gt.chars().take_while(|&c| c == ' ').count();
-
-                if space_count > 1 {
-                    // Replace multiple spaces with single space
-                    let leading_spaces = &line[..line.len() - trimmed.len()];
-                    let content = after_gt[space_count..].trim_start();
-                    let replacement = if content.is_empty() {
-                        format!("{}>", leading_spaces)
-                    } else {
-                        format!("{}> {}", leading_spaces, content)
-                    };
-
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(line.len() - trimmed.len() + 2),
-                        rule: self.name().to_string(),
-                        message: format!(
-                            "Multiple spaces after blockquote symbol ({} spaces)",
-                            space_count
-                        ),
-                        fix: Some(Fix {
-                            line_start: line_number,
-                            line_end: line_number,
-                            column_start: None,
-                            column_end: None,
-                            replacement,
-                            description: "Replace multiple spaces with single space".to_string(),
-                        }),
-                    });
-                }
+            if code_block_lines.contains(&line_number) || list_lines.contains(&line_number) {
+                continue;
+            }
+            let Some(after_marker) = last_marker_end(line) else {
+                continue;
+            };
+
+            let rest = &line[after_marker..];
+            let space_count = rest.chars().take_while(|&c| c == ' ').count();
+            // Whitespace-only lines are MD009's concern; flagging them here would overlap its fix.
+            if space_count <= 1 || rest.trim().is_empty() {
+                continue;
             }
+
+            // Columns are 1-indexed and the marker prefix is ASCII, so byte == char offsets.
+            let column_start = after_marker + 1;
+            let column_end = after_marker + space_count;
+
+            violations.push(Violation {
+                line: line_number,
+                column: Some(column_start),
+                end_column: None,
+                rule: self.name().to_string(),
+                message: format!(
+                    "Multiple spaces after blockquote symbol ({} spaces)",
+                    space_count
+                ),
+                fix: Some(Fix {
+                    line_start: line_number,
+                    line_end: line_number,
+                    column_start: Some(column_start),
+                    column_end: Some(column_end),
+                    replacement: " ".to_string(),
+                    description: "Replace multiple spaces with single space".to_string(),
+                }),
+            });
         }
 
         violations
@@ -69,6 +79,51 @@ impl Rule for MD027 {
     }
 }
 
+/// Lines after the first of a list item inside a blockquote, unless a blockquote
+/// nested in the item owns them.
+fn quoted_list_continuation_lines(parser: &MarkdownParser) -> HashSet<usize> {
+    // Containers start in document order, so an inner one overwrites the lines it
+    // shares with its parent. `true` marks a list item's continuation line.
+    let mut owners: HashMap<usize, bool> = HashMap::new();
+    let mut quote_depth = 0;
+    for (event, range) in parser.parse_with_offsets() {
+        let start = parser.offset_to_line(range.start);
+        let end = parser.offset_to_line(range.end.saturating_sub(1).max(range.start));
+        match event {
+            Event::Start(Tag::BlockQuote(_)) => {
+                quote_depth += 1;
+                owners.extend((start..=end).map(|line| (line, false)));
+            }
+            Event::End(TagEnd::BlockQuote(_)) => quote_depth -= 1,
+            Event::Start(Tag::Item) if quote_depth > 0 => {
+                owners.extend((start + 1..=end).map(|line| (line, true)));
+            }
+            _ => {}
+        }
+    }
+    owners
+        .into_iter()
+        .filter_map(|(line, in_item)| in_item.then_some(line))
+        .collect()
+}
+
+/// Byte offset just past the innermost `>` of a blockquote prefix such as `> > `,
+/// or `None` if the line is not a blockquote line.
+fn last_marker_end(line: &str) -> Option<usize> {
+    let mut offset = line.len() - line.trim_start_matches(' ').len();
+    let mut marker_end = None;
+    while line[offset..].starts_with('>') {
+        offset += 1;
+        marker_end = Some(offset);
+        let spaces = line[offset..].len() - line[offset..].trim_start_matches(' ').len();
+        if !line[offset + spaces..].starts_with('>') {
+            break;
+        }
+        offset += spaces;
+    }
+    marker_end
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,13 +159,14 @@ mod tests {
 
     #[test]
     fn test_many_spaces() {
-        let content = ">     Quote with 5 spaces";
+        // One more space would make it an indented code block.
+        let content = ">    Quote with 4 spaces";
         let parser = MarkdownParser::new(content);
         let rule = MD027;
         let violations = rule.check(&parser, None);
 
         assert_eq!(violations.len(), 1);
-        assert!(violations[0].message.contains("5 spaces"));
+        assert!(violations[0].message.contains("4 spaces"));
     }
 
     #[test]
@@ -133,4 +189,72 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "> Too many spaces\n> Correct line\n");
     }
+
+    #[test]
+    fn test_fix_is_column_scoped() {
+        let content = ">   text\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(2));
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!((fix.column_start, fix.column_end), (Some(2), Some(4)));
+        assert_eq!(apply_fixes(content, &violations), "> text\n");
+    }
+
+    #[test]
+    fn test_nested_blockquote_over_spacing() {
+        let content = "> > Nested ok\n> >   Nested too many\n>>  Compact nesting\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 2);
+        assert_eq!(violations[0].column, Some(4));
+        assert_eq!(violations[1].line, 3);
+        assert_eq!(
+            apply_fixes(content, &violations),
+            "> > Nested ok\n> > Nested too many\n>> Compact nesting\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_blockquote_preserves_outer_markers() {
+        let content = "  > > >    deep\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(apply_fixes(content, &violations), "  > > > deep\n");
+    }
+
+    #[test]
+    fn test_quoted_code_block_not_flagged() {
+        let content = "> Example:\n>\n> ```python\n> def f():\n>     return 1\n> ```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+        assert_eq!(violations.len(), 0, "{violations:?}");
+
+        let content = "> Example:\n>\n>     indented code\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+        assert_eq!(violations.len(), 0, "{violations:?}");
+    }
+
+    #[test]
+    fn test_quoted_nested_list_not_flagged() {
+        let content = "> - a\n>    - b\n>\n>   more of a\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+
+        assert_eq!(violations.len(), 0, "{violations:?}");
+    }
+
+    #[test]
+    fn test_quote_inside_list_item_still_flagged() {
+        let content = "- item\n\n  >  quoted\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD027.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(apply_fixes(content, &violations), "- item\n\n  > quoted\n");
+    }
 }
@@ -1,6 +1,6 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
+use crate::types::{Fix, Violation};
 use serde_json::Value;
 
 pub struct MD028;
@@ -14,6 +14,10 @@ impl Rule for MD028 {
         "Blank line inside blockquote"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-blanks-blockquote"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["blockquote", "whitespace"]
     }
@@ -21,10 +25,16 @@ impl Rule for MD028 {
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
         let lines = parser.lines();
+        let code_block_lines = parser.get_code_block_line_numbers();
         let mut in_blockquote = false;
+        let mut quote_prefix = ">";
 
         for (line_num, line) in lines.iter().enumerate() {
             let line_number = line_num + 1;
+            if code_block_lines.contains(&line_number) {
+                in_blockquote = false;
+                continue;
+            }
             let trimmed = line.trim_start();
 
             let is_blockquote_line = trimmed.starts_with('>');
@@ -32,6 +42,11 @@ impl Rule for MD028 {
 
             if is_blockquote_line {
                 in_blockquote = true;
+                // Markers (and nesting) to repeat on a blank line, e.g. "> >".
+                let prefix_len = line
+                    .find(|c: char| c != '>' && !c.is_whitespace())
+                    .unwrap_or(line.len());
+                quote_prefix = line[..prefix_len].trim_end();
             } else if is_blank && in_blockquote {
                 // Look ahead to find if blockquote continues (skip multiple blank lines)
                 let mut found_continuation = false;
@@ -50,9 +65,18 @@ impl Rule for MD028 {
                     violations.push(Violation {
                         line: line_number,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: "Blank line inside blockquote".to_string(),
-                        fix: None,
+                        // Later blank lines in the same run are left to MD012.
+                        fix: Some(Fix {
+                            line_start: line_number,
+                            line_end: line_number,
+                            column_start: None,
+                            column_end: None,
+                            replacement: quote_prefix.to_string(),
+                            description: "Continue blockquote on blank line".to_string(),
+                        }),
                     });
                     // After reporting violation, don't check subsequent blank lines
                     in_blockquote = false;
@@ -69,13 +93,21 @@ impl Rule for MD028 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_continuous_blockquote() {
@@ -117,4 +149,41 @@ mod tests {
 
         assert_eq!(violations.len(), 1); // First blank line is the violation
     }
+
+    #[test]
+    fn test_fix_continues_blockquote() {
+        let content = "> a\n\n> b\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD028.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "> a\n>\n> b\n");
+    }
+
+    #[test]
+    fn test_marked_blank_line_is_clean() {
+        let content = "> a\n>\n> b\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD028.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_fix_keeps_nesting_and_indent() {
+        let content = "  > > a\n\n  > > b\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD028.check(&parser, None);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "  > > a\n  > >\n  > > b\n");
+    }
+
+    #[test]
+    fn test_quote_markers_in_code_block_ignored() {
+        let content = "```\n> a\n\n> b\n```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD028.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
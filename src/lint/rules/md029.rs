@@ -2,7 +2,7 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
 use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD029;
 
@@ -15,10 +15,18 @@ impl Rule for MD029 {
         "Ordered list item prefix"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["ol-prefix"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["ol"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "ordered" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -47,9 +55,13 @@ impl Rule for MD029 {
                 }
                 Event::Start(Tag::Item) => {
                     if let Some(Some((expected, seen_non_one))) = list_stack.last_mut() {
-                        let line_num = parser.offset_to_line(range.start);
+                        // The item's range may start with its indentation; locate the
+                        // number itself so items in blockquotes are handled too.
+                        let item = &parser.content()[range.start..];
+                        let marker_offset = range.start + (item.len() - item.trim_start().len());
+                        let (line_num, byte_column) = parser.offset_to_position(marker_offset);
                         if let Some(line) = parser.get_line(line_num)
-                            && let Some(num) = parse_item_number(line.trim_start())
+                            && let Some(num) = parse_item_number(&line[byte_column - 1..])
                         {
                             if num != 1 {
                                 *seen_non_one = true;
@@ -57,6 +69,7 @@ impl Rule for MD029 {
 
                             let is_valid = match style {
                                 "one" => num == 1,
+                                "zero" => num == 0,
                                 "ordered" => num == *expected,
                                 _ => {
                                     // "one_or_ordered": if we've seen non-1, require sequential;
@@ -70,16 +83,20 @@ impl Rule for MD029 {
                             };
 
                             if !is_valid {
-                                let should_be = if style == "one" { 1 } else { *expected };
-                                let indent = line.len() - line.trim_start().len();
-                                let digit_len = line
-                                    .trim_start()
+                                let should_be = match style {
+                                    "one" => 1,
+                                    "zero" => 0,
+                                    _ => *expected,
+                                };
+                                let indent = line[..byte_column - 1].chars().count();
+                                let digit_len = line[byte_column - 1..]
                                     .chars()
                                     .take_while(|c| c.is_ascii_digit())
                                     .count();
                                 violations.push(Violation {
                                     line: line_num,
                                     column: Some(indent + 1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: format!(
                                         "Ordered list item prefix: expected {}, found {}",
@@ -115,8 +132,8 @@ impl Rule for MD029 {
     }
 }
 
-/// Extract the leading integer from an ordered list item line (after stripping indentation).
-/// Returns `Some(n)` for `"3. text"` or `"3) text"`, `None` otherwise.
+/// Extract the leading integer from an ordered list item, starting at its number.
+/// Either CommonMark delimiter is accepted: `Some(3)` for `"3. text"` or `"3) text"`.
 fn parse_item_number(trimmed: &str) -> Option<usize> {
     let digits: String = trimmed.chars().take_while(|c| c.is_ascii_digit()).collect();
     if digits.is_empty() {
@@ -268,4 +285,38 @@ mod tests {
         // Each `1.` is the first item of a fresh list — no violations.
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_paren_delimiter() {
+        let content = "1) First\n1) Second\n3) Third\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD029.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "1) First\n2) Second\n3) Third\n");
+    }
+
+    #[test]
+    fn test_zero_style() {
+        let content = "0. First\n0. Second\n1. Third\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "zero" });
+        let violations = MD029.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "0. First\n0. Second\n0. Third\n");
+    }
+
+    #[test]
+    fn test_list_in_blockquote() {
+        let content = "> 1. First\n> 1. Second\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD029.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(3));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "> 1. First\n> 2. Second\n");
+    }
 }
@@ -16,6 +16,10 @@ impl Rule for MD030 {
         "Spaces after list markers"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["list-marker-space"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["ol", "ul", "whitespace"]
     }
@@ -121,6 +125,7 @@ impl Rule for MD030 {
                         violations.push(Violation {
                             line: line_number,
                             column: Some(line.len() - trimmed.len() + 2),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "Expected {} space(s) after list marker, found {}",
@@ -164,6 +169,7 @@ impl Rule for MD030 {
                             violations.push(Violation {
                                 line: line_number,
                                 column: Some(line.len() - trimmed.len() + dot_pos + 2),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "Expected {} space(s) after list marker, found {}",
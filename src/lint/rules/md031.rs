@@ -2,7 +2,7 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
 use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD031;
 
@@ -15,11 +15,25 @@ impl Rule for MD031 {
         "Fenced code blocks should be surrounded by blank lines"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["blanks-around-fences"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["code", "blank_lines"]
     }
 
-    fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+    fn default_config(&self) -> Value {
+        json!({ "list_items": true })
+    }
+
+    fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        // Whether fences inside list items are checked too
+        let list_items = config
+            .and_then(|c| c.get("list_items"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
         let mut violations = Vec::new();
         let lines = parser.lines();
 
@@ -27,9 +41,17 @@ impl Rule for MD031 {
         let mut code_block_starts = Vec::new();
         let mut code_block_ends = Vec::new();
         let mut in_fenced_block = false;
+        let mut item_depth = 0;
 
         for (event, range) in parser.parse_with_offsets() {
             match event {
+                Event::Start(Tag::Item) => item_depth += 1,
+                Event::End(TagEnd::Item) => item_depth -= 1,
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_)))
+                    if !list_items && item_depth > 0 =>
+                {
+                    in_fenced_block = false;
+                }
                 Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => {
                     let line = parser.offset_to_line(range.start);
                     code_block_starts.push(line);
@@ -39,12 +61,10 @@ impl Rule for MD031 {
                     // Track that we're in an indented block, but don't record it
                     in_fenced_block = false;
                 }
-                Event::End(TagEnd::CodeBlock) => {
-                    if in_fenced_block {
-                        let line = parser.offset_to_line(range.end);
-                        code_block_ends.push(line);
-                        in_fenced_block = false;
-                    }
+                Event::End(TagEnd::CodeBlock) if in_fenced_block => {
+                    let line = parser.offset_to_line(range.end);
+                    code_block_ends.push(line);
+                    in_fenced_block = false;
                 }
                 _ => {}
             }
@@ -62,6 +82,7 @@ impl Rule for MD031 {
                     violations.push(Violation {
                         line: start_line,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message:
                             "Fenced code blocks should be surrounded by blank lines (missing before)"
@@ -90,6 +111,7 @@ impl Rule for MD031 {
                     violations.push(Violation {
                         line: end_line,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message:
                             "Fenced code blocks should be surrounded by blank lines (missing after)"
@@ -203,4 +225,27 @@ mod tests {
         let expected = "Text\n\n```\ncode\n```\n\nMore";
         assert_eq!(fixed, expected);
     }
+
+    #[test]
+    fn test_list_items_option() {
+        let content = "- Item\n  ```\n  code\n  ```\n- Next\n";
+        let parser = MarkdownParser::new(content);
+
+        let violations = MD031.check(&parser, None);
+        assert_eq!(violations.len(), 2, "{violations:?}");
+
+        let config = serde_json::json!({ "list_items": false });
+        assert_eq!(MD031.check(&parser, Some(&config)).len(), 0);
+    }
+
+    #[test]
+    fn test_list_items_false_still_checks_top_level_fences() {
+        let content = "- Item\n  ```\n  code\n  ```\n\nText\n```\ncode\n```\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "list_items": false });
+        let violations = MD031.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 7);
+    }
 }
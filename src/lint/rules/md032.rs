@@ -22,6 +22,10 @@ impl Rule for MD032 {
         "Lists should be surrounded by blank lines"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["blanks-around-lists"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["bullet", "ul", "ol", "blank_lines"]
     }
@@ -63,6 +67,7 @@ impl Rule for MD032 {
                                 violations.push(Violation {
                                     line: line_num, // previous line (0-indexed → 1-indexed)
                                     column: Some(1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: "Line breaks ordered list continuation; subsequent \
                                          numbered items are parsed as text, not list items"
@@ -73,6 +78,7 @@ impl Rule for MD032 {
                                 violations.push(Violation {
                                     line: line_num + 1,
                                     column: Some(1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: "List should be surrounded by blank lines".to_string(),
                                     fix: None,
@@ -86,6 +92,7 @@ impl Rule for MD032 {
                     violations.push(Violation {
                         line: last_list_line + 1,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: "List should be surrounded by blank lines".to_string(),
                         fix: None,
@@ -94,6 +101,7 @@ impl Rule for MD032 {
                     violations.push(Violation {
                         line: line_num + 1,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: "List should be surrounded by blank lines".to_string(),
                         fix: None,
@@ -116,6 +124,7 @@ impl Rule for MD032 {
                 violations.push(Violation {
                     line: line_num + 1, // The line after the list
                     column: Some(1),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: "List should be surrounded by blank lines".to_string(),
                     fix: None,
@@ -15,6 +15,10 @@ impl Rule for MD033 {
         "Inline HTML"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-inline-html"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["html"]
     }
@@ -34,32 +38,30 @@ impl Rule for MD033 {
 
         for (event, range) in parser.parse_with_offsets() {
             // Check both Html (block) and InlineHtml events
-            let html_str = match event {
-                Event::Html(html) | Event::InlineHtml(html) => html.to_string(),
+            let html = match event {
+                Event::Html(html) | Event::InlineHtml(html) => html,
                 _ => continue,
             };
 
-            let line = parser.offset_to_line(range.start);
-
-            // Skip closing tags - only report opening tags
-            if html_str.trim().starts_with("</") {
-                continue;
-            }
-
-            // Extract tag name from HTML
-            if let Some(tag_name) = extract_tag_name(&html_str) {
-                let is_disallowed = allowed_elements.is_empty()
-                    || !allowed_elements.contains(&tag_name.to_lowercase());
-
-                if is_disallowed {
-                    violations.push(Violation {
-                        line,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: format!("Inline HTML element: <{}>", tag_name),
-                        fix: None,
-                    });
+            // A block of HTML can hold several elements; closing tags aren't reported.
+            for (tag_offset, tag_name) in opening_tags(&html) {
+                if allowed_elements.contains(&tag_name.to_lowercase()) {
+                    continue;
                 }
+
+                let (line, byte_column) = parser.offset_to_position(range.start + tag_offset);
+                let column = parser
+                    .get_line(line)
+                    .and_then(|text| text.get(..byte_column - 1))
+                    .map_or(byte_column, |prefix| prefix.chars().count() + 1);
+                violations.push(Violation {
+                    line,
+                    column: Some(column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: format!("Inline HTML element: <{}>", tag_name),
+                    fix: None,
+                });
             }
         }
 
@@ -71,21 +73,30 @@ impl Rule for MD033 {
     }
 }
 
-fn extract_tag_name(html: &str) -> Option<String> {
-    let trimmed = html.trim();
-    if trimmed.starts_with('<') {
-        // Handle opening tags, closing tags, and self-closing tags
-        let inner = trimmed.trim_start_matches('<').trim_start_matches('/');
-        // Skip comments (<!--), DOCTYPE (<!DOCTYPE), and other `<!` declarations
-        if inner.starts_with('!') {
-            return None;
+/// Byte offset and name of each opening (or self-closing) tag in `html`, ignoring
+/// attributes. Closing tags, comments, and `<!DOCTYPE>`-style declarations are skipped.
+fn opening_tags(html: &str) -> Vec<(usize, &str)> {
+    let mut tags = Vec::new();
+    let mut offset = 0;
+    while let Some(found) = html[offset..].find('<') {
+        let start = offset + found;
+        let rest = &html[start + 1..];
+        if rest.starts_with("!--") {
+            // Tags inside a comment are not elements.
+            offset = rest
+                .find("-->")
+                .map_or(html.len(), |end| start + 1 + end + 3);
+            continue;
+        }
+        offset = start + 1;
+        if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                .unwrap_or(rest.len());
+            tags.push((start, &rest[..end]));
         }
-        inner
-            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
-            .map(|end_pos| inner[..end_pos].to_string())
-    } else {
-        None
     }
+    tags
 }
 
 #[cfg(test)]
@@ -135,4 +146,46 @@ mod tests {
 
         assert!(!violations.is_empty());
     }
+
+    #[test]
+    fn test_message_names_element() {
+        let content = "Some text\n\n<div class=\"note\">\nContent\n</div>\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD033.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("div"));
+    }
+
+    #[test]
+    fn test_allowed_element_with_attributes() {
+        let content = "An image: <img src=\"x\" alt=\"y\"> and <IMG src=\"z\"/>";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "allowed_elements": ["img"] });
+        let violations = MD033.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_each_element_in_block_reported() {
+        let content = "<p>Text <b>bold</b></p>\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "allowed_elements": ["p"] });
+        let violations = MD033.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("<b>"));
+        assert_eq!(violations[0].column, Some(9));
+    }
+
+    #[test]
+    fn test_comments_not_reported() {
+        let content = "<!-- a <b>note</b> -->\n\nText\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD033.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
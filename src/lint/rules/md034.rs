@@ -15,6 +15,10 @@ impl Rule for MD034 {
         "Bare URL used"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-bare-urls"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links", "url"]
     }
@@ -43,6 +47,7 @@ impl Rule for MD034 {
                     violations.push(Violation {
                         line: line_number,
                         column: Some(url_match.start() + 1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: format!("Bare URL used: {}", url),
                         fix: None,
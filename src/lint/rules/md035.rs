@@ -1,7 +1,10 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use pulldown_cmark::Event;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::ops::Range;
 
 pub struct MD035;
 
@@ -14,10 +17,18 @@ impl Rule for MD035 {
         "Horizontal rule style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["hr-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["hr"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "---" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -26,64 +37,66 @@ impl Rule for MD035 {
 
         let mut violations = Vec::new();
         let mut first_hr_style: Option<String> = None;
-        let code_block_lines = parser.get_code_block_line_numbers();
 
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-            if code_block_lines.contains(&line_number) {
-                continue;
-            }
-            let trimmed = line.trim();
-
-            // Check if line is a horizontal rule (3+ of same char: - * _)
-            if is_horizontal_rule(trimmed) {
-                let current_style = get_hr_style(trimmed);
-
-                if style == "consistent" {
-                    // Track first style and ensure consistency
-                    if let Some(first_style) = &first_hr_style {
-                        if &current_style != first_style {
-                            violations.push(Violation {
-                                line: line_number,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Horizontal rule style should be consistent: expected {}, found {}",
-                                    first_style, current_style
-                                ),
-                                fix: Some(Fix {
-                                    line_start: line_number,
-                                    line_end: line_number,
-                                    column_start: None,
-                                    column_end: None,
-                                    replacement: "---".to_string(),
-                                    description: "Replace with canonical horizontal rule"
-                                        .to_string(),
-                                }),
-                            });
-                        }
-                    } else {
-                        first_hr_style = Some(current_style);
+        // Thematic breaks as the AST sees them, so setext heading underlines and
+        // list items made of markers are never rewritten.
+        let hrs: BTreeMap<usize, (usize, String)> = parser
+            .parse_with_offsets()
+            .filter(|(event, _)| matches!(event, Event::Rule))
+            .map(|(_, range)| hr_span(parser, range))
+            .map(|(line, column, style)| (line, (column, style)))
+            .collect();
+
+        for (line_number, (column, current_style)) in hrs {
+            // Only the marker itself is replaced, so blockquote prefixes and list
+            // indentation in front of it survive the fix.
+            let column_end = column + current_style.chars().count() - 1;
+
+            if style == "consistent" {
+                // Track first style and ensure consistency
+                if let Some(first_style) = &first_hr_style {
+                    if &current_style != first_style {
+                        violations.push(Violation {
+                            line: line_number,
+                            column: Some(column),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: format!(
+                                "Horizontal rule style should be consistent: expected {}, found {}",
+                                first_style, current_style
+                            ),
+                            fix: Some(Fix {
+                                line_start: line_number,
+                                line_end: line_number,
+                                column_start: Some(column),
+                                column_end: Some(column_end),
+                                replacement: first_style.clone(),
+                                description: "Replace with first horizontal rule style".to_string(),
+                            }),
+                        });
                     }
-                } else if current_style != style {
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: format!(
-                            "Horizontal rule style should be '{}', found '{}'",
-                            style, current_style
-                        ),
-                        fix: Some(Fix {
-                            line_start: line_number,
-                            line_end: line_number,
-                            column_start: None,
-                            column_end: None,
-                            replacement: style.to_string(),
-                            description: "Replace with required horizontal rule style".to_string(),
-                        }),
-                    });
+                } else {
+                    first_hr_style = Some(current_style);
                 }
+            } else if current_style != style {
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: format!(
+                        "Horizontal rule style should be '{}', found '{}'",
+                        style, current_style
+                    ),
+                    fix: Some(Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(column),
+                        column_end: Some(column_end),
+                        replacement: style.to_string(),
+                        description: "Replace with required horizontal rule style".to_string(),
+                    }),
+                });
             }
         }
 
@@ -95,28 +108,15 @@ impl Rule for MD035 {
     }
 }
 
-fn is_horizontal_rule(line: &str) -> bool {
-    let trimmed = line.trim();
-    if trimmed.len() < 3 {
-        return false;
-    }
-
-    // Check for 3+ dashes, asterisks, or underscores (possibly with spaces)
-    let chars: Vec<char> = trimmed.chars().filter(|&c| c != ' ').collect();
-    if chars.len() < 3 {
-        return false;
-    }
-
-    let first_char = chars[0];
-    if first_char != '-' && first_char != '*' && first_char != '_' {
-        return false;
-    }
-
-    chars.iter().all(|&c| c == first_char)
-}
-
-fn get_hr_style(line: &str) -> String {
-    line.trim().to_string()
+/// Line, 1-indexed character column, and text of the thematic break at `range`,
+/// leaving out any container prefix or indentation on its line.
+fn hr_span(parser: &MarkdownParser, range: Range<usize>) -> (usize, usize, String) {
+    let text = &parser.content()[range.clone()];
+    let style = text.trim();
+    let start = range.start + (text.len() - text.trim_start().len());
+    let (line, byte_column) = parser.offset_to_position(start);
+    let prefix = &parser.lines()[line - 1][..byte_column - 1];
+    (line, prefix.chars().count() + 1, style.to_string())
 }
 
 #[cfg(test)]
@@ -194,4 +194,58 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "---\n\nContent\n");
     }
+
+    #[test]
+    fn test_fix_consistent_uses_first_style() {
+        let content = "---\n\nContent\n\n***\n\nMore\n\n_ _ _\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "consistent" });
+        let violations = MD035.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "---\n\nContent\n\n---\n\nMore\n\n---\n");
+
+        let content = "* * *\n\nContent\n\n---\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD035.check(&parser, Some(&config));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "* * *\n\nContent\n\n* * *\n");
+    }
+
+    #[test]
+    fn test_fix_keeps_blockquote_prefix() {
+        let content = "> Quote\n>\n> ***\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD035.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(3));
+        assert!(
+            violations[0].message.ends_with("found '***'"),
+            "{}",
+            violations[0].message
+        );
+        assert_eq!(apply_fixes(content, &violations), "> Quote\n>\n> ---\n");
+    }
+
+    #[test]
+    fn test_fix_keeps_list_item_indentation() {
+        let content = "- item\n\n  * * *\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD035.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(3));
+        assert_eq!(apply_fixes(content, &violations), "- item\n\n  ---\n");
+    }
+
+    #[test]
+    fn test_setext_underline_not_flagged() {
+        let content = "***\n\nHeading\n---\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "***" });
+        let violations = MD035.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
 }
@@ -1,10 +1,24 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use serde_json::Value;
+use pulldown_cmark::{Event, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD036;
 
+const DEFAULT_PUNCTUATION: &str = ".,;:!?。，；：！？";
+
+/// Tracks whether the paragraph being walked is a single emphasis span.
+struct Paragraph {
+    start_line: usize,
+    /// Nesting depth below the paragraph itself.
+    depth: usize,
+    /// Direct children seen so far.
+    children: usize,
+    emphasis_only: bool,
+    text: String,
+}
+
 impl Rule for MD036 {
     fn name(&self) -> &str {
         "MD036"
@@ -14,43 +28,87 @@ impl Rule for MD036 {
         "Emphasis used instead of a heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-emphasis-as-heading"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings", "emphasis"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "punctuation": DEFAULT_PUNCTUATION })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let punctuation = config
             .and_then(|c| c.get("punctuation"))
             .and_then(|v| v.as_str())
-            .unwrap_or(".,;:!?。，；：！？");
+            .unwrap_or(DEFAULT_PUNCTUATION);
 
         let mut violations = Vec::new();
-        let lines = parser.lines();
-
-        // Track if we're in emphasis on a line-by-line basis
-        for (line_num, line) in lines.iter().enumerate() {
-            let line_number = line_num + 1;
-            let trimmed = line.trim();
-
-            // Check if the line looks like emphasis-only content
-            // Simple patterns: **text**, *text*, __text__, _text_
-            if is_emphasis_only_line(trimmed) {
-                // Check if it ends with punctuation (if so, likely not a heading)
-                if let Some(last_char) = trimmed
-                    .trim_end_matches('*')
-                    .trim_end_matches('_')
-                    .chars()
-                    .last()
-                    && !punctuation.contains(last_char)
-                {
-                    // Likely being used as a heading
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Emphasis used instead of a heading".to_string(),
-                        fix: None,
-                    });
+        let mut paragraph: Option<Paragraph> = None;
+
+        for (event, range) in parser.parse_with_offsets() {
+            if let Event::Start(Tag::Paragraph) = event {
+                paragraph = Some(Paragraph {
+                    start_line: parser.offset_to_line(range.start),
+                    depth: 0,
+                    children: 0,
+                    emphasis_only: false,
+                    text: String::new(),
+                });
+                continue;
+            }
+            let Some(p) = paragraph.as_mut() else {
+                continue;
+            };
+
+            match event {
+                Event::End(TagEnd::Paragraph) => {
+                    let end_line = parser.offset_to_line(range.end.saturating_sub(1));
+                    let single_line = end_line == p.start_line;
+                    let text = p.text.trim();
+                    // Trailing punctuation suggests a sentence or label, not a heading.
+                    if p.emphasis_only
+                        && p.children == 1
+                        && single_line
+                        && text
+                            .chars()
+                            .last()
+                            .is_some_and(|c| !punctuation.contains(c))
+                    {
+                        violations.push(Violation {
+                            line: p.start_line,
+                            column: Some(1),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: format!("Emphasis used instead of a heading: {}", text),
+                            fix: None,
+                        });
+                    }
+                    paragraph = None;
+                }
+                Event::Start(tag) => {
+                    let is_emphasis = matches!(tag, Tag::Strong | Tag::Emphasis);
+                    if p.depth == 0 {
+                        p.children += 1;
+                        p.emphasis_only = is_emphasis;
+                    } else if !is_emphasis {
+                        p.emphasis_only = false;
+                    }
+                    p.depth += 1;
+                }
+                Event::End(_) => {
+                    p.depth = p.depth.saturating_sub(1);
+                }
+                Event::Text(text) if p.depth > 0 => p.text.push_str(&text),
+                _ => {
+                    // Bare text, code spans, breaks, or HTML alongside the emphasis.
+                    if p.depth == 0 {
+                        p.children += 1;
+                    }
+                    p.emphasis_only = false;
                 }
             }
         }
@@ -63,43 +121,6 @@ impl Rule for MD036 {
     }
 }
 
-fn is_emphasis_only_line(line: &str) -> bool {
-    let trimmed = line.trim();
-
-    // Check for **text** or __text__ (strong)
-    if (trimmed.starts_with("**") && trimmed.ends_with("**") && trimmed.len() > 4)
-        || (trimmed.starts_with("__") && trimmed.ends_with("__") && trimmed.len() > 4)
-    {
-        // Make sure it's not just asterisks/underscores
-        let inner = trimmed
-            .trim_start_matches('*')
-            .trim_start_matches('_')
-            .trim_end_matches('*')
-            .trim_end_matches('_');
-        return !inner.is_empty() && !inner.chars().all(|c| c == '*' || c == '_');
-    }
-
-    // Check for *text* or _text_ (emphasis)
-    if (trimmed.starts_with('*')
-        && trimmed.ends_with('*')
-        && !trimmed.starts_with("**")
-        && trimmed.len() > 2)
-        || (trimmed.starts_with('_')
-            && trimmed.ends_with('_')
-            && !trimmed.starts_with("__")
-            && trimmed.len() > 2)
-    {
-        let inner = trimmed
-            .trim_start_matches('*')
-            .trim_start_matches('_')
-            .trim_end_matches('*')
-            .trim_end_matches('_');
-        return !inner.is_empty() && !inner.chars().all(|c| c == '*' || c == '_');
-    }
-
-    false
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +164,43 @@ mod tests {
 
         assert_eq!(violations.len(), 0); // Not emphasis-only line
     }
+
+    #[test]
+    fn test_emphasis_mid_paragraph() {
+        let content = "Some text\n**Summary**\nmore text\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD036.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_single_emphasis_and_nested_strong() {
+        let content = "*Introduction*\n\n***Both***\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD036.check(&parser, None);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 1);
+        assert_eq!(violations[1].line, 3);
+    }
+
+    #[test]
+    fn test_trailing_punctuation_configurable() {
+        let content = "**Really?**\n";
+        let parser = MarkdownParser::new(content);
+
+        assert_eq!(MD036.check(&parser, None).len(), 0);
+        let config = serde_json::json!({ "punctuation": "." });
+        assert_eq!(MD036.check(&parser, Some(&config)).len(), 1);
+    }
+
+    #[test]
+    fn test_emphasis_with_link_not_flagged() {
+        let content = "**See [docs](https://example.com)**\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD036.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
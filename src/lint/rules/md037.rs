@@ -9,7 +9,7 @@ pub struct MD037;
 impl MD037 {
     fn check_pattern(
         text: &str,
-        base_offset: usize,
+        run: &TextRun,
         marker: &str,
         parser: &MarkdownParser,
         violations: &mut Vec<Violation>,
@@ -30,22 +30,24 @@ impl MD037 {
                 // Make sure there are no emphasis markers in between (prevents matching across blocks)
                 if !between.contains(marker) {
                     // Found opening marker with space
-                    let abs_start = base_offset + start_pos;
+                    let abs_start = run.source_offset(start_pos);
                     let (line, col) = parser.offset_to_position(abs_start);
                     violations.push(Violation {
                         line,
                         column: Some(col),
+                        end_column: None,
                         rule: "MD037".to_string(),
                         message: "Spaces inside emphasis markers".to_string(),
                         fix: None,
                     });
 
                     // Found closing marker with space
-                    let abs_end = base_offset + end_pos;
+                    let abs_end = run.source_offset(end_pos);
                     let (end_line, end_col) = parser.offset_to_position(abs_end);
                     violations.push(Violation {
                         line: end_line,
                         column: Some(end_col),
+                        end_column: None,
                         rule: "MD037".to_string(),
                         message: "Spaces inside emphasis markers".to_string(),
                         fix: None,
@@ -58,7 +60,7 @@ impl MD037 {
 
     fn check_single_marker_pattern(
         text: &str,
-        base_offset: usize,
+        run: &TextRun,
         marker: &str,
         parser: &MarkdownParser,
         violations: &mut Vec<Violation>,
@@ -118,22 +120,24 @@ impl MD037 {
                     }
 
                     // Found opening marker with space
-                    let abs_start = base_offset + start_pos;
+                    let abs_start = run.source_offset(start_pos);
                     let (line, col) = parser.offset_to_position(abs_start);
                     violations.push(Violation {
                         line,
                         column: Some(col),
+                        end_column: None,
                         rule: "MD037".to_string(),
                         message: "Spaces inside emphasis markers".to_string(),
                         fix: None,
                     });
 
                     // Found closing marker with space
-                    let abs_end = base_offset + end_pos;
+                    let abs_end = run.source_offset(end_pos);
                     let (end_line, end_col) = parser.offset_to_position(abs_end);
                     violations.push(Violation {
                         line: end_line,
                         column: Some(end_col),
+                        end_column: None,
                         rule: "MD037".to_string(),
                         message: "Spaces inside emphasis markers".to_string(),
                         fix: None,
@@ -145,6 +149,48 @@ impl MD037 {
     }
 }
 
+/// Adjacent text on one line, with the source offset at which each piece starts.
+#[derive(Default)]
+struct TextRun {
+    text: String,
+    segments: Vec<(usize, usize)>,
+}
+
+impl TextRun {
+    fn push(&mut self, text: &str, source_offset: usize) {
+        self.segments.push((self.text.len(), source_offset));
+        self.text.push_str(text);
+    }
+
+    /// Map a byte position in the run back to its offset in the document.
+    fn source_offset(&self, position: usize) -> usize {
+        let index = self
+            .segments
+            .partition_point(|&(start, _)| start <= position)
+            .saturating_sub(1);
+        let (start, source) = self.segments[index];
+        source + (position - start)
+    }
+
+    fn flush(&mut self, parser: &MarkdownParser, violations: &mut Vec<Violation>) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            // Check for ** X ** pattern (strong with spaces)
+            MD037::check_pattern(&text, self, "**", parser, violations);
+
+            // Check for __ X __ pattern (strong with underscores)
+            MD037::check_pattern(&text, self, "__", parser, violations);
+
+            // Check for * X * pattern (emphasis with spaces) - but avoid matching inside **
+            MD037::check_single_marker_pattern(&text, self, "*", parser, violations);
+
+            // Check for _ X _ pattern (emphasis with underscores) - but avoid matching inside __
+            MD037::check_single_marker_pattern(&text, self, "_", parser, violations);
+        }
+        self.segments.clear();
+    }
+}
+
 impl Rule for MD037 {
     fn name(&self) -> &str {
         "MD037"
@@ -154,6 +200,10 @@ impl Rule for MD037 {
         "Spaces inside emphasis markers"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-space-in-emphasis"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace", "emphasis"]
     }
@@ -162,34 +212,25 @@ impl Rule for MD037 {
         let mut violations = Vec::new();
 
         // Emphasis with spaces inside is not parsed as emphasis by pulldown-cmark,
-        // so we need to find these patterns in Text events (but not in code)
-        let events: Vec<_> = parser.parse_with_offsets().collect();
+        // so we need to find these patterns in runs of plain text on one line. Inline
+        // code within a run is masked so its contents can't look like markers, while
+        // the run still spans it (e.g. `* a `code` b *`).
         let code_ranges = parser.get_code_ranges();
-
-        for (event, range) in events.iter() {
-            if let Event::Text(text) = event {
-                let text_str = text.as_ref();
-                let offset = range.start;
-
-                // Skip if this text is inside code
-                let in_code = code_ranges.iter().any(|r| r.contains(&offset));
-                if in_code {
-                    continue;
+        let mut run = TextRun::default();
+
+        for (event, range) in parser.parse_with_offsets() {
+            match event {
+                Event::Text(text) => {
+                    // Text inside code blocks is never emphasis.
+                    if !code_ranges.iter().any(|r| r.contains(&range.start)) {
+                        run.push(&text, range.start);
+                    }
                 }
-
-                // Check for ** X ** pattern (strong with spaces)
-                Self::check_pattern(text_str, offset, "**", parser, &mut violations);
-
-                // Check for __ X __ pattern (strong with underscores)
-                Self::check_pattern(text_str, offset, "__", parser, &mut violations);
-
-                // Check for * X * pattern (emphasis with spaces) - but avoid matching inside **
-                Self::check_single_marker_pattern(text_str, offset, "*", parser, &mut violations);
-
-                // Check for _ X _ pattern (emphasis with underscores) - but avoid matching inside __
-                Self::check_single_marker_pattern(text_str, offset, "_", parser, &mut violations);
+                Event::Code(_) => run.push(&"x".repeat(range.len()), range.start),
+                _ => run.flush(parser, &mut violations),
             }
         }
+        run.flush(parser, &mut violations);
 
         violations
     }
@@ -297,4 +338,46 @@ mod tests {
         // Should not flag correctly formatted adjacent bold sections
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_emphasis_lookalike_in_code_span() {
+        let content = "Compute `a * b * c` and `x _ y _ z` here.\n\nThis is * spaced * emphasis.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD037.check(&parser, None);
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.line == 3));
+        assert_eq!(violations[0].column, Some(9));
+    }
+
+    #[test]
+    fn test_spaced_emphasis_around_code_span() {
+        let content = "Text * a `code` b * end.";
+        let parser = MarkdownParser::new(content);
+        let violations = MD037.check(&parser, None);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].column, Some(6));
+        assert_eq!(violations[1].column, Some(18));
+    }
+
+    #[test]
+    fn test_markers_inside_code_span_not_paired_with_prose() {
+        let content = "A * b `c *` d.";
+        let parser = MarkdownParser::new(content);
+        let violations = MD037.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_math_ignored_when_enabled() {
+        let content = "Product $a_i * b_j * c$ here.";
+
+        let parser = MarkdownParser::new(content);
+        assert_eq!(MD037.check(&parser, None).len(), 2);
+
+        let parser = MarkdownParser::new(content).with_math(true);
+        assert_eq!(MD037.check(&parser, None).len(), 0);
+    }
 }
@@ -15,6 +15,10 @@ impl Rule for MD038 {
         "Spaces inside code span elements"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-space-in-code"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace", "code"]
     }
@@ -50,6 +54,7 @@ impl Rule for MD038 {
                         violations.push(Violation {
                             line,
                             column: Some(column),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: "Spaces inside code span elements".to_string(),
                             fix: None,
@@ -65,6 +70,7 @@ impl Rule for MD038 {
                         violations.push(Violation {
                             line,
                             column: Some(column),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: "Spaces inside code span elements".to_string(),
                             fix: None,
@@ -1,8 +1,9 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
-use regex::Regex;
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag};
 use serde_json::Value;
+use std::ops::Range;
 
 pub struct MD039;
 
@@ -15,46 +16,41 @@ impl Rule for MD039 {
         "Spaces inside link text"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-space-in-links"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["whitespace", "links"]
     }
 
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
+        let content = parser.content();
+
+        for (event, range) in parser.parse_with_offsets() {
+            let kind = match event {
+                Event::Start(Tag::Link { .. }) => "link",
+                Event::Start(Tag::Image { .. }) => "image",
+                _ => continue,
+            };
+            let Some(text) = bracketed_text(content, range) else {
+                continue;
+            };
+            let inner = &content[text.clone()];
+            if inner.trim().is_empty() {
+                continue;
+            }
 
-        // Regex to detect spaces inside link text: [ text](url) or [text ](url)
-        // Use [^\]]+ to avoid matching across ] boundaries (e.g. task list checkboxes like [ ])
-        let pattern = Regex::new(r"\[( [^\]]+|[^\]]+ )\]\([^\)]+\)").unwrap();
-
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-
-            for mat in pattern.find_iter(line) {
-                let matched_text = mat.as_str();
-                // Extract the link text between [ and ]
-                if let Some(bracket_end) = matched_text.find(']') {
-                    let link_text = &matched_text[1..bracket_end];
-
-                    // Report separate violations for leading and trailing spaces
-                    if link_text.starts_with(' ') {
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(mat.start() + 1),
-                            rule: self.name().to_string(),
-                            message: "Spaces inside link text".to_string(),
-                            fix: None,
-                        });
-                    }
-                    if link_text.ends_with(' ') {
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(mat.start() + 1),
-                            rule: self.name().to_string(),
-                            message: "Spaces inside link text".to_string(),
-                            fix: None,
-                        });
-                    }
-                }
+            // Report separate violations for leading and trailing spaces, each with a
+            // fix that deletes just that run.
+            let leading = inner.len() - inner.trim_start().len();
+            if leading > 0 {
+                violations.push(self.violation(parser, text.start..text.start + leading, kind));
+            }
+            let trailing = inner.len() - inner.trim_end().len();
+            if trailing > 0 {
+                violations.push(self.violation(parser, text.end - trailing..text.end, kind));
             }
         }
 
@@ -62,13 +58,77 @@ impl Rule for MD039 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
+    }
+}
+
+impl MD039 {
+    fn violation(&self, parser: &MarkdownParser, spaces: Range<usize>, kind: &str) -> Violation {
+        let (line, start) = char_position(parser, spaces.start);
+        let (_, end) = char_position(parser, spaces.end - 1);
+        Violation {
+            line,
+            column: Some(start),
+            end_column: None,
+            rule: self.name().to_string(),
+            message: "Spaces inside link text".to_string(),
+            fix: Some(Fix {
+                line_start: line,
+                line_end: line,
+                column_start: Some(start),
+                column_end: Some(end),
+                replacement: String::new(),
+                description: format!("Remove spaces inside {} text", kind),
+            }),
+        }
     }
 }
 
+/// Byte range of the text between a link's (or image's) opening `[` and its matching
+/// `]`, skipping nested brackets and backslash escapes.
+fn bracketed_text(content: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let source = &content[range.clone()];
+    let open = source.find('[')?;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, c) in source[open..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(range.start + open + 1..range.start + open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 1-indexed line and char column of a byte offset.
+fn char_position(parser: &MarkdownParser, offset: usize) -> (usize, usize) {
+    let (line, byte_column) = parser.offset_to_position(offset);
+    let line_text = parser.lines().get(line - 1).copied().unwrap_or("");
+    let column = line_text
+        .get(..byte_column - 1)
+        .map_or(byte_column, |prefix| prefix.chars().count() + 1);
+    (line, column)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_correct_link() {
@@ -120,4 +180,38 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_fix_trims_link_text() {
+        let content = "See [ Link ](url) here.\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD039.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].column, Some(6));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "See [Link](url) here.\n");
+    }
+
+    #[test]
+    fn test_fix_trims_image_alt_text() {
+        let content = "![  alt ](image.png)\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD039.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "![alt](image.png)\n");
+    }
+
+    #[test]
+    fn test_reference_link_and_code_span() {
+        let content = "[ ref link ][ref] and `[ code ](url)`\n\n[ref]: https://example.com\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD039.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(
+            fixed,
+            "[ref link][ref] and `[ code ](url)`\n\n[ref]: https://example.com\n"
+        );
+    }
 }
@@ -15,6 +15,10 @@ impl Rule for MD040 {
         "Fenced code blocks should have a language specified"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["fenced-code-language"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["code", "language"]
     }
@@ -41,6 +45,7 @@ impl Rule for MD040 {
                     violations.push(Violation {
                         line,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: "Fenced code block should have a language specified".to_string(),
                         fix: None,
@@ -51,6 +56,7 @@ impl Rule for MD040 {
                         violations.push(Violation {
                             line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!("Language '{}' is not in the allowed list", lang_str),
                             fix: None,
@@ -15,6 +15,10 @@ impl Rule for MD041 {
         "First line in file should be a top-level heading"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["first-line-heading", "first-line-h1"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings"]
     }
@@ -49,6 +53,7 @@ impl Rule for MD041 {
                         violations.push(Violation {
                             line: heading_line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "First line in file should be a level {} heading",
@@ -73,6 +78,7 @@ impl Rule for MD041 {
                     violations.push(Violation {
                         line: 1,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: "First line in file should be a top-level heading".to_string(),
                         fix: None,
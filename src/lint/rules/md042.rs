@@ -1,11 +1,19 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use pulldown_cmark::{Event, Tag};
-use serde_json::Value;
+use pulldown_cmark::{Event, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD042;
 
+/// A link being walked: where it starts, its destination, and whether it has content.
+struct OpenLink {
+    line: usize,
+    column: usize,
+    dest: String,
+    has_content: bool,
+}
+
 impl Rule for MD042 {
     fn name(&self) -> &str {
         "MD042"
@@ -15,27 +23,83 @@ impl Rule for MD042 {
         "No empty links"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-empty-links"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
 
-    fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+    fn default_config(&self) -> Value {
+        json!({ "empty_fragment": true, "empty_text": false })
+    }
+
+    fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        let option = |key: &str, default: bool| {
+            config
+                .and_then(|c| c.get(key))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(default)
+        };
+        let empty_fragment = option("empty_fragment", true);
+        let empty_text = option("empty_text", false);
+
         let mut violations = Vec::new();
+        let mut link: Option<OpenLink> = None;
 
         for (event, range) in parser.parse_with_offsets() {
-            if let Event::Start(Tag::Link { dest_url, .. }) = event {
-                // Check if the destination URL is empty or only contains "#"
-                let url_str = dest_url.to_string();
-                if url_str.is_empty() || url_str == "#" {
-                    let line = parser.offset_to_line(range.start);
-                    violations.push(Violation {
+            match event {
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    let (line, byte_column) = parser.offset_to_position(range.start);
+                    let line_text = parser.lines().get(line - 1).copied().unwrap_or("");
+                    let column = line_text
+                        .get(..byte_column - 1)
+                        .map_or(byte_column, |prefix| prefix.chars().count() + 1);
+                    link = Some(OpenLink {
                         line,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "No empty links".to_string(),
-                        fix: None,
+                        column,
+                        dest: dest_url.to_string(),
+                        has_content: false,
                     });
                 }
+                Event::Text(text) | Event::Code(text) => {
+                    if let Some(open) = link.as_mut()
+                        && !text.trim().is_empty()
+                    {
+                        open.has_content = true;
+                    }
+                }
+                Event::Start(Tag::Image { .. }) => {
+                    if let Some(open) = link.as_mut() {
+                        open.has_content = true;
+                    }
+                }
+                Event::End(TagEnd::Link) => {
+                    let Some(open) = link.take() else {
+                        continue;
+                    };
+                    let message = if open.dest.is_empty() {
+                        Some("No empty links (empty destination)")
+                    } else if empty_fragment && open.dest == "#" {
+                        Some("No empty links (empty fragment)")
+                    } else if empty_text && !open.has_content {
+                        Some("No empty links (empty link text)")
+                    } else {
+                        None
+                    };
+                    if let Some(message) = message {
+                        violations.push(Violation {
+                            line: open.line,
+                            column: Some(open.column),
+                            end_column: None,
+                            rule: self.name().to_string(),
+                            message: message.to_string(),
+                            fix: None,
+                        });
+                    }
+                }
+                _ => {}
             }
         }
 
@@ -102,4 +166,36 @@ mod tests {
 
         assert_eq!(violations.len(), 1); // Only second link has empty destination
     }
+
+    #[test]
+    fn test_fragment_link_clean() {
+        let content = "[Jump](#section)";
+        let parser = MarkdownParser::new(content);
+        let violations = MD042.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_empty_fragment_can_be_allowed() {
+        let content = "[Top](#)";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "empty_fragment": false });
+        let violations = MD042.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_empty_text_when_enabled() {
+        let content = "See [](https://example.com) and [![logo](logo.png)](https://example.com)";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "empty_text": true });
+        let violations = MD042.check(&parser, Some(&config));
+
+        // The image link has visible content; only the first link is empty.
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(5));
+        assert!(violations[0].message.contains("empty link text"));
+    }
 }
@@ -1,8 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD043;
 
@@ -15,11 +14,24 @@ impl Rule for MD043 {
         "Required heading structure"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["required-headings"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["headings"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "headings": [], "match_case": true })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        let match_case = config
+            .and_then(|c| c.get("match_case"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
         let headings = config
             .and_then(|c| c.get("headings"))
             .and_then(|v| v.as_array())
@@ -36,67 +48,50 @@ impl Rule for MD043 {
         };
 
         let mut violations = Vec::new();
-        let mut heading_index = 0;
-        let mut in_heading = false;
-        let mut current_heading_text = String::new();
-        let mut current_heading_line = 0;
-
-        for (event, range) in parser.parse_with_offsets() {
-            match event {
-                Event::Start(Tag::Heading { .. }) => {
-                    in_heading = true;
-                    current_heading_text.clear();
-                    current_heading_line = parser.offset_to_line(range.start);
-                }
-                Event::Text(text) if in_heading => {
-                    current_heading_text.push_str(&text);
-                }
-                Event::End(TagEnd::Heading(_)) if in_heading => {
-                    let text = current_heading_text.trim();
-
-                    if heading_index < required_headings.len() {
-                        let expected = &required_headings[heading_index];
-                        // Support wildcards (*)
-                        if expected != "*" && text != expected {
-                            violations.push(Violation {
-                                line: current_heading_line,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Expected heading '{}', found '{}'",
-                                    expected, text
-                                ),
-                                fix: None,
-                            });
-                        }
-                    } else {
-                        // Extra heading not in structure
-                        violations.push(Violation {
-                            line: current_heading_line,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: format!("Unexpected heading: '{}'", text),
-                            fix: None,
-                        });
-                    }
-
-                    heading_index += 1;
-                    in_heading = false;
+        let headings = parser.headings();
+
+        for (heading_index, heading) in headings.iter().enumerate() {
+            let text = heading.text.as_str();
+
+            if heading_index < required_headings.len() {
+                let expected = &required_headings[heading_index];
+                // Support wildcards (*)
+                if expected != "*"
+                    && !heading_matches(expected, heading.level as usize, text, match_case)
+                {
+                    violations.push(Violation {
+                        line: heading.line,
+                        column: Some(1),
+                        end_column: None,
+                        rule: self.name().to_string(),
+                        message: format!("Expected heading '{}', found '{}'", expected, text),
+                        fix: None,
+                    });
                 }
-                _ => {}
+            } else {
+                // Extra heading not in structure
+                violations.push(Violation {
+                    line: heading.line,
+                    column: Some(1),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: format!("Unexpected heading: '{}'", text),
+                    fix: None,
+                });
             }
         }
 
         // Check if we have fewer headings than required
-        if heading_index < required_headings.len() {
+        if headings.len() < required_headings.len() {
             violations.push(Violation {
                 line: parser.lines().len(),
                 column: Some(1),
+                end_column: None,
                 rule: self.name().to_string(),
                 message: format!(
                     "Missing required headings (expected {}, found {})",
                     required_headings.len(),
-                    heading_index
+                    headings.len()
                 ),
                 fix: None,
             });
@@ -110,6 +105,23 @@ impl Rule for MD043 {
     }
 }
 
+/// Whether a heading satisfies a required entry. An entry may start with a run of
+/// `#` and a space (e.g. `"## Background"`) to require that heading level too.
+fn heading_matches(expected: &str, level: usize, text: &str, match_case: bool) -> bool {
+    let hashes = expected.chars().take_while(|&c| c == '#').count();
+    let (expected_level, expected_text) = match expected[hashes..].strip_prefix(' ') {
+        Some(rest) if hashes > 0 => (Some(hashes), rest.trim()),
+        _ => (None, expected),
+    };
+
+    let text_matches = if match_case {
+        text == expected_text
+    } else {
+        text.to_lowercase() == expected_text.to_lowercase()
+    };
+    text_matches && expected_level.is_none_or(|expected_level| expected_level == level)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +175,36 @@ mod tests {
 
         assert_eq!(violations.len(), 0); // Wildcard matches anything
     }
+
+    #[test]
+    fn test_match_case_disabled() {
+        let content = "# Introduction\n## background";
+        let parser = MarkdownParser::new(content);
+        let rule = MD043;
+        let config = serde_json::json!({ "headings": ["Introduction", "Background"] });
+        assert_eq!(rule.check(&parser, Some(&config)).len(), 1);
+
+        let config = serde_json::json!({
+            "headings": ["Introduction", "Background"],
+            "match_case": false
+        });
+        assert_eq!(rule.check(&parser, Some(&config)).len(), 0);
+    }
+
+    #[test]
+    fn test_level_specifiers() {
+        let content = "# Introduction\n### Background";
+        let parser = MarkdownParser::new(content);
+        let rule = MD043;
+        let config = serde_json::json!({ "headings": ["# Introduction", "## Background"] });
+        let violations = rule.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 2);
+        assert!(violations[0].message.contains("## Background"));
+
+        let content = "# Introduction\n## Background";
+        let parser = MarkdownParser::new(content);
+        assert_eq!(rule.check(&parser, Some(&config)).len(), 0);
+    }
 }
@@ -15,6 +15,10 @@ impl Rule for MD044 {
         "Proper names should have the correct capitalization"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["proper-names"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["spelling"]
     }
@@ -41,29 +45,30 @@ impl Rule for MD044 {
         };
 
         let mut violations = Vec::new();
+        // Fenced and indented code blocks plus inline code spans, by byte range.
+        let code_ranges = parser.get_code_ranges();
 
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
 
-            // Skip code blocks if configured
-            if !code_blocks
-                && (line.starts_with("    ") || line.starts_with('\t') || line.contains("```"))
-            {
-                continue;
-            }
-
             // Check each proper name
             for name in &proper_names {
                 // Create case-insensitive regex with word boundaries
                 let pattern = format!(r"(?i)\b{}\b", regex::escape(name));
                 if let Ok(re) = Regex::new(&pattern) {
                     for mat in re.find_iter(line) {
+                        // Skip code if configured
+                        let offset = parser.line_offset_to_absolute(line_number, mat.start());
+                        if !code_blocks && code_ranges.iter().any(|r| r.contains(&offset)) {
+                            continue;
+                        }
                         let found = mat.as_str();
                         // Check if capitalization matches
                         if found != name {
                             violations.push(Violation {
                                 line: line_number,
                                 column: Some(mat.start() + 1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "Proper name '{}' should be capitalized as '{}'",
@@ -138,4 +143,25 @@ mod tests {
         // Should only match whole word "JavaScript", not "JavaScriptCore"
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_code_skipped_when_code_blocks_off() {
+        let content = "Use javascript.\n\n```sh\nnode javascript.js\n```\n\nRun `javascript` here.\n\n    javascript\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "names": ["JavaScript"], "code_blocks": false });
+        let violations = MD044.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+    }
+
+    #[test]
+    fn test_code_checked_by_default() {
+        let content = "```sh\nnode javascript.js\n```\n\nRun `javascript` here.\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "names": ["JavaScript"] });
+        let violations = MD044.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 2);
+    }
 }
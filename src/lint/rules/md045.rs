@@ -2,10 +2,17 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
 use pulldown_cmark::{Event, Tag, TagEnd};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD045;
 
+/// HTML attributes that mark the wrapped content as purely decorative.
+const DECORATIVE_ATTRIBUTES: &[&str] = &[
+    "role=\"presentation\"",
+    "role=\"none\"",
+    "aria-hidden=\"true\"",
+];
+
 impl Rule for MD045 {
     fn name(&self) -> &str {
         "MD045"
@@ -15,38 +22,61 @@ impl Rule for MD045 {
         "Images should have alternate text (alt text)"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["no-alt-text"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["accessibility", "images"]
     }
 
-    fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
+    fn default_config(&self) -> Value {
+        json!({ "allow_decorative": false })
+    }
+
+    fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
+        let allow_decorative = config
+            .and_then(|c| c.get("allow_decorative"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         let mut violations = Vec::new();
-        let mut in_image = false;
-        let mut image_start_line = 0;
+        let mut image: Option<(usize, usize, bool)> = None;
         let mut alt_text = String::new();
+        // Names of open HTML elements carrying a decorative attribute.
+        let mut decorative_elements: Vec<String> = Vec::new();
 
         for (event, range) in parser.parse_with_offsets() {
             match event {
-                Event::Start(Tag::Image { .. }) => {
-                    in_image = true;
-                    image_start_line = parser.offset_to_line(range.start);
+                Event::Start(Tag::Image { title, .. }) => {
+                    let (line, column) = parser.offset_to_position(range.start);
+                    let decorative = title.trim().eq_ignore_ascii_case("decorative")
+                        || !decorative_elements.is_empty();
+                    image = Some((line, column, decorative));
                     alt_text.clear();
                 }
-                Event::Text(text) if in_image => {
+                Event::Text(text) if image.is_some() => {
                     alt_text.push_str(&text);
                 }
-                Event::End(TagEnd::Image) if in_image => {
+                Event::End(TagEnd::Image) => {
                     // Only report if alt_text is completely empty (not just whitespace)
-                    if alt_text.is_empty() {
+                    if let Some((line, column, decorative)) = image.take()
+                        && alt_text.is_empty()
+                        && !(allow_decorative && decorative)
+                    {
+                        let line_text = parser.lines().get(line - 1).copied().unwrap_or("");
                         violations.push(Violation {
-                            line: image_start_line,
-                            column: Some(1),
+                            line,
+                            column: Some(char_column(line_text, column)),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: "Images should have alternate text (alt text)".to_string(),
                             fix: None,
                         });
                     }
-                    in_image = false;
+                }
+                Event::Html(html) | Event::InlineHtml(html) => {
+                    track_decorative_elements(&html, &mut decorative_elements);
                 }
                 _ => {}
             }
@@ -60,6 +90,35 @@ impl Rule for MD045 {
     }
 }
 
+/// Convert a 1-indexed byte column to a 1-indexed char column.
+fn char_column(line: &str, byte_column: usize) -> usize {
+    line.get(..byte_column - 1)
+        .map_or(byte_column, |prefix| prefix.chars().count() + 1)
+}
+
+/// Push opening tags with a decorative attribute and pop them at their closing tag.
+fn track_decorative_elements(html: &str, open: &mut Vec<String>) {
+    let html = html.trim();
+    if let Some(closing) = html.strip_prefix("</") {
+        let name = closing.trim_end_matches('>').trim();
+        if open
+            .last()
+            .is_some_and(|top| top.eq_ignore_ascii_case(name))
+        {
+            open.pop();
+        }
+    } else if let Some(tag) = html.strip_prefix('<')
+        && !html.ends_with("/>")
+        && DECORATIVE_ATTRIBUTES.iter().any(|attr| html.contains(attr))
+    {
+        let name: String = tag
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        open.push(name);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +162,37 @@ mod tests {
 
         assert_eq!(violations.len(), 1); // Only second image lacks alt
     }
+
+    #[test]
+    fn test_reference_image_without_alt() {
+        let content = "Logo: ![][logo]\n\n[logo]: logo.png\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD045.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+        assert_eq!(violations[0].column, Some(7));
+    }
+
+    #[test]
+    fn test_decorative_title_exempt_when_allowed() {
+        let content = "![](divider.png \"decorative\")\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "allow_decorative": true });
+
+        assert_eq!(MD045.check(&parser, None).len(), 1);
+        assert_eq!(MD045.check(&parser, Some(&config)).len(), 0);
+    }
+
+    #[test]
+    fn test_decorative_html_wrapper_exempt_when_allowed() {
+        let content = "<span role=\"presentation\">![](divider.png)</span> and ![](chart.png)\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "allow_decorative": true });
+        let violations = MD045.check(&parser, Some(&config));
+
+        // Only the image outside the decorative wrapper is reported.
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(55));
+    }
 }
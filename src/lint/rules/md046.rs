@@ -1,8 +1,8 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
-use pulldown_cmark::{CodeBlockKind, Event, Tag};
-use serde_json::Value;
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
+use serde_json::{Value, json};
 
 pub struct MD046;
 
@@ -15,10 +15,18 @@ impl Rule for MD046 {
         "Code block style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["code-block-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["code"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "fenced" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -27,6 +35,9 @@ impl Rule for MD046 {
 
         let mut violations = Vec::new();
         let mut first_style: Option<&str> = None;
+        // An indented block that should be fenced: its first line, message, and the
+        // code collected so far. The violation is pushed once the block ends.
+        let mut pending: Option<(usize, String, String)> = None;
 
         for (event, range) in parser.parse_with_offsets() {
             let line = parser.offset_to_line(range.start);
@@ -41,6 +52,7 @@ impl Rule for MD046 {
                                 violations.push(Violation {
                                     line,
                                     column: Some(1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: format!(
                                         "Code block style should be consistent: expected {}, found {}",
@@ -56,6 +68,7 @@ impl Rule for MD046 {
                         violations.push(Violation {
                             line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: "Code block style should be 'indented', found 'fenced'"
                                 .to_string(),
@@ -69,28 +82,35 @@ impl Rule for MD046 {
                     if style == "consistent" {
                         if let Some(first) = first_style {
                             if current_style != first {
-                                violations.push(Violation {
-                                    line,
-                                    column: Some(1),
-                                    rule: self.name().to_string(),
-                                    message: format!(
-                                        "Code block style should be consistent: expected {}, found {}",
-                                        first, current_style
-                                    ),
-                                    fix: None,
-                                });
+                                let message = format!(
+                                    "Code block style should be consistent: expected {}, found {}",
+                                    first, current_style
+                                );
+                                pending = Some((line, message, String::new()));
                             }
                         } else {
                             first_style = Some(current_style);
                         }
                     } else if style == "fenced" {
+                        let message =
+                            "Code block style should be 'fenced', found 'indented'".to_string();
+                        pending = Some((line, message, String::new()));
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some((_, _, code)) = pending.as_mut() {
+                        code.push_str(&text);
+                    }
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    if let Some((line, message, code)) = pending.take() {
                         violations.push(Violation {
                             line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
-                            message: "Code block style should be 'fenced', found 'indented'"
-                                .to_string(),
-                            fix: None,
+                            message,
+                            fix: fenced_fix(parser, line, &code),
                         });
                     }
                 }
@@ -102,13 +122,62 @@ impl Rule for MD046 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
+    }
+}
+
+/// Replace the indented block starting at `line` with a fenced block holding `code`,
+/// which pulldown-cmark has already stripped of the block's base indentation.
+///
+/// Blocks whose first line does not start with plain indentation (e.g. inside a
+/// blockquote) are left alone.
+fn fenced_fix(parser: &MarkdownParser, line: usize, code: &str) -> Option<Fix> {
+    let first_line = parser.get_line(line)?;
+    let leading = &first_line[..first_line.len() - first_line.trim_start().len()];
+    let indent = leading
+        .strip_suffix("    ")
+        .or_else(|| leading.strip_suffix('\t'))?;
+
+    let fence = fence_for(code);
+    let mut replacement = format!("{indent}{fence}\n");
+    for code_line in code.lines() {
+        if !code_line.is_empty() {
+            replacement.push_str(indent);
+            replacement.push_str(code_line);
+        }
+        replacement.push('\n');
     }
+    replacement.push_str(indent);
+    replacement.push_str(&fence);
+
+    Some(Fix {
+        line_start: line,
+        line_end: line + code.lines().count().max(1) - 1,
+        column_start: None,
+        column_end: None,
+        replacement,
+        description: "Convert indented code block to fenced".to_string(),
+    })
+}
+
+/// A backtick fence at least three long and longer than any backtick run in `code`,
+/// so the code cannot close it early.
+fn fence_for(code: &str) -> String {
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat((longest_run + 1).max(3))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_consistent_fenced() {
@@ -151,4 +220,62 @@ mod tests {
 
         assert_eq!(violations.len(), 1);
     }
+
+    #[test]
+    fn test_fix_preserves_inner_indentation() {
+        let content = "Text\n\n    fn main() {\n        body();\n    }\n\nMore\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD046.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(
+            fixed,
+            "Text\n\n```\nfn main() {\n    body();\n}\n```\n\nMore\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_keeps_interior_blank_lines() {
+        let content = "    one\n\n    two\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD046.check(&parser, None);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "```\none\n\ntwo\n```\n");
+    }
+
+    #[test]
+    fn test_fix_fence_longer_than_inner_backticks() {
+        let content = "    ```\n    code\n    ```\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD046.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "````\n```\ncode\n```\n````\n");
+    }
+
+    #[test]
+    fn test_fence_for() {
+        assert_eq!(fence_for("code\n"), "```");
+        assert_eq!(fence_for("`inline` and ``double``\n"), "```");
+        assert_eq!(fence_for("````\n"), "`````");
+    }
+
+    #[test]
+    fn test_fix_in_list_item_keeps_item_indent() {
+        let content = "- Item\n\n      code\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD046.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "- Item\n\n  ```\n  code\n  ```\n");
+    }
+
+    #[test]
+    fn test_no_fix_in_blockquote() {
+        let content = "> Text\n>\n>     code\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD046.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_none());
+    }
 }
@@ -14,6 +14,10 @@ impl Rule for MD047 {
         "Files should end with a single newline character"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["single-trailing-newline"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["blank_lines"]
     }
@@ -30,19 +34,21 @@ impl Rule for MD047 {
 
         // Check if file ends with a newline
         if !content.ends_with('\n') {
-            // Missing newline at end
-            let last_line = lines.last().unwrap_or(&"");
+            // Missing newline at end. Insert it after the last character rather than
+            // rewriting the line, so fixes elsewhere on that line can still apply.
+            let end = lines.last().map_or(0, |line| line.chars().count());
             violations.push(Violation {
                 line: lines.len(),
                 column: Some(1),
+                end_column: None,
                 rule: self.name().to_string(),
                 message: "Files should end with a single newline character".to_string(),
                 fix: Some(Fix {
                     line_start: lines.len(),
                     line_end: lines.len(),
-                    column_start: None,
-                    column_end: None,
-                    replacement: format!("{}\n", last_line),
+                    column_start: Some(end + 1),
+                    column_end: Some(end),
+                    replacement: "\n".to_string(),
                     description: "Add newline at end of file".to_string(),
                 }),
             });
@@ -51,30 +57,23 @@ impl Rule for MD047 {
             let trailing_newlines = content.chars().rev().take_while(|&c| c == '\n').count();
 
             if trailing_newlines > 1 {
-                // Remove all but one newline
-                // The last "line" in lines() will be empty string(s) for trailing newlines
-                let last_content_line_idx = lines.len().saturating_sub(trailing_newlines);
-                let last_content_line = if last_content_line_idx > 0 {
-                    lines.get(last_content_line_idx - 1).unwrap_or(&"")
-                } else {
-                    ""
-                };
+                // Remove all but one newline. `lines()` drops the final newline, so
+                // only the last `trailing_newlines - 1` lines are blank; delete just
+                // those, leaving the content line free for other fixes.
+                let first_blank_line = lines.len() + 2 - trailing_newlines;
 
                 violations.push(Violation {
                     line: lines.len(),
                     column: Some(1),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: "Files should end with a single newline character".to_string(),
                     fix: Some(Fix {
-                        line_start: last_content_line_idx.max(1),
+                        line_start: first_blank_line,
                         line_end: lines.len(),
                         column_start: None,
                         column_end: None,
-                        replacement: if last_content_line_idx > 0 {
-                            format!("{}\n", last_content_line)
-                        } else {
-                            "\n".to_string()
-                        },
+                        replacement: String::new(),
                         description: "Remove extra newlines at end of file".to_string(),
                     }),
                 });
@@ -151,4 +150,24 @@ mod tests {
         let fixed = apply_fixes(content, &violations);
         assert_eq!(fixed, "# Heading\n\nContent\n");
     }
+
+    #[test]
+    fn test_fix_removes_extra_trailing_newlines() {
+        let content = "# Heading\n\nContent\n\n\n\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD047.check(&parser, None);
+        assert_eq!(violations.len(), 1);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "# Heading\n\nContent\n");
+    }
+
+    #[test]
+    fn test_fix_leaves_last_line_free_for_other_fixes() {
+        let content = "# Heading\n\nContent   ";
+        let parser = MarkdownParser::new(content);
+        let mut violations = MD047.check(&parser, None);
+        violations.extend(crate::lint::rules::MD009.check(&parser, None));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "# Heading\n\nContent\n");
+    }
 }
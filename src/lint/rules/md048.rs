@@ -1,7 +1,8 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
-use serde_json::Value;
+use pulldown_cmark::{CodeBlockKind, Event, Tag};
+use serde_json::{Value, json};
 
 pub struct MD048;
 
@@ -14,10 +15,18 @@ impl Rule for MD048 {
         "Code fence style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["code-fence-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["code"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "backtick" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -26,79 +35,51 @@ impl Rule for MD048 {
 
         let mut violations = Vec::new();
         let mut first_style: Option<char> = None;
-        let mut in_code_block = false;
-
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-            let trimmed = line.trim();
-
-            // Check if line is a code fence (opening or closing)
-            if trimmed.starts_with("```") {
-                // Only check opening fence, not closing
-                if !in_code_block {
-                    let fence_char = '`';
-                    if style == "consistent" {
-                        if let Some(first) = first_style {
-                            if fence_char != first {
-                                violations.push(Violation {
-                                    line: line_number,
-                                    column: Some(1),
-                                    rule: self.name().to_string(),
-                                    message: format!(
-                                        "Code fence style should be consistent: expected '{}', found '{}'",
-                                        first, fence_char
-                                    ),
-                                    fix: None,
-                                });
-                            }
-                        } else {
-                            first_style = Some(fence_char);
-                        }
-                    } else if style == "tilde" {
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: "Code fence style should be 'tilde' (~), found backtick (`)"
-                                .to_string(),
-                            fix: None,
-                        });
+
+        for (event, range) in parser.parse_with_offsets() {
+            let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) = event else {
+                continue;
+            };
+            // The block starts at its opening fence, possibly after indentation.
+            let fence = &parser.content()[range.start..];
+            let fence_offset = range.start + (fence.len() - fence.trim_start().len());
+            let Some(fence_char) = parser.content()[fence_offset..].chars().next() else {
+                continue;
+            };
+            let (line_number, byte_column) = parser.offset_to_position(fence_offset);
+            let line = parser.get_line(line_number).unwrap_or("");
+            let column = line[..byte_column - 1].chars().count() + 1;
+
+            let message = match style {
+                "consistent" => match first_style {
+                    Some(first) if first != fence_char => Some(format!(
+                        "Code fence style should be consistent: expected '{}', found '{}'",
+                        first, fence_char
+                    )),
+                    Some(_) => None,
+                    None => {
+                        first_style = Some(fence_char);
+                        None
                     }
+                },
+                "tilde" if fence_char == '`' => {
+                    Some("Code fence style should be 'tilde' (~), found backtick (`)".to_string())
                 }
-                in_code_block = !in_code_block;
-            } else if trimmed.starts_with("~~~") {
-                // Only check opening fence, not closing
-                if !in_code_block {
-                    let fence_char = '~';
-                    if style == "consistent" {
-                        if let Some(first) = first_style {
-                            if fence_char != first {
-                                violations.push(Violation {
-                                    line: line_number,
-                                    column: Some(1),
-                                    rule: self.name().to_string(),
-                                    message: format!(
-                                        "Code fence style should be consistent: expected '{}', found '{}'",
-                                        first, fence_char
-                                    ),
-                                    fix: None,
-                                });
-                            }
-                        } else {
-                            first_style = Some(fence_char);
-                        }
-                    } else if style == "backtick" {
-                        violations.push(Violation {
-                            line: line_number,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: "Code fence style should be 'backtick' (`), found tilde (~)"
-                                .to_string(),
-                            fix: None,
-                        });
-                    }
+                "backtick" if fence_char == '~' => {
+                    Some("Code fence style should be 'backtick' (`), found tilde (~)".to_string())
                 }
-                in_code_block = !in_code_block;
+                _ => None,
+            };
+
+            if let Some(message) = message {
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message,
+                    fix: None,
+                });
             }
         }
 
@@ -155,4 +136,39 @@ mod tests {
 
         assert_eq!(violations.len(), 1); // Only opening
     }
+
+    #[test]
+    fn test_longer_backtick_fence() {
+        let content = "````\n```\nnested\n```\n````\n\n```\ncode\n```\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "consistent" });
+        assert_eq!(MD048.check(&parser, Some(&config)).len(), 0);
+
+        let config = serde_json::json!({ "style": "tilde" });
+        let violations = MD048.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 1);
+        assert_eq!(violations[1].line, 7);
+    }
+
+    #[test]
+    fn test_backticks_in_prose_are_not_fences() {
+        let content = "```inline``` code at the start of a line\n\n~~~\ncode\n~~~\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "tilde" });
+        let violations = MD048.check(&parser, Some(&config));
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_fence_in_list_reports_fence_column() {
+        let content = "- item\n\n  ~~~\n  code\n  ~~~\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD048.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(violations[0].column, Some(3));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD049;
 
@@ -14,10 +14,18 @@ impl Rule for MD049 {
         "Emphasis style should be consistent"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["emphasis-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["emphasis"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "asterisk" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -27,13 +35,17 @@ impl Rule for MD049 {
         let mut violations = Vec::new();
         let mut first_style: Option<char> = None;
 
-        // Get byte ranges that are in code (more precise than line numbers)
-        let code_ranges = parser.get_code_ranges();
+        // Get byte ranges that are in code or math (more precise than line numbers)
+        let skipped_ranges: Vec<_> = parser
+            .get_code_ranges()
+            .iter()
+            .chain(parser.get_math_ranges())
+            .collect();
 
-        // Helper function to check if a position is within code
+        // Helper function to check if a position is within code or math
         let is_in_code = |line_num: usize, byte_offset: usize| -> bool {
             let absolute_offset = parser.line_offset_to_absolute(line_num, byte_offset);
-            code_ranges
+            skipped_ranges
                 .iter()
                 .any(|range| range.contains(&absolute_offset))
         };
@@ -43,6 +55,7 @@ impl Rule for MD049 {
 
             // Look for emphasis patterns: *text* or _text_ (not ** or __)
             let chars: Vec<char> = line.chars().collect();
+            let byte_offsets: Vec<usize> = line.char_indices().map(|(offset, _)| offset).collect();
             let mut i = 0;
 
             while i < chars.len() {
@@ -75,7 +88,7 @@ impl Rule for MD049 {
 
                                 if !close_is_strong && can_close {
                                     // Skip if this emphasis is inside code
-                                    if is_in_code(line_number, i) {
+                                    if is_in_code(line_number, byte_offsets[i]) {
                                         i = j; // Skip to after closing
                                         break;
                                     }
@@ -96,6 +109,7 @@ impl Rule for MD049 {
                                                 violations.push(Violation {
                                                     line: line_number,
                                                     column: Some(i + 1),
+                                                    end_column: None,
                                                     rule: self.name().to_string(),
                                                     message: format!(
                                                         "Emphasis style should be consistent: expected '{}', found '{}'",
@@ -106,6 +120,7 @@ impl Rule for MD049 {
                                                 violations.push(Violation {
                                                     line: line_number,
                                                     column: Some(j + 1),
+                                                    end_column: None,
                                                     rule: self.name().to_string(),
                                                     message: format!(
                                                         "Emphasis style should be consistent: expected '{}', found '{}'",
@@ -123,6 +138,7 @@ impl Rule for MD049 {
                                             violations.push(Violation {
                                                 line: line_number,
                                                 column: Some(i + 1),
+                                                end_column: None,
                                                 rule: self.name().to_string(),
                                                 message: format!(
                                                     "Emphasis style should be '{}', found '{}'",
@@ -133,6 +149,7 @@ impl Rule for MD049 {
                                             violations.push(Violation {
                                                 line: line_number,
                                                 column: Some(j + 1),
+                                                end_column: None,
                                                 rule: self.name().to_string(),
                                                 message: format!(
                                                     "Emphasis style should be '{}', found '{}'",
@@ -268,4 +285,16 @@ mod tests {
         // Should not flag asterisks in code as emphasis markers
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_math_ignored_when_enabled() {
+        let content = "Compute $a_i * b_j * c$ and $x _y_ z$.";
+        let config = serde_json::json!({ "style": "underscore" });
+
+        let parser = MarkdownParser::new(content);
+        assert!(!MD049.check(&parser, Some(&config)).is_empty());
+
+        let parser = MarkdownParser::new(content).with_math(true);
+        assert!(MD049.check(&parser, Some(&config)).is_empty());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::{Fix, Violation};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 pub struct MD050;
 
@@ -14,10 +14,18 @@ impl Rule for MD050 {
         "Strong style should be consistent"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["strong-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["emphasis"]
     }
 
+    fn default_config(&self) -> Value {
+        json!({ "style": "asterisk" })
+    }
+
     fn check(&self, parser: &MarkdownParser, config: Option<&Value>) -> Vec<Violation> {
         let style = config
             .and_then(|c| c.get("style"))
@@ -27,11 +35,21 @@ impl Rule for MD050 {
         let mut violations = Vec::new();
         let mut first_style: Option<&str> = None;
 
+        // `*` and `_` inside math are operators, not strong markers
+        let math_ranges = parser.get_math_ranges();
+        let is_in_math = |line_num: usize, byte_offset: usize| -> bool {
+            let absolute_offset = parser.line_offset_to_absolute(line_num, byte_offset);
+            math_ranges
+                .iter()
+                .any(|range| range.contains(&absolute_offset))
+        };
+
         for (line_num, line) in parser.lines().iter().enumerate() {
             let line_number = line_num + 1;
 
             // Look for strong patterns: **text** or __text__
             let chars: Vec<char> = line.chars().collect();
+            let byte_offsets: Vec<usize> = line.char_indices().map(|(offset, _)| offset).collect();
             let mut i = 0;
 
             while i + 1 < chars.len() {
@@ -48,6 +66,11 @@ impl Rule for MD050 {
                                 if close_two == two_char {
                                     found_close = true;
 
+                                    if is_in_math(line_number, byte_offsets[i]) {
+                                        i = j + 1; // Skip to after closing
+                                        break;
+                                    }
+
                                     // Track style
                                     let current_style = if two_char == "**" {
                                         "asterisk"
@@ -73,6 +96,7 @@ impl Rule for MD050 {
                                                 violations.push(Violation {
                                                     line: line_number,
                                                     column: Some(i + 1),
+                                                    end_column: None,
                                                     rule: self.name().to_string(),
                                                     message: format!(
                                                         "Strong style should be consistent: expected '{}', found '{}'",
@@ -83,6 +107,7 @@ impl Rule for MD050 {
                                                 violations.push(Violation {
                                                     line: line_number,
                                                     column: Some(j + 1),
+                                                    end_column: None,
                                                     rule: self.name().to_string(),
                                                     message: format!(
                                                         "Strong style should be consistent: expected '{}', found '{}'",
@@ -102,6 +127,7 @@ impl Rule for MD050 {
                                             violations.push(Violation {
                                                 line: line_number,
                                                 column: Some(i + 1),
+                                                end_column: None,
                                                 rule: self.name().to_string(),
                                                 message: format!(
                                                     "Strong style should be '{}', found '{}'",
@@ -112,6 +138,7 @@ impl Rule for MD050 {
                                             violations.push(Violation {
                                                 line: line_number,
                                                 column: Some(j + 1),
+                                                end_column: None,
                                                 rule: self.name().to_string(),
                                                 message: format!(
                                                     "Strong style should be '{}', found '{}'",
@@ -194,4 +221,16 @@ mod tests {
         // Reports violation for both opening and closing markers
         assert_eq!(violations.len(), 2);
     }
+
+    #[test]
+    fn test_math_ignored_when_enabled() {
+        let content = "Power $a __ b __ c$ here.";
+        let config = serde_json::json!({ "style": "asterisk" });
+
+        let parser = MarkdownParser::new(content);
+        assert_eq!(MD050.check(&parser, Some(&config)).len(), 2);
+
+        let parser = MarkdownParser::new(content).with_math(true);
+        assert_eq!(MD050.check(&parser, Some(&config)).len(), 0);
+    }
 }
@@ -2,8 +2,9 @@ use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
 use crate::types::Violation;
 use pulldown_cmark::{Event, Tag, TagEnd};
+use regex::Regex;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::HashSet;
 
 pub struct MD051;
 
@@ -16,6 +17,10 @@ impl Rule for MD051 {
         "Link fragments should be valid"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["link-fragments"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
@@ -23,33 +28,23 @@ impl Rule for MD051 {
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
 
-        // Build a set of valid heading fragments
-        let mut heading_ids: HashMap<String, usize> = HashMap::new();
-        let mut in_heading = false;
-        let mut current_heading_text = String::new();
-
-        // First pass: collect all headings
-        for (event, _range) in parser.parse_with_offsets() {
-            match event {
-                Event::Start(Tag::Heading { .. }) => {
-                    in_heading = true;
-                    current_heading_text.clear();
-                }
-                Event::Text(text) if in_heading => {
-                    current_heading_text.push_str(&text);
-                }
-                Event::End(TagEnd::Heading(_)) if in_heading => {
-                    let heading_id = heading_to_id(&current_heading_text);
-                    // Handle duplicate headings by tracking counts
-                    let count = heading_ids.entry(heading_id.clone()).or_insert(0);
-                    *count += 1;
-                    in_heading = false;
+        let mut valid_ids: HashSet<String> =
+            parser.headings().iter().map(|h| h.id.clone()).collect();
+
+        // Anchors defined in HTML, e.g. `<a name="x">` or `<span id="x">`
+        let anchor_attr =
+            Regex::new(r#"(?i)\s(?:id|name)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).unwrap();
+        for event in parser.parse() {
+            if let Event::Html(html) | Event::InlineHtml(html) = event {
+                for caps in anchor_attr.captures_iter(&html) {
+                    if let Some(id) = caps.get(1).or(caps.get(2)).or(caps.get(3)) {
+                        valid_ids.insert(id.as_str().to_string());
+                    }
                 }
-                _ => {}
             }
         }
 
-        // Second pass: check link fragments
+        // Check link fragments
         let mut in_link = false;
         let mut link_url = String::new();
         let mut link_line = 0;
@@ -67,13 +62,14 @@ impl Rule for MD051 {
                         // Remove the '#'
                         let fragment_id = fragment.to_string();
 
-                        if !heading_ids.contains_key(&fragment_id) {
+                        if !valid_ids.contains(&fragment_id) {
                             violations.push(Violation {
                                 line: link_line,
                                 column: Some(1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
-                                    "Link fragment '{}' does not match any heading",
+                                    "Link fragment '{}' does not match any heading or anchor",
                                     fragment
                                 ),
                                 fix: None,
@@ -86,13 +82,14 @@ impl Rule for MD051 {
                             let fragment = &link_url[pos + 1..];
                             let fragment_id = fragment.to_string();
 
-                            if !heading_ids.contains_key(&fragment_id) {
+                            if !valid_ids.contains(&fragment_id) {
                                 violations.push(Violation {
                                     line: link_line,
                                     column: Some(1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: format!(
-                                        "Link fragment '{}' does not match any heading",
+                                        "Link fragment '{}' does not match any heading or anchor",
                                         fragment
                                     ),
                                     fix: None,
@@ -115,24 +112,6 @@ impl Rule for MD051 {
     }
 }
 
-/// Convert heading text to a GitHub-style heading ID
-fn heading_to_id(text: &str) -> String {
-    text.to_lowercase()
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
-                c
-            } else if c.is_whitespace() {
-                '-'
-            } else {
-                // Remove special characters
-                '\0'
-            }
-        })
-        .filter(|&c| c != '\0')
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +167,25 @@ mod tests {
         // External links should be ignored
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_html_anchor_targets() {
+        let content = "<a id=\"top\"></a>\n\n# Title\n\n[Up](#top) and [down](#bottom)";
+        let parser = MarkdownParser::new(content);
+        let rule = MD051;
+        let violations = rule.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'bottom'"));
+    }
+
+    #[test]
+    fn test_inline_html_name_and_id_attributes() {
+        let content = "Text <a name='intro'></a> and <span class=\"x\" id=\"note\">n</span>.\n\n[a](#intro) [b](#note)";
+        let parser = MarkdownParser::new(content);
+        let rule = MD051;
+        let violations = rule.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
 }
@@ -16,6 +16,10 @@ impl Rule for MD052 {
         "Reference links and images should use a label that is defined"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["reference-links-images"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
@@ -54,6 +58,7 @@ impl Rule for MD052 {
                     violations.push(Violation {
                         line: line_number,
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: format!(
                             "Reference {} label '{}' is not defined",
@@ -16,6 +16,10 @@ impl Rule for MD053 {
         "Link and image reference definitions should be needed"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["link-image-reference-definitions"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
@@ -55,6 +59,7 @@ impl Rule for MD053 {
                 violations.push(Violation {
                     line: line_number,
                     column: Some(1),
+                    end_column: None,
                     rule: self.name().to_string(),
                     message: format!(
                         "Link reference definition '{}' is defined but not used",
@@ -15,6 +15,10 @@ impl Rule for MD054 {
         "Link and image style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["link-image-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links"]
     }
@@ -53,6 +57,7 @@ impl Rule for MD054 {
                             violations.push(Violation {
                                     line: parser.offset_to_line(range.start),
                                     column: Some(1),
+                                    end_column: None,
                                     rule: self.name().to_string(),
                                     message: format!(
                                         "Link/image style should be consistent: expected '{}', found '{}'",
@@ -68,6 +73,7 @@ impl Rule for MD054 {
                     violations.push(Violation {
                         line: parser.offset_to_line(range.start),
                         column: Some(1),
+                        end_column: None,
                         rule: self.name().to_string(),
                         message: format!(
                             "Link/image style should be '{}', found '{}'",
@@ -1,6 +1,7 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{Event, Tag};
 use serde_json::Value;
 
 pub struct MD055;
@@ -14,6 +15,10 @@ impl Rule for MD055 {
         "Table pipe style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["table-pipe-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["table"]
     }
@@ -25,135 +30,105 @@ impl Rule for MD055 {
             .unwrap_or("leading_and_trailing");
 
         let mut violations = Vec::new();
-        let mut first_style: Option<&str> = None;
-        let code_block_lines = parser.get_code_block_line_numbers();
+        let mut expected = pipes_for_style(style);
 
-        for (line_num, line) in parser.lines().iter().enumerate() {
-            let line_number = line_num + 1;
-
-            if code_block_lines.contains(&line_number) {
+        for (line_number, prefix_len) in table_rows(parser) {
+            let line = parser.lines()[line_number - 1];
+            let row = line[prefix_len.min(line.len())..].trim_end();
+            if row.is_empty() {
                 continue;
             }
 
-            // Check if line is a table row (contains pipes)
-            if !line.contains('|') {
-                continue;
-            }
+            // Columns are 1-indexed and count characters.
+            let first_column = line[..prefix_len.min(line.len())].chars().count() + 1;
+            let row_chars: Vec<char> = row.chars().collect();
+            let last_column = first_column + row_chars.len() - 1;
 
-            let trimmed = line.trim();
+            let has_leading = row.starts_with('|');
+            let has_trailing = row.len() > 1 && row.ends_with('|') && !row.ends_with("\\|");
 
-            // Determine the style of this line
-            let has_leading = trimmed.starts_with('|');
-            let has_trailing = trimmed.ends_with('|');
+            let (want_leading, want_trailing) = match expected {
+                Some(pipes) => pipes,
+                None => {
+                    // "consistent": the first row sets the style for the file.
+                    expected = Some((has_leading, has_trailing));
+                    continue;
+                }
+            };
 
-            let current_style = match (has_leading, has_trailing) {
-                (true, true) => "leading_and_trailing",
-                (true, false) => "leading_only",
-                (false, true) => "trailing_only",
-                (false, false) => "no_leading_or_trailing",
+            // Removing the leading pipe also takes one following space; the trailing
+            // removal takes one preceding space unless that space was already taken.
+            let leading_end = if row_chars.get(1) == Some(&' ') {
+                first_column + 1
+            } else {
+                first_column
             };
 
-            if style == "consistent" {
-                if let Some(first) = first_style {
-                    if current_style != first {
-                        // Report separate violations for leading and trailing mismatches
-                        let (first_leading, first_trailing) = match first {
-                            "leading_and_trailing" => (true, true),
-                            "leading_only" => (true, false),
-                            "trailing_only" => (false, true),
-                            "no_leading_or_trailing" => (false, false),
-                            _ => (false, false),
-                        };
-
-                        // Check leading pipe
-                        if has_leading != first_leading {
-                            violations.push(Violation {
-                                line: line_number,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Table pipe style should be consistent: expected {}, found {}",
-                                    if first_leading {
-                                        "leading pipe"
-                                    } else {
-                                        "no leading pipe"
-                                    },
-                                    if has_leading {
-                                        "leading pipe"
-                                    } else {
-                                        "no leading pipe"
-                                    }
-                                ),
-                                fix: None,
-                            });
-                        }
-
-                        // Check trailing pipe
-                        if has_trailing != first_trailing {
-                            violations.push(Violation {
-                                line: line_number,
-                                column: Some(1),
-                                rule: self.name().to_string(),
-                                message: format!(
-                                    "Table pipe style should be consistent: expected {}, found {}",
-                                    if first_trailing {
-                                        "trailing pipe"
-                                    } else {
-                                        "no trailing pipe"
-                                    },
-                                    if has_trailing {
-                                        "trailing pipe"
-                                    } else {
-                                        "no trailing pipe"
-                                    }
-                                ),
-                                fix: None,
-                            });
-                        }
+            if has_leading != want_leading {
+                let fix = if want_leading {
+                    Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(first_column),
+                        column_end: Some(first_column),
+                        replacement: format!("| {}", row_chars[0]),
+                        description: "Add leading pipe".to_string(),
                     }
                 } else {
-                    first_style = Some(current_style);
-                }
-            } else if style == "leading_and_trailing" && current_style != "leading_and_trailing" {
-                // Report separate violations for missing leading/trailing
-                if !has_leading {
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Table should have leading pipe".to_string(),
-                        fix: None,
-                    });
-                }
-                if !has_trailing {
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Table should have trailing pipe".to_string(),
-                        fix: None,
-                    });
-                }
-            } else if style == "no_leading_or_trailing" && (has_leading || has_trailing) {
-                // Report separate violations for unwanted leading/trailing
-                if has_leading {
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Table should not have leading pipe".to_string(),
-                        fix: None,
-                    });
-                }
-                if has_trailing {
-                    violations.push(Violation {
-                        line: line_number,
-                        column: Some(1),
-                        rule: self.name().to_string(),
-                        message: "Table should not have trailing pipe".to_string(),
-                        fix: None,
-                    });
-                }
+                    Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(first_column),
+                        column_end: Some(leading_end),
+                        replacement: String::new(),
+                        description: "Remove leading pipe".to_string(),
+                    }
+                };
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(first_column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: pipe_message(style, "leading", want_leading, has_leading),
+                    fix: Some(fix),
+                });
+            }
+
+            if has_trailing != want_trailing {
+                let fix = if want_trailing {
+                    Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(last_column),
+                        column_end: Some(last_column),
+                        replacement: format!("{} |", row_chars[row_chars.len() - 1]),
+                        description: "Add trailing pipe".to_string(),
+                    }
+                } else {
+                    let space_before = row_chars.len() > 1
+                        && row_chars[row_chars.len() - 2] == ' '
+                        && (last_column - 1 > leading_end || has_leading == want_leading);
+                    Fix {
+                        line_start: line_number,
+                        line_end: line_number,
+                        column_start: Some(if space_before {
+                            last_column - 1
+                        } else {
+                            last_column
+                        }),
+                        column_end: Some(last_column),
+                        replacement: String::new(),
+                        description: "Remove trailing pipe".to_string(),
+                    }
+                };
+                violations.push(Violation {
+                    line: line_number,
+                    column: Some(last_column),
+                    end_column: None,
+                    rule: self.name().to_string(),
+                    message: pipe_message(style, "trailing", want_trailing, has_trailing),
+                    fix: Some(fix),
+                });
             }
         }
 
@@ -161,13 +136,75 @@ impl Rule for MD055 {
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
+    }
+}
+
+/// Required (leading, trailing) pipes for an enforced style, or `None` for "consistent".
+fn pipes_for_style(style: &str) -> Option<(bool, bool)> {
+    match style {
+        "consistent" => None,
+        "leading_only" => Some((true, false)),
+        "trailing_only" => Some((false, true)),
+        "no_leading_or_trailing" => Some((false, false)),
+        _ => Some((true, true)),
+    }
+}
+
+fn pipe_message(style: &str, side: &str, wanted: bool, found: bool) -> String {
+    let describe = |present: bool| {
+        if present {
+            format!("{} pipe", side)
+        } else {
+            format!("no {} pipe", side)
+        }
+    };
+    if style == "consistent" {
+        format!(
+            "Table pipe style should be consistent: expected {}, found {}",
+            describe(wanted),
+            describe(found)
+        )
+    } else if wanted {
+        format!("Table should have {} pipe", side)
+    } else {
+        format!("Table should not have {} pipe", side)
+    }
+}
+
+/// Table rows (header, delimiter and body) as `(line, byte offset of the row
+/// within the line)`, so rows inside blockquotes and list items skip their prefix.
+fn table_rows(parser: &MarkdownParser) -> Vec<(usize, usize)> {
+    let mut rows = Vec::new();
+    for (event, range) in parser.parse_with_offsets() {
+        match event {
+            Event::Start(Tag::TableHead) => {
+                let (line, column) = parser.offset_to_position(range.start);
+                rows.push((line, column - 1));
+                // The delimiter row has no event of its own; assume the same prefix.
+                rows.push((line + 1, column - 1));
+            }
+            Event::Start(Tag::TableRow) => {
+                let (line, column) = parser.offset_to_position(range.start);
+                rows.push((line, column - 1));
+            }
+            _ => {}
+        }
     }
+    rows
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_consistent_with_pipes() {
@@ -222,4 +259,51 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_fix_adds_leading_and_trailing_pipes() {
+        let content = "| Col1 | Col2 |\n|------|------|\nA    | B\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "leading_and_trailing" });
+        let violations = MD055.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "| Col1 | Col2 |\n|------|------|\n| A    | B |\n");
+    }
+
+    #[test]
+    fn test_fix_removes_pipes_for_no_leading_or_trailing() {
+        let content = "| a | b |\n|---|---|\n| c | d |\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "no_leading_or_trailing" });
+        let violations = MD055.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 6);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "a | b\n---|---\nc | d\n");
+    }
+
+    #[test]
+    fn test_fix_consistent_follows_first_row() {
+        let content = "| a | b\n|---|---\n| c | d |\n";
+        let parser = MarkdownParser::new(content);
+        let config = serde_json::json!({ "style": "consistent" });
+        let violations = MD055.check(&parser, Some(&config));
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, Some(9));
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(fixed, "| a | b\n|---|---\n| c | d\n");
+    }
+
+    #[test]
+    fn test_table_in_blockquote_and_prose_pipes() {
+        let content = "Use a | b in prose.\n\n> | a | b |\n> |---|---|\n> c | d\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD055.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        let fixed = apply_fixes(content, &violations);
+        assert_eq!(
+            fixed,
+            "Use a | b in prose.\n\n> | a | b |\n> |---|---|\n> | c | d |\n"
+        );
+    }
 }
@@ -14,6 +14,10 @@ impl Rule for MD056 {
         "Table column count"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["table-column-count"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["table"]
     }
@@ -33,7 +37,7 @@ impl Rule for MD056 {
             }
 
             // Count columns in this row
-            let row_columns = count_columns(line);
+            let row_columns = count_columns(&mask_code_span_pipes(parser, i));
 
             // Check if next line is a separator (making this a table header)
             if i + 1 < lines.len() {
@@ -47,6 +51,7 @@ impl Rule for MD056 {
                         violations.push(Violation {
                             line: i + 2, // +1 for 1-indexed, +1 for next line
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "Table separator has {} columns, expected {}",
@@ -64,11 +69,12 @@ impl Rule for MD056 {
                             break;
                         }
 
-                        let data_columns = count_columns(data_line);
+                        let data_columns = count_columns(&mask_code_span_pipes(parser, i));
                         if data_columns != expected_columns {
                             violations.push(Violation {
                                 line: i + 1,
                                 column: Some(1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "Table row has {} columns, expected {}",
@@ -95,6 +101,23 @@ impl Rule for MD056 {
     }
 }
 
+/// Line `index` (0-based) with pipes inside inline code spans blanked out, so they
+/// are not counted as separators. In a table, GFM only forms a code span around a
+/// pipe that is escaped (`` `a \| b` ``); an unescaped one still splits the cell.
+fn mask_code_span_pipes(parser: &MarkdownParser, index: usize) -> String {
+    let line = parser.lines()[index];
+    let start = parser.line_offset_to_absolute(index + 1, 0);
+    let code_ranges = parser.get_inline_code_ranges();
+    line.char_indices()
+        .map(|(offset, c)| {
+            let in_code = code_ranges
+                .iter()
+                .any(|range| range.contains(&(start + offset)));
+            if c == '|' && in_code { ' ' } else { c }
+        })
+        .collect()
+}
+
 /// Count the number of columns in a table row by counting pipe separators
 fn count_columns(line: &str) -> usize {
     let trimmed = line.trim();
@@ -188,4 +211,24 @@ mod tests {
 
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_code_span_with_escaped_pipe_is_one_cell() {
+        let content = "| A | B |\n|---|---|\n| `a \\| b` | c |\n| `x\\|y` `z` | w |";
+        let parser = MarkdownParser::new(content);
+        let violations = MD056.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_unescaped_pipe_in_backticks_splits_cell() {
+        // GFM splits the row before looking for code spans, so this row has 3 cells.
+        let content = "| A | B |\n|---|---|\n| `a | b` | c |";
+        let parser = MarkdownParser::new(content);
+        let violations = MD056.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("3 columns"));
+    }
 }
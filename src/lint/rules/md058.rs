@@ -1,7 +1,9 @@
 use crate::lint::rule::Rule;
 use crate::markdown::MarkdownParser;
-use crate::types::Violation;
+use crate::types::{Fix, Violation};
+use pulldown_cmark::{CodeBlockKind, Event, Tag};
 use serde_json::Value;
+use std::collections::HashSet;
 
 pub struct MD058;
 
@@ -14,6 +16,10 @@ impl Rule for MD058 {
         "Tables should be surrounded by blank lines"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["blanks-around-tables"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["table", "blank_lines"]
     }
@@ -21,62 +27,108 @@ impl Rule for MD058 {
     fn check(&self, parser: &MarkdownParser, _config: Option<&Value>) -> Vec<Violation> {
         let mut violations = Vec::new();
         let lines = parser.lines();
-        let mut i = 0;
-
-        while i < lines.len() {
-            let line = lines[i].trim();
-
-            // Check if this looks like a table start
-            if line.contains('|') && !is_separator_line(line) {
-                // Check if next line is separator (confirming this is a table)
-                if i + 1 < lines.len() && is_separator_line(lines[i + 1].trim()) {
-                    // Found start of table, check for blank line before
-                    if i > 0 && !lines[i - 1].trim().is_empty() {
-                        violations.push(Violation {
-                            line: i + 1,
-                            column: Some(1),
-                            rule: self.name().to_string(),
-                            message: "Table should be surrounded by blank lines".to_string(),
-                            fix: None,
-                        });
-                    }
-
-                    // Skip to end of table (but don't check for blank line after,
-                    // since Markdown spec treats following text as part of the table)
-                    i += 2; // Skip header and separator
-                    while i < lines.len() {
-                        let current = lines[i].trim();
-                        if !current.contains('|') {
-                            break;
-                        }
-                        i += 1;
-                    }
-
-                    continue;
-                }
+        let mut fence_opens = HashSet::new();
+        let mut fence_closes = HashSet::new();
+        for (event, range) in parser.parse_with_offsets() {
+            if let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) = event {
+                fence_opens.insert(parser.offset_to_line(range.start));
+                fence_closes.insert(parser.offset_to_line(range.end.saturating_sub(1)));
             }
+        }
 
-            i += 1;
+        for (event, range) in parser.parse_with_offsets() {
+            let Event::Start(Tag::Table(_)) = event else {
+                continue;
+            };
+            let first_line = parser.offset_to_line(range.start);
+            let last_line = parser.offset_to_line(range.end.saturating_sub(1).max(range.start));
+            // Text before the table on its first line, e.g. `> ` inside a blockquote,
+            // which an inserted blank line needs to stay in the same container.
+            let (_, table_column) = parser.offset_to_position(range.start);
+            let prefix = lines[first_line - 1][..table_column - 1].trim_end();
+
+            // Next to a code fence, the blank line is added the way MD031 adds it, so
+            // the Fixer applies the two rules' identical fixes once.
+            if first_line > 1 && !is_blank(lines[first_line - 2]) {
+                let fix = if prefix.is_empty() && fence_closes.contains(&(first_line - 1)) {
+                    rewrite_line(first_line - 1, format!("{}\n", lines[first_line - 2]))
+                } else {
+                    insert_after(first_line - 1, lines, prefix)
+                };
+                violations.push(self.violation(first_line, fix));
+            }
+            if last_line < lines.len() && !is_blank(lines[last_line]) {
+                let fix = if prefix.is_empty() && fence_opens.contains(&(last_line + 1)) {
+                    rewrite_line(last_line + 1, format!("\n{}", lines[last_line]))
+                } else {
+                    insert_after(last_line, lines, prefix)
+                };
+                violations.push(self.violation(last_line, fix));
+            }
         }
 
         violations
     }
 
     fn fixable(&self) -> bool {
-        false
+        true
     }
 }
 
-/// Check if a line is a table separator (contains ---)
-fn is_separator_line(line: &str) -> bool {
-    let trimmed = line.trim();
-    (trimmed.contains("---") || trimmed.contains(":--") || trimmed.contains("--:"))
-        && trimmed.contains('|')
+impl MD058 {
+    fn violation(&self, line: usize, fix: Fix) -> Violation {
+        Violation {
+            line,
+            column: Some(1),
+            end_column: None,
+            rule: self.name().to_string(),
+            message: "Table should be surrounded by blank lines".to_string(),
+            fix: Some(fix),
+        }
+    }
+}
+
+/// Insert a blank line, continuing `prefix`, at the end of line `line`.
+fn insert_after(line: usize, lines: &[&str], prefix: &str) -> Fix {
+    let end = lines[line - 1].chars().count();
+    Fix {
+        line_start: line,
+        line_end: line,
+        column_start: Some(end + 1),
+        column_end: Some(end),
+        replacement: format!("\n{prefix}"),
+        description: "Insert blank line around table".to_string(),
+    }
+}
+
+/// Replace line `line` with `replacement`, which adds the blank line.
+fn rewrite_line(line: usize, replacement: String) -> Fix {
+    Fix {
+        line_start: line,
+        line_end: line,
+        column_start: None,
+        column_end: None,
+        replacement,
+        description: "Insert blank line around table".to_string(),
+    }
+}
+
+/// Whether a line is blank, ignoring blockquote markers.
+fn is_blank(line: &str) -> bool {
+    line.chars().all(|c| c == '>' || c.is_whitespace())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fix::Fixer;
+
+    fn apply_fixes(content: &str, violations: &[Violation]) -> String {
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        Fixer::new()
+            .apply_fixes_to_content(content, &fixes)
+            .unwrap()
+    }
 
     #[test]
     fn test_table_with_blank_lines() {
@@ -130,4 +182,57 @@ mod tests {
         // No blank line before is okay at start of document
         assert_eq!(violations.len(), 0);
     }
+
+    #[test]
+    fn test_escaped_pipe_in_prose() {
+        let content = "Intro text\na \\| b\n---|---\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD058.check(&parser, None);
+
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_fix_inserts_blank_lines() {
+        let content = "Text before\n| A | B |\n|---|---|\n| 1 | 2 |\n# Next\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD058.check(&parser, None);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 2);
+        assert_eq!(violations[1].line, 4);
+        assert_eq!(
+            apply_fixes(content, &violations),
+            "Text before\n\n| A | B |\n|---|---|\n| 1 | 2 |\n\n# Next\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_keeps_blockquote() {
+        let content = "> Text\n> | A |\n> |---|\n> | 1 |\n";
+        let parser = MarkdownParser::new(content);
+        let violations = MD058.check(&parser, None);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            apply_fixes(content, &violations),
+            "> Text\n>\n> | A |\n> |---|\n> | 1 |\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_next_to_fence_combines_with_md031() {
+        use crate::lint::rules::md031::MD031;
+
+        let content = "| A |\n|---|\n| 1 |\n```\ncode\n```\n| B |\n|---|\n| 2 |\n";
+        let parser = MarkdownParser::new(content);
+        let mut violations = MD058.check(&parser, None);
+        assert_eq!(violations.len(), 2);
+        violations.extend(MD031.check(&parser, None));
+
+        assert_eq!(
+            apply_fixes(content, &violations),
+            "| A |\n|---|\n| 1 |\n\n```\ncode\n```\n\n| B |\n|---|\n| 2 |\n"
+        );
+    }
 }
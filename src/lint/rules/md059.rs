@@ -15,6 +15,10 @@ impl Rule for MD059 {
         "Link text should be descriptive"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["descriptive-link-text"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["links", "accessibility"]
     }
@@ -56,6 +60,7 @@ impl Rule for MD059 {
                         violations.push(Violation {
                             line: link_line,
                             column: Some(1),
+                            end_column: None,
                             rule: self.name().to_string(),
                             message: format!(
                                 "Link text '{}' is not descriptive; use meaningful text",
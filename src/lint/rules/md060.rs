@@ -14,6 +14,10 @@ impl Rule for MD060 {
         "Table column style"
     }
 
+    fn aliases(&self) -> &[&str] {
+        &["table-column-style"]
+    }
+
     fn tags(&self) -> &[&str] {
         &["table"]
     }
@@ -46,6 +50,7 @@ impl Rule for MD060 {
                                     violations.push(Violation {
                                         line: line_number,
                                         column: Some(1),
+                                        end_column: None,
                                         rule: self.name().to_string(),
                                         message: format!(
                                             "Table column {} alignment should be consistent: expected '{}', found '{}'",
@@ -68,6 +73,7 @@ impl Rule for MD060 {
                             violations.push(Violation {
                                 line: line_number,
                                 column: Some(1),
+                                end_column: None,
                                 rule: self.name().to_string(),
                                 message: format!(
                                     "Table column {} should use '{}' alignment, found '{}'",
@@ -1,72 +1,168 @@
 use clap::Parser;
-use mdlint::args::{CheckArgs, Cli, Command, FormatArgs, OutputFormat, TerminalColor};
+use mdlint::args::{CheckArgs, Cli, Command, FormatArgs, TerminalColor};
 use mdlint::config::loader::{ConfigLoader, find_all_configs};
 use mdlint::config::{Config, merge_many_configs};
-use mdlint::error::Result;
-use mdlint::fix::Fixer;
-use mdlint::format::{DefaultFormatter, Formatter, JsonFormatter};
+use mdlint::error::{MarkdownlintError, Result};
+use mdlint::fix::{Fixer, unified_diff};
+use mdlint::format::{
+    ConciseFormatter, DefaultFormatter, Formatter, JsonFormatter, JunitFormatter, NdjsonFormatter,
+    OutputFormat, SarifFormatter,
+};
 use mdlint::formatter;
-use mdlint::glob::FileWalker;
+use mdlint::glob::{FileWalker, GlobMatcher, IgnoreMatcher, is_glob_pattern, split_glob_pattern};
+use mdlint::lint::rules::create_default_registry;
 use mdlint::lint::{LintEngine, LintResult};
-use mdlint::types::Violation;
+use mdlint::types::{FileResult, Fix, Violation};
+use std::collections::HashSet;
 use std::env;
 use std::fs;
-use std::io::{self, IsTerminal};
+use std::io::{self, IsTerminal, Write};
+use std::iter;
 use std::path::PathBuf;
 use std::process;
 
+/// Environment variable naming a config file to load instead of discovering one.
+const CONFIG_ENV_VAR: &str = "MARKDOWNLINT_CONFIG";
+
+/// No violations (or nothing to reformat).
+const EXIT_SUCCESS: i32 = 0;
+/// Violations found, or `format --check` found files to reformat.
+const EXIT_LINT_FAILURE: i32 = 1;
+/// Invalid arguments or config, or an I/O error; also clap's exit code for usage errors.
+const EXIT_ERROR: i32 = 2;
+
 fn main() {
     process::exit(
         run()
-            .map(|had_errors| if had_errors { 1 } else { 0 })
-            .unwrap_or(2),
+            .map(|had_errors| {
+                if had_errors {
+                    EXIT_LINT_FAILURE
+                } else {
+                    EXIT_SUCCESS
+                }
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                EXIT_ERROR
+            }),
     );
 }
 
 fn run() -> Result<bool> {
     let cli = Cli::parse();
     let config = load_config(&cli)?;
-    let use_color = should_use_color(&cli.color);
+    let use_color = should_use_color(&cli.color_choice());
 
     match &cli.command {
-        Command::Check(args) => run_check(args, config, use_color, cli.verbose),
-        Command::Format(args) => run_format(args, config),
+        Command::Check(args) => run_check(args, config, use_color, cli.verbose, cli.debug),
+        Command::Format(args) => run_format(args, config, cli.debug),
         Command::Server(_) => mdlint::server::run_server().map(|()| false),
     }
 }
 
-fn run_check(args: &CheckArgs, config: Config, use_color: bool, verbose: bool) -> Result<bool> {
+fn run_check(
+    args: &CheckArgs,
+    config: Config,
+    use_color: bool,
+    verbose: bool,
+    debug: bool,
+) -> Result<bool> {
+    if args.list_rules {
+        print!("{}", list_rules());
+        return Ok(false);
+    }
+
+    if let Some(rule_name) = &args.explain {
+        print!("{}", explain_rule(rule_name)?);
+        return Ok(false);
+    }
+
     let excludes = merge_excludes(&args.exclude, &config.exclude);
     let should_fix = args.fix || config.fix;
-    let files = find_files(&args.files(), &excludes, args.should_respect_ignore())?;
+    let fix_rules = resolve_fix_rules(&args.fix_rules)?;
+    let files = match &args.files_from {
+        Some(manifest) => read_files_from(manifest)?,
+        None => find_files(&args.files(), &excludes, &config.ignores, || {
+            FileWalker::new(args.should_respect_ignore())
+                .max_depth(args.max_depth)
+                .extensions(&config.extensions)
+        })?,
+    };
+    if debug {
+        debug_files(&files);
+    }
 
     if files.is_empty() {
         eprintln!("No markdown files found");
         return Ok(false);
     }
 
-    let lint_result = if args.parallel && !args.no_parallel {
-        lint_files_parallel(config, &files, verbose)?
+    let fail_on = config.fail_on;
+    let output_format = config.output_format;
+    let engine = LintEngine::new(config);
+    let mut lint_result = if args.parallel && !args.no_parallel {
+        lint_files_parallel(&engine, &files, verbose)?
     } else {
-        lint_files(config, &files, verbose)?
+        lint_files(&engine, &files, verbose)?
     };
 
-    if should_fix && lint_result.has_errors() {
-        apply_fixes(&lint_result)?;
+    if args.fix_dry_run {
+        print_fix_diffs(&lint_result, fix_rules.as_ref())?;
+    } else if should_fix && lint_result.has_errors() {
+        let summary = apply_fixes(
+            &engine,
+            &lint_result,
+            fix_rules.as_ref(),
+            args.backup.as_deref(),
+        )?;
+        eprintln!("{}", summary);
+    }
+
+    if args.relative_paths {
+        // Canonical so it lines up with the canonical paths the walker returns.
+        let cwd = env::current_dir()?.canonicalize()?;
+        lint_result.relativize_paths(&cwd);
     }
 
-    let output = match args.output_format {
-        OutputFormat::Default => DefaultFormatter::new(use_color).format(&lint_result),
-        OutputFormat::Json => JsonFormatter::new(false).format(&lint_result),
+    let output_format = args.output_format.or(output_format).unwrap_or_default();
+    let formatter: Box<dyn Formatter> = match output_format {
+        OutputFormat::Default => Box::new(
+            DefaultFormatter::new(use_color)
+                .with_stats(args.stats)
+                .with_severity(|path| engine.severities(path)),
+        ),
+        OutputFormat::Concise => Box::new(ConciseFormatter::new()),
+        OutputFormat::Json => Box::new(JsonFormatter::new(false)),
+        OutputFormat::Ndjson => Box::new(NdjsonFormatter::new()),
+        OutputFormat::Junit => Box::new(JunitFormatter::new()),
+        OutputFormat::Sarif => {
+            Box::new(SarifFormatter::new().with_severity(|path| engine.severities(path)))
+        }
     };
-    print!("{}", output);
+    let mut stdout = io::stdout().lock();
+    formatter.format_to(&lint_result, &mut stdout)?;
+    stdout.flush()?;
 
-    Ok(lint_result.has_errors())
+    if args.exit_zero {
+        return Ok(false);
+    }
+    let failing = lint_result.failing_violations(fail_on, |path| engine.severities(path));
+    Ok(match args.max_errors {
+        Some(max) => failing > max,
+        None => failing > 0,
+    })
 }
 
-fn run_format(args: &FormatArgs, config: Config) -> Result<bool> {
+fn run_format(args: &FormatArgs, config: Config, debug: bool) -> Result<bool> {
     let excludes = merge_excludes(&args.exclude, &config.exclude);
-    let files = find_files(&args.files(), &excludes, args.should_respect_ignore())?;
+    let files = find_files(&args.files(), &excludes, &config.ignores, || {
+        FileWalker::new(args.should_respect_ignore())
+            .max_depth(args.max_depth)
+            .extensions(&config.extensions)
+    })?;
+    if debug {
+        debug_files(&files);
+    }
 
     if files.is_empty() {
         eprintln!("No markdown files found");
@@ -96,17 +192,124 @@ fn run_format(args: &FormatArgs, config: Config) -> Result<bool> {
     Ok(args.check && any_changed)
 }
 
+fn list_rules() -> String {
+    let registry = create_default_registry();
+    let mut rules: Vec<_> = registry.all_rules().collect();
+    rules.sort_by_key(|rule| rule.name().to_string());
+    rules
+        .iter()
+        .map(|rule| {
+            let names: Vec<&str> = iter::once(rule.name())
+                .chain(rule.aliases().iter().copied())
+                .collect();
+            format!(
+                "{}  {} [{}]\n",
+                names.join("/"),
+                rule.description(),
+                rule.tags().join(", ")
+            )
+        })
+        .collect()
+}
+
+fn explain_rule(rule_name: &str) -> Result<String> {
+    let registry = create_default_registry();
+    let rule = registry
+        .get(rule_name)
+        .ok_or_else(|| MarkdownlintError::Config(format!("Unknown rule: {}", rule_name)))?;
+
+    let mut output = format!("{}: {}\n", rule.name(), rule.description());
+    if !rule.aliases().is_empty() {
+        output.push_str(&format!("Aliases: {}\n", rule.aliases().join(", ")));
+    }
+    output.push_str(&format!("Tags: {}\n", rule.tags().join(", ")));
+    output.push_str(&format!(
+        "Fixable: {}\n",
+        if rule.fixable() { "yes" } else { "no" }
+    ));
+
+    let default_config = rule.default_config();
+    if default_config
+        .as_object()
+        .is_some_and(|opts| !opts.is_empty())
+    {
+        let options = toml::to_string(&default_config)
+            .map_err(|e| MarkdownlintError::Config(format!("Failed to render config: {}", e)))?;
+        output.push_str(&format!(
+            "\nDefault configuration:\n\n[rules.{}]\n{}",
+            rule.name(),
+            options
+        ));
+    }
+
+    Ok(output)
+}
+
 fn load_config(cli: &Cli) -> Result<Config> {
-    match ConfigLoader::from(cli) {
+    // `--config` > `MARKDOWNLINT_CONFIG` > discovery; `--no-config` ignores all of them.
+    let mut source = "--config";
+    let loader = match ConfigLoader::from(cli) {
+        ConfigLoader::Detect => match env::var_os(CONFIG_ENV_VAR).filter(|p| !p.is_empty()) {
+            Some(path) => {
+                source = CONFIG_ENV_VAR;
+                ConfigLoader::File(PathBuf::from(path))
+            }
+            None => ConfigLoader::Detect,
+        },
+        loader => loader,
+    };
+    let config = match &loader {
         ConfigLoader::Detect => {
             let configs = find_all_configs(&env::current_dir()?)?;
+            if cli.debug {
+                if configs.is_empty() {
+                    eprintln!("Debug: no config files found");
+                }
+                for (path, _) in &configs {
+                    eprintln!("Debug: config file: {}", path.display());
+                }
+            }
             if configs.is_empty() {
-                return Ok(Config::default());
+                Config::default()
+            } else {
+                let config_list: Vec<Config> = configs.into_iter().map(|(_, cfg)| cfg).collect();
+                merge_many_configs(config_list)
             }
-            let config_list: Vec<Config> = configs.into_iter().map(|(_, cfg)| cfg).collect();
-            Ok(merge_many_configs(config_list))
         }
-        loader => loader.load(),
+        ConfigLoader::File(path) => {
+            if cli.debug {
+                eprintln!("Debug: config file: {} (from {})", path.display(), source);
+            }
+            loader.load()?
+        }
+        ConfigLoader::None => {
+            if cli.debug {
+                eprintln!("Debug: config files ignored (--no-config)");
+            }
+            loader.load()?
+        }
+    };
+
+    if cli.debug {
+        let rendered = toml::to_string(&config)
+            .map_err(|e| MarkdownlintError::Config(format!("Failed to render config: {}", e)))?;
+        eprintln!("Debug: effective config:\n{}", rendered.trim_end());
+    }
+    Ok(config)
+}
+
+/// Rewrite a gitignore-style `!pattern` negation to the matcher's `#pattern` exclude.
+fn normalize_negation(pattern: &str) -> String {
+    match pattern.strip_prefix('!') {
+        Some(rest) if !rest.is_empty() => format!("#{rest}"),
+        _ => pattern.to_string(),
+    }
+}
+
+fn debug_files(files: &[PathBuf]) {
+    eprintln!("Debug: {} file(s) to process", files.len());
+    for file in files {
+        eprintln!("Debug:   {}", file.display());
     }
 }
 
@@ -116,27 +319,89 @@ fn merge_excludes(cli_excludes: &[PathBuf], config_excludes: &[String]) -> Vec<P
     excludes
 }
 
+/// Resolve `paths` to the markdown files to process. `make_walker` is only called
+/// when a directory or glob has to be walked, so explicit file lists (as passed by
+/// pre-commit hooks) never set up a walk.
 fn find_files(
     paths: &[PathBuf],
     excludes: &[PathBuf],
-    respect_ignore: bool,
+    ignores: &[String],
+    make_walker: impl Fn() -> FileWalker,
 ) -> Result<Vec<PathBuf>> {
+    // `#`- or `!`-prefixed patterns exclude matches from every walk and always win
+    // over the rest, which select files.
+    let (exclude_patterns, patterns): (Vec<String>, Vec<String>) = paths
+        .iter()
+        .map(|path| normalize_negation(&path.to_string_lossy()))
+        .partition(|pattern| pattern.starts_with('#'));
+    let patterns = if patterns.is_empty() {
+        vec![".".to_string()]
+    } else {
+        patterns
+    };
+
     let mut all_files = Vec::new();
+    // Exclude patterns are written relative to the working directory (or as
+    // absolute paths), not to whichever root a file was walked from.
+    let exclude_matcher = GlobMatcher::new(&exclude_patterns)?;
+    let cwd = env::current_dir().and_then(|cwd| cwd.canonicalize()).ok();
+    // Config `ignores` are anchored to their config file's directory, so they're
+    // matched against the canonical path whichever path was passed.
+    let ignore_matcher = IgnoreMatcher::new(ignores)?;
+    // Keyed by canonical path so `./a.md`, `a.md`, and a walked `/abs/a.md` match.
+    let mut seen = HashSet::new();
     let mut add_to_file = |path: PathBuf| {
-        if !all_files.contains(&path) && !is_excluded(&path, excludes) {
+        let key = path.canonicalize().unwrap_or_else(|_| path.clone());
+        let relative = cwd
+            .as_deref()
+            .and_then(|cwd| key.strip_prefix(cwd).ok())
+            .unwrap_or_else(|| path.strip_prefix(".").unwrap_or(&path));
+        if !is_excluded(&path, excludes)
+            && exclude_matcher.matches(relative)
+            && exclude_matcher.matches(&key)
+            && !ignore_matcher.is_ignored(&key)
+            && seen.insert(key)
+        {
             all_files.push(path);
         }
     };
 
-    for path in paths {
-        if path.is_dir() {
-            let walker = FileWalker::new(respect_ignore);
+    // Stat each literal path once; globs are resolved by walking.
+    let targets: Vec<(&String, Option<fs::Metadata>)> = patterns
+        .iter()
+        .map(|pattern| {
+            let metadata = if is_glob_pattern(pattern) {
+                None
+            } else {
+                fs::metadata(pattern).ok()
+            };
+            (pattern, metadata)
+        })
+        .collect();
+    let mut walker = None;
+
+    for (pattern, metadata) in targets {
+        let path = PathBuf::from(pattern);
+        if is_glob_pattern(pattern) {
+            let (root, glob) = split_glob_pattern(pattern);
+            let matcher = GlobMatcher::new(&[glob])?;
+            if root.is_dir() {
+                walker
+                    .get_or_insert_with(&make_walker)
+                    .find_files_with_matcher(&root, &matcher)?
+                    .into_iter()
+                    .for_each(&mut add_to_file);
+            } else {
+                eprintln!("Warning: Path not found: {}", root.display());
+            }
+        } else if metadata.as_ref().is_some_and(fs::Metadata::is_dir) {
             walker
-                .find_markdown_files(path)?
+                .get_or_insert_with(&make_walker)
+                .find_markdown_files(&path)?
                 .into_iter()
                 .for_each(&mut add_to_file);
-        } else if path.is_file() {
-            add_to_file(path.clone());
+        } else if metadata.as_ref().is_some_and(fs::Metadata::is_file) {
+            add_to_file(path);
         } else {
             eprintln!("Warning: Path not found: {}", path.display());
         }
@@ -145,6 +410,28 @@ fn find_files(
     Ok(all_files)
 }
 
+/// Read newline-separated paths from `manifest` (`-` for stdin). Blank lines are
+/// ignored; paths that don't exist are warned about and skipped.
+fn read_files_from(manifest: &PathBuf) -> Result<Vec<PathBuf>> {
+    let contents = if manifest.as_os_str() == "-" {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(manifest)?
+    };
+
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    for line in contents.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let path = PathBuf::from(line);
+        if !path.is_file() {
+            eprintln!("Warning: Path not found: {}", path.display());
+        } else if seen.insert(path.clone()) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
 fn is_excluded(path: &PathBuf, excludes: &[PathBuf]) -> bool {
     excludes.iter().any(|exclude| {
         // Canonicalize the exclude path so relative paths (e.g. "FORMAT_SPEC.md")
@@ -159,10 +446,13 @@ fn is_excluded(path: &PathBuf, excludes: &[PathBuf]) -> bool {
 
 type FileOutcome = Result<(PathBuf, Vec<Violation>, Vec<String>)>;
 
-fn lint_files_parallel(config: Config, files: &[PathBuf], verbose: bool) -> Result<LintResult> {
+fn lint_files_parallel(
+    engine: &LintEngine,
+    files: &[PathBuf],
+    verbose: bool,
+) -> Result<LintResult> {
     use rayon::prelude::*;
 
-    let engine = LintEngine::new(config);
     let outcomes: Vec<FileOutcome> = files
         .par_iter()
         .map(|file_path| {
@@ -170,7 +460,7 @@ fn lint_files_parallel(config: Config, files: &[PathBuf], verbose: bool) -> Resu
                 eprintln!("Checking: {}", file_path.display());
             }
             let content = fs::read_to_string(file_path)?;
-            let violations = engine.lint_content(&content)?;
+            let violations = engine.lint_file(file_path, &content)?;
             let source_lines = content.lines().map(str::to_string).collect();
             Ok((file_path.clone(), violations, source_lines))
         })
@@ -188,8 +478,7 @@ fn lint_files_parallel(config: Config, files: &[PathBuf], verbose: bool) -> Resu
     Ok(lint_result)
 }
 
-fn lint_files(config: Config, files: &[PathBuf], verbose: bool) -> Result<LintResult> {
-    let engine = LintEngine::new(config);
+fn lint_files(engine: &LintEngine, files: &[PathBuf], verbose: bool) -> Result<LintResult> {
     let mut lint_result = LintResult::new();
 
     for file_path in files {
@@ -197,7 +486,7 @@ fn lint_files(config: Config, files: &[PathBuf], verbose: bool) -> Result<LintRe
             eprintln!("Checking: {}", file_path.display());
         }
         let content = fs::read_to_string(file_path)?;
-        let violations = engine.lint_content(&content)?;
+        let violations = engine.lint_file(file_path, &content)?;
         if violations.is_empty() {
             lint_result.record_clean_file();
         } else {
@@ -223,27 +512,132 @@ fn should_use_color(color: &TerminalColor) -> bool {
     }
 }
 
-fn apply_fixes(lint_result: &LintResult) -> Result<()> {
+/// Canonical names of the rules given to `--fix-rules`, or `None` to fix every rule.
+fn resolve_fix_rules(names: &[String]) -> Result<Option<HashSet<String>>> {
+    if names.is_empty() {
+        return Ok(None);
+    }
+    let registry = create_default_registry();
+    names
+        .iter()
+        .map(|name| {
+            registry
+                .resolve(name.trim())
+                .map(str::to_string)
+                .ok_or_else(|| MarkdownlintError::Config(format!("Unknown rule: {}", name)))
+        })
+        .collect::<Result<HashSet<_>>>()
+        .map(Some)
+}
+
+fn file_fixes(file_result: &FileResult, fix_rules: Option<&HashSet<String>>) -> Vec<Fix> {
+    file_result
+        .violations
+        .iter()
+        .filter(|v| fix_rules.is_none_or(|rules| rules.contains(&v.rule)))
+        .filter_map(|v| v.fix.clone())
+        .collect()
+}
+
+/// Print what `--fix` would change in each file, leaving the files untouched.
+/// Diffs go to stderr so stdout holds only the report, which may be JSON or SARIF.
+fn print_fix_diffs(lint_result: &LintResult, fix_rules: Option<&HashSet<String>>) -> Result<()> {
+    let fixer = Fixer::with_dry_run(true);
+    let mut stderr = io::stderr().lock();
+
+    for file_result in &lint_result.file_results {
+        let fixes = file_fixes(file_result, fix_rules);
+
+        if fixes.is_empty() {
+            continue;
+        }
+
+        let content = fs::read_to_string(&file_result.path)?;
+        match fixer.apply_fixes_to_content(&content, &fixes) {
+            Ok(fixed_content) => {
+                let path = file_result.path.display().to_string();
+                write!(stderr, "{}", unified_diff(&path, &content, &fixed_content))?;
+            }
+            Err(e) => {
+                eprintln!(
+                    "Failed to apply fixes to {}: {}",
+                    file_result.path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    stderr.flush()?;
+    Ok(())
+}
+
+/// Totals printed after `--fix` writes its changes.
+#[derive(Debug, Default)]
+struct FixSummary {
+    files_changed: usize,
+    fixes_applied: usize,
+    /// Fixes in files the fixer rejected, which were left untouched.
+    fixes_failed: usize,
+    remaining: usize,
+}
+
+impl std::fmt::Display for FixSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Fixed {} error(s) in {} file(s), {} error(s) remaining",
+            self.fixes_applied, self.files_changed, self.remaining
+        )?;
+        if self.fixes_failed > 0 {
+            write!(f, ", {} fix(es) could not be applied", self.fixes_failed)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write each file's fixes. What remains in a fixed file is counted by linting the
+/// fixed content again, since one fix can clear several violations.
+fn apply_fixes(
+    engine: &LintEngine,
+    lint_result: &LintResult,
+    fix_rules: Option<&HashSet<String>>,
+    backup_suffix: Option<&str>,
+) -> Result<FixSummary> {
     let fixer = Fixer::new();
+    let mut summary = FixSummary::default();
 
     for file_result in &lint_result.file_results {
-        let fixes: Vec<_> = file_result
-            .violations
-            .iter()
-            .filter_map(|v| v.fix.clone())
-            .collect();
+        let fixes = file_fixes(file_result, fix_rules);
+        let found = file_result.violations.len();
 
         if fixes.is_empty() {
+            summary.remaining += found;
             continue;
         }
 
         let content = fs::read_to_string(&file_result.path)?;
         match fixer.apply_fixes_to_content(&content, &fixes) {
             Ok(fixed_content) => {
+                if fixed_content == content {
+                    summary.remaining += found;
+                    continue;
+                }
+                let remaining = engine.lint_file(&file_result.path, &fixed_content)?.len();
+                summary.files_changed += 1;
+                summary.fixes_applied += found.saturating_sub(remaining);
+                summary.remaining += remaining;
+                if let Some(suffix) = backup_suffix {
+                    let mut backup = file_result.path.clone().into_os_string();
+                    backup.push(suffix);
+                    fs::write(&backup, &content)?;
+                }
                 fs::write(&file_result.path, fixed_content)?;
                 eprintln!("Fixed: {}", file_result.path.display());
             }
             Err(e) => {
+                summary.fixes_failed += fixes.len();
+                summary.remaining += found - fixes.len();
                 eprintln!(
                     "Failed to apply fixes to {}: {}",
                     file_result.path.display(),
@@ -253,5 +647,5 @@ fn apply_fixes(lint_result: &LintResult) -> Result<()> {
         }
     }
 
-    Ok(())
+    Ok(summary)
 }
@@ -1,27 +1,62 @@
+use std::ops::RangeInclusive;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FrontMatterType {
     Yaml,
     Toml,
+    Json,
+    /// Delimited by a user-configured line, e.g. `front_matter = ";;;"`.
+    Custom(String),
 }
 
 const YAML_FRONT_MATTER: &str = "---";
 const TOML_FRONT_MATTER: &str = "+++";
+const JSON_FRONT_MATTER_OPEN: &str = "{";
+const JSON_FRONT_MATTER_CLOSE: &str = "}";
 
 #[derive(Debug, Clone)]
 pub struct FrontMatter {
     pub matter_type: FrontMatterType,
+    /// Text between the delimiters. JSON front matter keeps its braces so it
+    /// remains a valid JSON object.
     pub content: String,
+    /// 1-indexed line of the closing delimiter.
     pub end_line: usize,
 }
 
+impl FrontMatter {
+    /// The 1-indexed lines occupied by the front matter, delimiters included.
+    pub fn lines(&self) -> RangeInclusive<usize> {
+        1..=self.end_line
+    }
+}
+
+/// Detect YAML (`---`), TOML (`+++`), or JSON (`{` … `}`) front matter at the top of `content`.
 pub fn detect_front_matter(content: &str) -> Option<FrontMatter> {
+    detect_front_matter_with(content, None)
+}
+
+/// Like [`detect_front_matter`], but when `delimiter` is set only front matter
+/// fenced by that exact line is recognized.
+pub fn detect_front_matter_with(content: &str, delimiter: Option<&str>) -> Option<FrontMatter> {
     let lines: Vec<&str> = content.lines().collect();
     if lines.is_empty() {
         return None;
     }
 
-    detect_filetype_front_matter(&lines, FrontMatterType::Yaml)
-        .or_else(|| detect_filetype_front_matter(&lines, FrontMatterType::Toml))
+    match delimiter {
+        Some(delimiter) => {
+            let matter_type = match delimiter {
+                YAML_FRONT_MATTER => FrontMatterType::Yaml,
+                TOML_FRONT_MATTER => FrontMatterType::Toml,
+                custom => FrontMatterType::Custom(custom.to_string()),
+            };
+            detect_filetype_front_matter(&lines, matter_type)
+        }
+        None => detect_filetype_front_matter(&lines, FrontMatterType::Yaml)
+            .or_else(|| detect_filetype_front_matter(&lines, FrontMatterType::Toml))
+            .or_else(|| detect_filetype_front_matter(&lines, FrontMatterType::Json)),
+    }
 }
 
 fn detect_filetype_front_matter(
@@ -32,19 +67,25 @@ fn detect_filetype_front_matter(
         return None;
     }
 
-    let front_matter = match matter_type {
-        FrontMatterType::Yaml => YAML_FRONT_MATTER,
-        FrontMatterType::Toml => TOML_FRONT_MATTER,
+    let (open, close) = match &matter_type {
+        FrontMatterType::Yaml => (YAML_FRONT_MATTER, YAML_FRONT_MATTER),
+        FrontMatterType::Toml => (TOML_FRONT_MATTER, TOML_FRONT_MATTER),
+        FrontMatterType::Json => (JSON_FRONT_MATTER_OPEN, JSON_FRONT_MATTER_CLOSE),
+        FrontMatterType::Custom(delimiter) => (delimiter.as_str(), delimiter.as_str()),
     };
-    if lines[0] != front_matter {
+    if lines[0] != open {
         return None;
     }
 
     for (i, line) in lines.iter().enumerate().skip(1) {
-        if *line != front_matter {
+        if *line != close {
             continue;
         }
-        let content = lines[1..i].join("\n");
+        let content = if matter_type == FrontMatterType::Json {
+            lines[..=i].join("\n")
+        } else {
+            lines[1..i].join("\n")
+        };
         return Some(FrontMatter {
             matter_type,
             content,
@@ -77,6 +118,7 @@ mod tests {
         assert_eq!(fm.matter_type, FrontMatterType::Yaml);
         assert_eq!(fm.content, "title: Test\nauthor: John");
         assert_eq!(fm.end_line, 4);
+        assert_eq!(fm.lines(), 1..=4);
     }
 
     #[test]
@@ -89,6 +131,36 @@ mod tests {
         assert_eq!(fm.end_line, 4);
     }
 
+    #[test]
+    fn test_detect_json_front_matter() {
+        let content = "{\n  \"title\": \"Test\"\n}\n# Heading";
+        let fm = detect_front_matter(content).unwrap();
+
+        assert_eq!(fm.matter_type, FrontMatterType::Json);
+        assert_eq!(fm.content, "{\n  \"title\": \"Test\"\n}");
+        assert_eq!(fm.lines(), 1..=3);
+    }
+
+    #[test]
+    fn test_detect_custom_front_matter() {
+        let content = ";;;\ntitle: Test\n;;;\n# Heading";
+        assert!(detect_front_matter(content).is_none());
+
+        let fm = detect_front_matter_with(content, Some(";;;")).unwrap();
+        assert_eq!(fm.matter_type, FrontMatterType::Custom(";;;".to_string()));
+        assert_eq!(fm.content, "title: Test");
+        assert_eq!(fm.lines(), 1..=3);
+    }
+
+    #[test]
+    fn test_configured_delimiter_excludes_others() {
+        let content = "+++\ntitle = \"Test\"\n+++\n# Heading";
+        assert!(detect_front_matter_with(content, Some("---")).is_none());
+
+        let fm = detect_front_matter_with(content, Some("+++")).unwrap();
+        assert_eq!(fm.matter_type, FrontMatterType::Toml);
+    }
+
     #[test]
     fn test_no_front_matter() {
         let content = "# Heading\nSome content";
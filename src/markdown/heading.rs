@@ -0,0 +1,89 @@
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+
+/// A heading found in the document, ATX or setext.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadingInfo {
+    pub level: HeadingLevel,
+    /// Heading text with inline code kept in backticks and other markup dropped, trimmed.
+    pub text: String,
+    /// 1-indexed line the heading starts on.
+    pub line: usize,
+    /// Fragment id a link would use: an explicit `{#id}` attribute, otherwise the
+    /// GitHub-style slug of `text`, suffixed `-1`, `-2`, … when repeated.
+    pub id: String,
+}
+
+/// Collect every heading in one AST pass. `line_of` maps a byte offset to its line.
+pub(crate) fn collect_headings<'a>(
+    events: Parser<'a>,
+    line_of: impl Fn(usize) -> usize,
+) -> Vec<HeadingInfo> {
+    let mut headings = Vec::new();
+    let mut slug_counts: HashMap<String, usize> = HashMap::new();
+    let mut current: Option<(HeadingLevel, usize, Option<String>, String)> = None;
+
+    for (event, range) in events.into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, id, .. }) => {
+                let id = id.map(|id| id.into_string());
+                current = Some((level, line_of(range.start), id, String::new()));
+            }
+            Event::Text(text) => {
+                if let Some((_, _, _, heading_text)) = current.as_mut() {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::Code(code) => {
+                if let Some((_, _, _, heading_text)) = current.as_mut() {
+                    heading_text.push('`');
+                    heading_text.push_str(&code);
+                    heading_text.push('`');
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, line, explicit_id, text)) = current.take() {
+                    let text = text.trim().to_string();
+                    let id = explicit_id.unwrap_or_else(|| {
+                        let slug = heading_to_id(&text);
+                        let count = slug_counts.entry(slug.clone()).or_insert(0);
+                        let id = if *count == 0 {
+                            slug
+                        } else {
+                            format!("{slug}-{count}")
+                        };
+                        *count += 1;
+                        id
+                    });
+                    headings.push(HeadingInfo {
+                        level,
+                        text,
+                        line,
+                        id,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    headings
+}
+
+/// Convert heading text to a GitHub-style heading ID
+fn heading_to_id(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else if c.is_whitespace() {
+                '-'
+            } else {
+                // Remove special characters
+                '\0'
+            }
+        })
+        .filter(|&c| c != '\0')
+        .collect()
+}
@@ -1,5 +1,9 @@
 mod front_matter;
+mod heading;
 mod parser;
 
-pub use front_matter::{FrontMatter, FrontMatterType, detect_front_matter};
+pub use front_matter::{
+    FrontMatter, FrontMatterType, detect_front_matter, detect_front_matter_with,
+};
+pub use heading::HeadingInfo;
 pub use parser::MarkdownParser;
@@ -1,4 +1,7 @@
+use super::heading::{HeadingInfo, collect_headings};
+use crate::config::CommonMarkOptions;
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::cell::OnceCell;
 use std::collections::HashSet;
 use std::ops::Range;
 
@@ -8,29 +11,65 @@ pub struct MarkdownParser<'a> {
     /// Byte offset of the start of each line (0-indexed).
     /// Enables O(log n) offset → (line, column) lookup via binary search.
     line_offsets: Vec<usize>,
+    /// pulldown-cmark extensions used for every parse of this document.
+    options: Options,
+    /// Where code sits in the document, built by one AST pass on first use.
+    code_info: OnceCell<CodeInfo>,
+    /// Every heading in document order, built by one AST pass on first use.
+    headings: OnceCell<Vec<HeadingInfo>>,
+}
+
+/// Code locations shared by every rule that needs to skip code.
+#[derive(Default)]
+struct CodeInfo {
     /// Lines (1-indexed) that fall inside a fenced/indented code block.
     code_block_lines: HashSet<usize>,
     /// Lines (1-indexed) inside any code (blocks + inline spans).
     code_lines: HashSet<usize>,
     /// Byte ranges of all code blocks and inline code spans.
     code_ranges: Vec<Range<usize>>,
+    /// Byte ranges of inline code spans only.
+    inline_code_ranges: Vec<Range<usize>>,
+    /// Byte ranges of `$…$` and `$$…$$` math, when math parsing is enabled.
+    math_ranges: Vec<Range<usize>>,
 }
 
 impl<'a> MarkdownParser<'a> {
     pub fn new(content: &'a str) -> Self {
         let lines: Vec<&'a str> = content.lines().collect();
         let line_offsets = build_line_offsets(content);
-        let (code_block_lines, code_lines, code_ranges) = build_code_info(content, &line_offsets);
         Self {
             content,
             lines,
             line_offsets,
-            code_block_lines,
-            code_lines,
-            code_ranges,
+            options: mk_options(),
+            code_info: OnceCell::new(),
+            headings: OnceCell::new(),
         }
     }
 
+    /// Parse `$…$` and `$$…$$` as math rather than text, so rules can skip it.
+    pub fn with_math(mut self, enabled: bool) -> Self {
+        self.options.set(Options::ENABLE_MATH, enabled);
+        self
+    }
+
+    /// Enable or disable the extensions beyond strict CommonMark (all on by default).
+    pub fn with_commonmark(mut self, commonmark: &CommonMarkOptions) -> Self {
+        self.options.set(Options::ENABLE_TABLES, commonmark.tables);
+        self.options
+            .set(Options::ENABLE_FOOTNOTES, commonmark.footnotes);
+        self.options
+            .set(Options::ENABLE_STRIKETHROUGH, commonmark.strikethrough);
+        self.options
+            .set(Options::ENABLE_TASKLISTS, commonmark.tasklists);
+        self.options.set(
+            Options::ENABLE_HEADING_ATTRIBUTES,
+            commonmark.heading_attributes,
+        );
+        self
+    }
+
     pub fn content(&self) -> &'a str {
         self.content
     }
@@ -52,11 +91,11 @@ impl<'a> MarkdownParser<'a> {
     }
 
     pub fn parse(&self) -> impl Iterator<Item = Event<'a>> + 'a {
-        Parser::new_ext(self.content, mk_options())
+        Parser::new_ext(self.content, self.options)
     }
 
     pub fn parse_with_offsets(&self) -> impl Iterator<Item = (Event<'a>, Range<usize>)> {
-        Parser::new_ext(self.content, mk_options()).into_offset_iter()
+        Parser::new_ext(self.content, self.options).into_offset_iter()
     }
 
     pub fn offset_to_line(&self, offset: usize) -> usize {
@@ -75,22 +114,49 @@ impl<'a> MarkdownParser<'a> {
         (line_idx + 1, column) // 1-indexed
     }
 
+    fn code_info(&self) -> &CodeInfo {
+        self.code_info
+            .get_or_init(|| build_code_info(self.content, self.options, &self.line_offsets))
+    }
+
     /// Returns the 1-indexed line numbers inside code blocks or inline code.
-    /// Result is precomputed in `new()` — O(1) to access.
+    /// Computed on first use of any code helper and cached.
     pub fn get_code_line_numbers(&self) -> &HashSet<usize> {
-        &self.code_lines
+        &self.code_info().code_lines
     }
 
     /// Returns the 1-indexed line numbers inside code blocks only (not inline spans).
-    /// Result is precomputed in `new()` — O(1) to access.
+    /// Computed on first use of any code helper and cached.
     pub fn get_code_block_line_numbers(&self) -> &HashSet<usize> {
-        &self.code_block_lines
+        &self.code_info().code_block_lines
     }
 
     /// Returns byte ranges (into the original content) for all code blocks and
-    /// inline code spans. Result is precomputed in `new()` — O(1) to access.
+    /// inline code spans. Computed on first use of any code helper and cached.
     pub fn get_code_ranges(&self) -> &[Range<usize>] {
-        &self.code_ranges
+        &self.code_info().code_ranges
+    }
+
+    /// Returns byte ranges (into the original content) for inline code spans only.
+    /// Computed on first use of any code helper and cached.
+    pub fn get_inline_code_ranges(&self) -> &[Range<usize>] {
+        &self.code_info().inline_code_ranges
+    }
+
+    /// Returns every heading (ATX and setext) with its level, text, line, and
+    /// fragment id. Computed on first use and cached.
+    pub fn headings(&self) -> &[HeadingInfo] {
+        self.headings.get_or_init(|| {
+            collect_headings(Parser::new_ext(self.content, self.options), |offset| {
+                self.offset_to_line(offset)
+            })
+        })
+    }
+
+    /// Returns byte ranges (into the original content) for math spans and blocks.
+    /// Always empty unless math parsing was enabled with [`Self::with_math`].
+    pub fn get_math_ranges(&self) -> &[Range<usize>] {
+        &self.code_info().math_ranges
     }
 
     /// Converts a (1-indexed) line number and 0-indexed byte offset within that
@@ -148,65 +214,53 @@ fn line_from_offset(offset: usize, line_offsets: &[usize]) -> usize {
     i.max(1)
 }
 
-/// Single parse pass that builds all three code-location caches simultaneously.
-/// Called once in `MarkdownParser::new()`.
-fn build_code_info(
-    content: &str,
-    line_offsets: &[usize],
-) -> (HashSet<usize>, HashSet<usize>, Vec<Range<usize>>) {
-    let mut code_block_lines: HashSet<usize> = HashSet::new();
-    let mut code_lines: HashSet<usize> = HashSet::new();
-    let mut code_ranges: Vec<Range<usize>> = Vec::new();
+/// Lines spanned by the half-open byte range `range`. Block ranges end just past
+/// their trailing newline, which must not pull in the following line.
+fn lines_in_range(range: &Range<usize>, line_offsets: &[usize]) -> std::ops::RangeInclusive<usize> {
+    let start_line = line_from_offset(range.start, line_offsets);
+    let end_line = line_from_offset(range.end.saturating_sub(1).max(range.start), line_offsets);
+    start_line..=end_line
+}
 
-    let mut in_code_block = false;
-    let mut code_block_start = 0usize;
+/// Single parse pass that builds all of the code-location caches simultaneously.
+/// Called once, the first time a rule asks where code is.
+fn build_code_info(content: &str, options: Options, line_offsets: &[usize]) -> CodeInfo {
+    let mut info = CodeInfo::default();
+    let mut code_block_start: Option<usize> = None;
 
-    for (event, range) in Parser::new_ext(content, mk_options()).into_offset_iter() {
+    for (event, range) in Parser::new_ext(content, options).into_offset_iter() {
         match event {
             Event::Start(Tag::CodeBlock(_)) => {
-                in_code_block = true;
-                code_block_start = range.start;
-                let start_line = line_from_offset(range.start, line_offsets);
-                let end_line = line_from_offset(range.end, line_offsets);
-                for line in start_line..=end_line {
-                    code_block_lines.insert(line);
-                    code_lines.insert(line);
+                code_block_start = Some(range.start);
+                for line in lines_in_range(&range, line_offsets) {
+                    info.code_block_lines.insert(line);
+                    info.code_lines.insert(line);
                 }
             }
             Event::End(TagEnd::CodeBlock) => {
-                if in_code_block {
-                    code_ranges.push(code_block_start..range.end);
-                    in_code_block = false;
+                if let Some(start) = code_block_start.take() {
+                    info.code_ranges.push(start..range.end);
                 }
             }
             Event::Code(_) => {
-                // Inline code span
-                code_ranges.push(range.clone());
-                let start_line = line_from_offset(range.start, line_offsets);
-                let end_line = line_from_offset(range.end, line_offsets);
-                for line in start_line..=end_line {
-                    code_lines.insert(line);
-                }
+                info.code_ranges.push(range.clone());
+                info.inline_code_ranges.push(range.clone());
+                info.code_lines.extend(lines_in_range(&range, line_offsets));
             }
-            _ => {
-                if in_code_block {
-                    let start_line = line_from_offset(range.start, line_offsets);
-                    let end_line = line_from_offset(range.end, line_offsets);
-                    for line in start_line..=end_line {
-                        code_block_lines.insert(line);
-                        code_lines.insert(line);
-                    }
-                }
+            Event::InlineMath(_) | Event::DisplayMath(_) => {
+                info.math_ranges.push(range.clone());
             }
+            _ => {}
         }
     }
 
-    (code_block_lines, code_lines, code_ranges)
+    info
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pulldown_cmark::HeadingLevel;
 
     #[test]
     fn test_basic_parsing() {
@@ -391,4 +445,91 @@ mod tests {
         assert_eq!(parser.offset_to_position(5), (2, 1));
         assert_eq!(parser.offset_to_position(7), (2, 3));
     }
+
+    #[test]
+    fn test_code_block_line_numbers_exclude_prose() {
+        let content =
+            "Prose\n\n```\nfenced\n```\n\nMore prose\n\n    indented\n    block\n\nEnd `span`\n";
+        let parser = MarkdownParser::new(content);
+        let block_lines = parser.get_code_block_line_numbers();
+
+        let mut sorted: Vec<_> = block_lines.iter().copied().collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![3, 4, 5, 9, 10]);
+        assert!(parser.get_code_line_numbers().contains(&12));
+        assert!(!block_lines.contains(&12));
+    }
+
+    #[test]
+    fn test_inline_code_ranges() {
+        let content = "Use `foo` here\n\n```\nbar\n```\n";
+        let parser = MarkdownParser::new(content);
+        let inline: Vec<_> = parser
+            .get_inline_code_ranges()
+            .iter()
+            .map(|r| &content[r.clone()])
+            .collect();
+        assert_eq!(inline, vec!["`foo`"]);
+        assert_eq!(parser.get_code_ranges().len(), 2);
+    }
+
+    #[test]
+    fn test_headings_inventory() {
+        let content = "# Title\n\nSetext Two\n----------\n\n## `Code` and *emphasis*\n\nSetext One\n==========\n\n### Title\n\n## Custom {#my-id}\n";
+        let parser = MarkdownParser::new(content);
+        let summary: Vec<_> = parser
+            .headings()
+            .iter()
+            .map(|h| (h.level, h.text.as_str(), h.line, h.id.as_str()))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (HeadingLevel::H1, "Title", 1, "title"),
+                (HeadingLevel::H2, "Setext Two", 3, "setext-two"),
+                (
+                    HeadingLevel::H2,
+                    "`Code` and emphasis",
+                    6,
+                    "code-and-emphasis"
+                ),
+                (HeadingLevel::H1, "Setext One", 8, "setext-one"),
+                (HeadingLevel::H3, "Title", 11, "title-1"),
+                (HeadingLevel::H2, "Custom", 13, "my-id"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commonmark_options_toggle_strikethrough() {
+        let content = "Some ~~deleted~~ text\n";
+        let has_strikethrough = |parser: &MarkdownParser| {
+            parser
+                .parse()
+                .any(|event| matches!(event, Event::Start(Tag::Strikethrough)))
+        };
+        assert!(has_strikethrough(&MarkdownParser::new(content)));
+
+        let commonmark = CommonMarkOptions {
+            strikethrough: false,
+            ..Default::default()
+        };
+        let parser = MarkdownParser::new(content).with_commonmark(&commonmark);
+        assert!(!has_strikethrough(&parser));
+    }
+
+    #[test]
+    fn test_math_ranges_only_when_enabled() {
+        let content = "Inline $a_i * b_j$ and\n\n$$\nx * y\n$$\n";
+        assert!(MarkdownParser::new(content).get_math_ranges().is_empty());
+
+        let parser = MarkdownParser::new(content).with_math(true);
+        let math: Vec<_> = parser
+            .get_math_ranges()
+            .iter()
+            .map(|r| &content[r.clone()])
+            .collect();
+        assert_eq!(math, vec!["$a_i * b_j$", "$$\nx * y\n$$"]);
+    }
 }
@@ -17,24 +17,32 @@ fn char_idx_to_utf16(line_text: &str, char_idx: usize) -> u32 {
 pub fn violation_to_diagnostic(v: &Violation, content: &str) -> Diagnostic {
     let lines: Vec<&str> = content.lines().collect();
     let lsp_line = v.line.saturating_sub(1) as u32;
+    let to_utf16 = |char_idx: usize| {
+        lines
+            .get(v.line.saturating_sub(1))
+            .map(|line| char_idx_to_utf16(line, char_idx))
+            .unwrap_or(0)
+    };
     let lsp_char = match v.column {
         None => 0,
-        Some(col) => {
-            let char_idx = col.saturating_sub(1);
-            lines
-                .get(v.line.saturating_sub(1))
-                .map(|line| char_idx_to_utf16(line, char_idx))
-                .unwrap_or(0)
-        }
+        Some(col) => to_utf16(col.saturating_sub(1)),
     };
     let position = Position {
         line: lsp_line,
         character: lsp_char,
     };
+    // `end_column` is inclusive; the LSP range end is exclusive.
+    let end = match v.end_column {
+        Some(end_col) => Position {
+            line: lsp_line,
+            character: to_utf16(end_col),
+        },
+        None => position,
+    };
     Diagnostic {
         range: Range {
             start: position,
-            end: position,
+            end,
         },
         severity: Some(DiagnosticSeverity::WARNING),
         code: Some(NumberOrString::String(v.rule.clone())),
@@ -133,6 +141,7 @@ mod tests {
         Violation {
             line,
             column,
+            end_column: None,
             rule: "MD001".to_string(),
             message: "test".to_string(),
             fix: None,
@@ -168,6 +177,19 @@ mod tests {
         assert_eq!(diag.range.start.character, 3);
     }
 
+    #[test]
+    fn test_end_column_extends_range() {
+        // End column 3 (inclusive, 'c') → exclusive UTF-16 end 2 (emoji) + 1 + 1 = 4.
+        let content = "\u{1F600}bc\n";
+        let v = Violation {
+            end_column: Some(3),
+            ..make_violation(1, Some(2))
+        };
+        let diag = violation_to_diagnostic(&v, content);
+        assert_eq!(diag.range.start.character, 2);
+        assert_eq!(diag.range.end.character, 4);
+    }
+
     #[test]
     fn test_uri_file_scheme() {
         let uri = Uri::from_str("file:///tmp/foo.md").unwrap();
@@ -9,15 +9,48 @@ pub struct FileResult {
     pub source_lines: Vec<String>,
 }
 
+/// A single rule violation. [`Violation::new`] and the `with_*` methods build one
+/// without spelling out the optional fields.
 #[derive(Debug, Clone)]
 pub struct Violation {
     pub line: usize,
     pub column: Option<usize>,
+    /// 1-indexed, inclusive character column where the reported span ends, when
+    /// the rule knows it.
+    pub end_column: Option<usize>,
     pub rule: String,
     pub message: String,
     pub fix: Option<Fix>,
 }
 
+impl Violation {
+    pub fn new(
+        line: usize,
+        column: Option<usize>,
+        rule: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            end_column: None,
+            rule: rule.into(),
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    pub fn with_end_column(mut self, end_column: usize) -> Self {
+        self.end_column = Some(end_column);
+        self
+    }
+
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Fix {
     pub line_start: usize,
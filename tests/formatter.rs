@@ -315,6 +315,111 @@ fn check_without_fix_does_not_modify_file() {
     );
 }
 
+#[test]
+fn check_applies_fixes_when_config_enables_fix() {
+    // `fix = true` in config applies fixes without passing `--fix`.
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = true\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Heading\n\nTrailing spaces.   \n").unwrap();
+
+    Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--config",
+            config.to_str().unwrap(),
+            file.to_str().unwrap(),
+        ])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+
+    let after = fs::read_to_string(&file).unwrap();
+    assert_eq!(after, "# Heading\n\nTrailing spaces.\n");
+}
+
+#[test]
+fn check_fix_adds_exactly_one_final_newline() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("missing.md");
+    // Trailing spaces on the last line are fixed alongside the missing newline.
+    fs::write(&missing, "# Heading\n\nNo final newline.   ").unwrap();
+    let extra = dir.path().join("extra.md");
+    fs::write(&extra, "# Heading\n\nToo many.   \n\n\n").unwrap();
+
+    Command::new(mdlint_bin())
+        .args(["check", "--fix"])
+        .args([&missing, &extra])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&missing).unwrap(),
+        "# Heading\n\nNo final newline.\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&extra).unwrap(),
+        "# Heading\n\nToo many.\n"
+    );
+}
+
+#[test]
+fn check_fix_prints_summary_to_stderr() {
+    let dir = TempDir::new().unwrap();
+    // Four fixable violations (two MD009 and an MD047 in a.md, one MD009 in b.md) and
+    // one unfixable MD013.
+    fs::write(
+        dir.path().join("a.md"),
+        format!(
+            "# Heading\n\nOne.   \nTwo.   \n\n{}",
+            "word ".repeat(30).trim_end()
+        ),
+    )
+    .unwrap();
+    fs::write(dir.path().join("b.md"), "# Heading\n\nThree.   \n").unwrap();
+    fs::write(dir.path().join("clean.md"), "# Heading\n\nClean.\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--fix", "a.md", "b.md", "clean.md"])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Fixed 4 error(s) in 2 file(s), 1 error(s) remaining"),
+        "{stderr}"
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("Fixed"));
+}
+
+#[test]
+fn check_fix_summary_counts_violations_cleared_by_another_fix() {
+    let dir = TempDir::new().unwrap();
+    // MD012 reports the trailing blank lines and MD047's fix removes them.
+    fs::write(dir.path().join("a.md"), "# Heading\n\nText\n\n\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--fix", "a.md"])
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("a.md")).unwrap(),
+        "# Heading\n\nText\n"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Fixed 2 error(s) in 1 file(s), 0 error(s) remaining"),
+        "{stderr}"
+    );
+}
+
 #[test]
 fn check_with_fix_corrects_violations_and_exits_1() {
     // `check --fix` applies inline fixes but still exits 1 because violations
@@ -341,3 +446,990 @@ fn check_with_fix_corrects_violations_and_exits_1() {
         "trailing spaces should be removed by --fix"
     );
 }
+
+#[test]
+fn check_sarif_output_is_valid_sarif_json() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "default_enabled = true\nfix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Heading\n\nTrailing spaces.   \n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--config",
+            config.to_str().unwrap(),
+            "--format",
+            "sarif",
+            file.to_str().unwrap(),
+        ])
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+
+    let sarif: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be SARIF JSON");
+    assert_eq!(sarif["version"], "2.1.0");
+    let runs = sarif["runs"]
+        .as_array()
+        .expect("SARIF should have a runs array");
+    assert_eq!(runs.len(), 1);
+    assert!(
+        runs[0]["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|r| r["ruleId"] == "MD009"),
+        "MD009 should be reported: {sarif}"
+    );
+}
+
+#[test]
+fn check_sarif_uris_are_relative_to_cwd() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("docs")).unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    fs::write(
+        dir.path().join("docs/doc.md"),
+        "# Heading\n\nTrailing spaces.   \n",
+    )
+    .unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--format", "sarif", "."])
+        .output()
+        .unwrap();
+
+    let sarif: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be SARIF JSON");
+    let location = &sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"], "docs/doc.md",
+        "{sarif}"
+    );
+    assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+    let base = sarif["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+        .as_str()
+        .unwrap();
+    assert!(base.starts_with("file://") && base.ends_with('/'), "{base}");
+}
+
+#[test]
+fn check_exits_zero_for_clean_file() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Heading\n\nSome text.\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .args(["--no-config", "check", file.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn check_no_config_ignores_discovered_config() {
+    let dir = TempDir::new().unwrap();
+    let long_line = "word ".repeat(30);
+    fs::write(
+        dir.path().join("doc.md"),
+        format!("# Heading\n\n{}\n", long_line.trim_end()),
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\n\n[rules.MD013]\nline_length = 200\n",
+    )
+    .unwrap();
+
+    let run = |global: &[&str]| {
+        Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args(global)
+            .args(["check", "--fix-dry-run", "doc.md"])
+            .output()
+            .unwrap()
+    };
+
+    let output = run(&[]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = run(&["--no-config"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("MD013"));
+
+    let output = run(&["--no-config", "--config", "mdlint.toml"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn check_exits_two_for_malformed_config() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Heading\n\nSome text.\n").unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "rules = [\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["--config", "mdlint.toml", "check", "doc.md"])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to parse TOML"), "{stderr}");
+}
+
+#[test]
+fn check_skips_malformed_discovered_config() {
+    let dir = TempDir::new().unwrap();
+    let docs = dir.path().join("docs");
+    fs::create_dir(&docs).unwrap();
+    fs::write(dir.path().join(".markdownlintrc"), "{ \"MD013\": \n").unwrap();
+    fs::write(
+        docs.join("mdlint.toml"),
+        "fix = false\n\n[rules.MD009]\nbr_spaces = 0\n",
+    )
+    .unwrap();
+    fs::write(docs.join("doc.md"), "# Heading\n\nText  \n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(&docs)
+        .args(["check", "doc.md"])
+        .output()
+        .unwrap();
+
+    // The nearby config still applies: without it the two-space line break is allowed.
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("MD009"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Warning: skipping config file"), "{stderr}");
+}
+
+#[test]
+fn check_fail_on_controls_exit_for_warning_severity() {
+    let dir = TempDir::new().unwrap();
+    // MD013 is configured as a warning; MD009 stays an error.
+    fs::write(
+        dir.path().join("warning.md"),
+        format!("# Heading\n\n{}\n", "a ".repeat(70).trim_end()),
+    )
+    .unwrap();
+    fs::write(dir.path().join("error.md"), "# Heading\n\nText   \n").unwrap();
+
+    let exit_code = |fail_on: &str, file: &str| {
+        fs::write(
+            dir.path().join("mdlint.toml"),
+            format!(
+                "fix = false\nfail_on = \"{fail_on}\"\n\n[rules.MD013]\nline_length = 80\nseverity = \"warning\"\n"
+            ),
+        )
+        .unwrap();
+        Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args(["check", file])
+            .stdout(Stdio::null())
+            .output()
+            .unwrap()
+            .status
+            .code()
+    };
+
+    assert_eq!(exit_code("error", "warning.md"), Some(0));
+    assert_eq!(exit_code("error", "error.md"), Some(1));
+    assert_eq!(exit_code("warning", "warning.md"), Some(1));
+    assert_eq!(exit_code("none", "error.md"), Some(0));
+}
+
+#[test]
+fn check_invalid_severity_is_a_config_error() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("test.md"), "# Heading\n").unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\n\n[rules.MD013]\nseverity = \"warn\"\n",
+    )
+    .unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "test.md"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid severity for MD013"), "{stderr}");
+}
+
+#[test]
+fn check_overrides_are_relative_to_config_dir() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("docs/gen")).unwrap();
+    fs::write(dir.path().join("docs/gen/api.md"), "# API\n\nText   \n").unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\n\n[[overrides]]\nfiles = [\"docs/gen/**\"]\n\n[overrides.rules]\nMD009 = false\n",
+    )
+    .unwrap();
+
+    for (cwd, file) in [(".", "docs/gen/api.md"), ("docs", "gen/api.md")] {
+        let output = Command::new(mdlint_bin())
+            .current_dir(dir.path().join(cwd))
+            .args(["check", file])
+            .output()
+            .unwrap();
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "from {cwd}: {}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+}
+
+#[test]
+fn check_override_severity_applies_to_matching_files() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("legacy")).unwrap();
+    fs::write(dir.path().join("legacy/old.md"), "# Heading\n\nText   \n").unwrap();
+    fs::write(dir.path().join("new.md"), "# Heading\n\nText   \n").unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\n\n[[overrides]]\nfiles = [\"legacy/**\"]\n\n[overrides.rules.MD009]\nseverity = \"warning\"\n",
+    )
+    .unwrap();
+
+    let check = |file: &str| {
+        Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args(["check", "--output-format", "sarif", file])
+            .output()
+            .unwrap()
+    };
+
+    let legacy = check("legacy/old.md");
+    assert_eq!(legacy.status.code(), Some(0));
+    assert!(
+        String::from_utf8_lossy(&legacy.stdout).contains("\"level\": \"warning\""),
+        "{}",
+        String::from_utf8_lossy(&legacy.stdout)
+    );
+
+    let new = check("new.md");
+    assert_eq!(new.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&new.stdout).contains("\"level\": \"error\""));
+}
+
+#[test]
+fn check_uses_output_format_from_config() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("doc.md"), "# Heading\n\nText   \n").unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\noutput_format = \"json\"\n",
+    )
+    .unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .arg("check")
+            .args(extra)
+            .arg("doc.md")
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let stdout = run(&[]);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(json.to_string().contains("MD009"), "{stdout}");
+
+    // The command-line flag wins over the config.
+    let stdout = run(&["--format", "concise"]);
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err());
+    assert!(stdout.contains("MD009"), "{stdout}");
+}
+
+#[test]
+fn check_rejects_unknown_output_format() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Heading\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .args(["check", "--format", "xml", file.to_str().unwrap()])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("sarif") && stderr.contains("junit"),
+        "error should list valid formats: {stderr}"
+    );
+}
+
+#[test]
+fn check_glob_pattern_selects_matching_files_only() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let nested = dir.path().join("docs").join("guide");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(dir.path().join("top.md"), "# Top\n").unwrap();
+    fs::write(nested.join("deep.md"), "# Deep\n").unwrap();
+    fs::write(nested.join("other.markdown"), "# Other\n").unwrap();
+    fs::write(nested.join("notes.txt"), "not markdown\n").unwrap();
+
+    let pattern = format!("{}/**/*.md", dir.path().display());
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--verbose",
+            "--config",
+            config.to_str().unwrap(),
+            &pattern,
+        ])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("top.md"),
+        "top.md should be checked: {stderr}"
+    );
+    assert!(
+        stderr.contains("deep.md"),
+        "deep.md should be checked: {stderr}"
+    );
+    assert!(!stderr.contains("other.markdown"), "{stderr}");
+    assert!(!stderr.contains("notes.txt"), "{stderr}");
+}
+
+#[test]
+fn check_duplicate_paths_are_linted_once() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\nText   \n").unwrap();
+    let pattern = format!("{}/*.md", dir.path().display());
+
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--verbose",
+            "--output-format",
+            "json",
+            "--config",
+            config.to_str().unwrap(),
+            file.to_str().unwrap(),
+            dir.path().to_str().unwrap(),
+            &pattern,
+        ])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(stderr.matches("Checking:").count(), 1, "{stderr}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["files"].as_array().unwrap().len(), 1, "{json}");
+    assert_eq!(json["summary"]["rules"]["MD009"], 1, "{json}");
+}
+
+#[test]
+fn check_exit_zero_succeeds_with_violations() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\nText   \n").unwrap();
+
+    let run = |extra: &[&str]| {
+        Command::new(mdlint_bin())
+            .args(["check", "--config", config.to_str().unwrap()])
+            .args(extra)
+            .arg(&file)
+            .stdout(Stdio::null())
+            .status()
+            .unwrap()
+            .code()
+    };
+
+    assert_eq!(run(&[]), Some(1));
+    assert_eq!(run(&["--exit-zero"]), Some(0));
+}
+
+#[test]
+fn check_max_errors_sets_failure_threshold() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    // Two violations: MD022 (missing blank after heading) and MD009 (trailing spaces).
+    fs::write(&file, "# Title\nText   \n").unwrap();
+
+    let run = |max: &str| {
+        Command::new(mdlint_bin())
+            .args([
+                "check",
+                "--config",
+                config.to_str().unwrap(),
+                "--max-errors",
+            ])
+            .arg(max)
+            .arg(&file)
+            .stdout(Stdio::null())
+            .status()
+            .unwrap()
+            .code()
+    };
+
+    assert_eq!(run("2"), Some(0));
+    assert_eq!(run("1"), Some(1));
+}
+
+#[test]
+fn check_relative_paths_renders_paths_relative_to_cwd() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    let docs = dir.path().join("docs");
+    fs::create_dir(&docs).unwrap();
+    let file = docs.join("doc.md");
+    fs::write(&file, "# Title\nText   \n").unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args(["check", "--output-format", "concise"])
+            .args(extra)
+            .arg(file.canonicalize().unwrap())
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    let absolute = run(&[]);
+    assert!(absolute.starts_with('/'), "{absolute}");
+    let relative = run(&["--relative-paths"]);
+    assert!(relative.starts_with("docs/doc.md:"), "{relative}");
+}
+
+#[test]
+fn check_files_from_lints_only_listed_files() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    for name in ["a.md", "b.md", "c.md"] {
+        fs::write(dir.path().join(name), "# Title\n").unwrap();
+    }
+    let manifest = dir.path().join("changed.txt");
+    let listed = [dir.path().join("a.md"), dir.path().join("c.md")];
+    let missing = dir.path().join("gone.md");
+    fs::write(
+        &manifest,
+        format!(
+            "{}\n\n{}\n{}\n",
+            listed[0].display(),
+            missing.display(),
+            listed[1].display()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--verbose",
+            "--config",
+            config.to_str().unwrap(),
+            "--files-from",
+            manifest.to_str().unwrap(),
+        ])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(0), "{stderr}");
+    assert!(stderr.contains("a.md"), "{stderr}");
+    assert!(stderr.contains("c.md"), "{stderr}");
+    assert!(!stderr.contains("b.md"), "{stderr}");
+    assert_eq!(stderr.matches("Checking:").count(), 2, "{stderr}");
+    assert!(stderr.contains("Path not found"), "{stderr}");
+}
+
+#[test]
+fn check_color_flags_control_ansi_output() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\nText   \n").unwrap();
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(mdlint_bin())
+            .args(["check", "--config", config.to_str().unwrap()])
+            .args(extra)
+            .arg(&file)
+            .env_remove("NO_COLOR")
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // stdout is a pipe here, so `auto` must not color but `always` must.
+    assert!(run(&["--color", "always"]).contains("\x1b["));
+    assert!(!run(&[]).contains("\x1b["));
+    assert!(!run(&["--color", "never"]).contains("\x1b["));
+    assert!(!run(&["--color", "always", "--no-color"]).contains("\x1b["));
+}
+
+#[test]
+fn check_hash_pattern_excludes_matches() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\n").unwrap();
+    let vendor = dir.path().join("vendor");
+    fs::create_dir_all(&vendor).unwrap();
+    fs::write(dir.path().join("keep.md"), "# Keep\n").unwrap();
+    fs::write(vendor.join("skip.md"), "# Skip\n").unwrap();
+
+    let pattern = format!("{}/**/*.md", dir.path().display());
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--verbose",
+            "--config",
+            config.to_str().unwrap(),
+            &pattern,
+            "#vendor",
+        ])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("keep.md"),
+        "keep.md should be checked: {stderr}"
+    );
+    assert!(
+        !stderr.contains("skip.md"),
+        "vendor should be excluded: {stderr}"
+    );
+}
+
+#[test]
+fn check_skips_files_matching_config_ignores() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("mdlint.toml");
+    fs::write(&config, "fix = false\nignores = [\"vendor/**\"]\n").unwrap();
+    let vendor = dir.path().join("vendor").join("pkg");
+    fs::create_dir_all(&vendor).unwrap();
+    fs::write(dir.path().join("keep.md"), "# Keep\n").unwrap();
+    fs::write(vendor.join("skip.md"), "# Skip\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .args([
+            "check",
+            "--verbose",
+            "--config",
+            config.to_str().unwrap(),
+            dir.path().to_str().unwrap(),
+        ])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("keep.md"),
+        "keep.md should be checked: {stderr}"
+    );
+    assert!(
+        !stderr.contains("skip.md"),
+        "vendor/** should be ignored: {stderr}"
+    );
+}
+
+#[test]
+fn check_config_ignores_literal_file_name() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\nignores = [\"CHANGELOG.md\"]\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("CHANGELOG.md"), "# Changes\n\nText   \n").unwrap();
+    fs::write(dir.path().join("keep.md"), "# Keep\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--verbose", ".", "CHANGELOG.md"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("keep.md"), "{stderr}");
+    assert!(!stderr.contains("CHANGELOG.md"), "{stderr}");
+}
+
+#[test]
+fn check_config_ignores_are_relative_to_config_dir() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\nignores = [\"docs/generated/**\"]\n",
+    )
+    .unwrap();
+    let docs = dir.path().join("docs");
+    fs::create_dir_all(docs.join("generated")).unwrap();
+    fs::write(docs.join("guide.md"), "# Guide\n").unwrap();
+    fs::write(docs.join("generated").join("api.md"), "# API\n\nText   \n").unwrap();
+
+    // Walking the subdirectory, from the config's directory and from inside it.
+    for (cwd, path) in [(dir.path().to_path_buf(), "docs"), (docs.clone(), ".")] {
+        let output = Command::new(mdlint_bin())
+            .current_dir(&cwd)
+            .args(["check", "--verbose", path])
+            .output()
+            .unwrap();
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(0), "{stderr}");
+        assert!(stderr.contains("guide.md"), "{stderr}");
+        assert!(!stderr.contains("api.md"), "{stderr}");
+    }
+}
+
+#[test]
+fn check_list_rules_prints_rules_and_exits_0() {
+    let output = Command::new(mdlint_bin())
+        .args(["check", "--list-rules"])
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let md013 = stdout
+        .lines()
+        .find(|line| line.starts_with("MD013"))
+        .expect("MD013 should be listed");
+    assert!(md013.contains("Line length"), "{md013}");
+    assert!(md013.starts_with("MD013/line-length"), "{md013}");
+    let names: Vec<_> = stdout.lines().map(|l| &l[..5]).collect();
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(names, sorted, "rules should be sorted by name");
+}
+
+#[test]
+fn check_explain_prints_rule_defaults() {
+    let output = Command::new(mdlint_bin())
+        .args(["check", "--explain", "MD013"])
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("MD013: Line length"), "{stdout}");
+    assert!(stdout.contains("Aliases: line-length"), "{stdout}");
+    assert!(stdout.contains("Fixable: no"), "{stdout}");
+    assert!(stdout.contains("line_length = 120"), "{stdout}");
+}
+
+#[test]
+fn check_explain_accepts_alias() {
+    let output = Command::new(mdlint_bin())
+        .args(["check", "--explain", "no-hard-tabs"])
+        .stderr(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("MD010: Hard tabs"), "{stdout}");
+}
+
+#[test]
+fn check_explain_unknown_rule_exits_2() {
+    let output = Command::new(mdlint_bin())
+        .args(["check", "--explain", "MD999"])
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown rule: MD999"), "{stderr}");
+}
+
+#[test]
+fn check_debug_names_loaded_config_and_files() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("mdlint.toml"),
+        "fix = false\n\n[rules.MD013]\nline_length = 120\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--debug"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(&format!(
+            "config file: {}",
+            dir.path()
+                .canonicalize()
+                .unwrap()
+                .join("mdlint.toml")
+                .display()
+        )),
+        "{stderr}"
+    );
+    assert!(stderr.contains("line_length = 120"), "{stderr}");
+    assert!(stderr.contains("doc.md"), "{stderr}");
+}
+
+#[test]
+fn check_explicit_files_match_directory_walk() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    fs::write(dir.path().join("a.md"), "# A\nText   \n").unwrap();
+    fs::write(dir.path().join("b.md"), "# B\n\nText\n").unwrap();
+
+    let summary = |args: &[&str]| {
+        let output = Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .arg("check")
+            .args(args)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        (
+            output.status.code(),
+            stdout.lines().last().unwrap_or_default().to_string(),
+        )
+    };
+
+    let walked = summary(&["."]);
+    // Repeated spellings of the same file are linted once.
+    let explicit = summary(&["a.md", "./a.md", "b.md"]);
+    assert_eq!(explicit, walked);
+    assert_eq!(
+        walked.1, "Checked 2 file(s), found 2 error(s) in 1 file(s)",
+        "{walked:?}"
+    );
+}
+
+#[test]
+fn check_bang_pattern_excludes_matching_files() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    fs::create_dir(dir.path().join("docs")).unwrap();
+    fs::write(dir.path().join("README.md"), "# Readme\nText   \n").unwrap();
+    fs::write(dir.path().join("docs/guide.md"), "# Guide\nText   \n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--output-format", "concise", "**/*.md", "!docs/**"])
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("README.md"), "{stdout}");
+    assert!(!stdout.contains("guide.md"), "{stdout}");
+}
+
+#[test]
+fn check_bang_pattern_is_relative_to_cwd_not_walk_root() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    fs::create_dir_all(dir.path().join("docs/vendor")).unwrap();
+    fs::write(dir.path().join("docs/guide.md"), "# Guide\nText   \n").unwrap();
+    fs::write(dir.path().join("docs/vendor/lib.md"), "# Lib\nText   \n").unwrap();
+
+    for root in ["docs", "docs/**/*.md"] {
+        let output = Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args([
+                "check",
+                "--output-format",
+                "concise",
+                root,
+                "!docs/vendor/**",
+            ])
+            .output()
+            .unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("guide.md"), "{root}: {stdout}");
+        assert!(!stdout.contains("lib.md"), "{root}: {stdout}");
+    }
+}
+
+#[test]
+fn check_reads_config_from_environment_variable() {
+    let dir = TempDir::new().unwrap();
+    let env_config = dir.path().join("env.toml");
+    fs::write(&env_config, "fix = false\n\n[rules]\nMD009 = false\n").unwrap();
+    let flag_config = dir.path().join("flag.toml");
+    fs::write(&flag_config, "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\n\nText   \n").unwrap();
+
+    let run = |extra: &[&str]| {
+        Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .env("MARKDOWNLINT_CONFIG", &env_config)
+            .arg("check")
+            .args(extra)
+            .arg(&file)
+            .output()
+            .unwrap()
+    };
+
+    // The environment config disables MD009, so the trailing spaces are allowed.
+    assert!(run(&[]).status.success());
+    // `--config` takes precedence over the environment variable.
+    let flag = flag_config.to_str().unwrap();
+    assert_eq!(run(&["--config", flag]).status.code(), Some(1));
+}
+
+#[test]
+fn check_fix_backup_keeps_original_content() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    let fixable = dir.path().join("fixable.md");
+    let original = "# Title\n\nText   \n";
+    fs::write(&fixable, original).unwrap();
+    let clean = dir.path().join("clean.md");
+    fs::write(&clean, "# Clean\n").unwrap();
+
+    let run = |extra: &[&str]| {
+        Command::new(mdlint_bin())
+            .current_dir(dir.path())
+            .args(["check", "--fix"])
+            .args(extra)
+            .args([&fixable, &clean])
+            .output()
+            .unwrap()
+    };
+
+    run(&["--backup"]);
+    assert_eq!(
+        fs::read_to_string(dir.path().join("fixable.md.bak")).unwrap(),
+        original
+    );
+    assert_eq!(fs::read_to_string(&fixable).unwrap(), "# Title\n\nText\n");
+    assert!(!dir.path().join("clean.md.bak").exists());
+
+    fs::write(&fixable, original).unwrap();
+    run(&["--backup=.orig"]);
+    assert_eq!(
+        fs::read_to_string(dir.path().join("fixable.md.orig")).unwrap(),
+        original
+    );
+}
+
+#[test]
+fn check_fix_dry_run_prints_diff_without_writing() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = true\n").unwrap();
+    let file = dir.path().join("doc.md");
+    let original = "# Title\n\nText   \n";
+    fs::write(&file, original).unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--fix-dry-run", "--output-format", "concise"])
+        .arg(&file)
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("@@ -1,3 +1,3 @@"), "{stderr}");
+    assert!(stderr.contains("-Text   \n+Text\n"), "{stderr}");
+    assert_eq!(fs::read_to_string(&file).unwrap(), original);
+}
+
+#[test]
+fn check_fix_dry_run_keeps_json_output_parseable() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\n\nText   \n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--fix-dry-run", "--output-format", "json"])
+        .arg(&file)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect(&stdout);
+    assert!(parsed.to_string().contains("MD009"), "{stdout}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("+Text\n"));
+}
+
+#[test]
+fn check_fix_rules_applies_only_listed_fixes() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("mdlint.toml"), "fix = false\n").unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\n\n\n\nText   \n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args([
+            "check",
+            "--fix",
+            "--fix-rules",
+            "MD009",
+            "--output-format",
+            "concise",
+        ])
+        .arg(&file)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("MD012"), "{stdout}");
+    assert_eq!(fs::read_to_string(&file).unwrap(), "# Title\n\n\n\nText\n");
+}
+
+#[test]
+fn check_fix_rules_rejects_unknown_rule() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("doc.md");
+    fs::write(&file, "# Title\n").unwrap();
+
+    let output = Command::new(mdlint_bin())
+        .current_dir(dir.path())
+        .args(["check", "--fix", "--fix-rules", "MD999"])
+        .arg(&file)
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown rule: MD999"));
+}
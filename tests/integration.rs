@@ -110,6 +110,51 @@ fn check_fix_replaces_hard_tabs() {
     assert!(!fixed.contains('\t'), "tabs should be replaced after fix");
 }
 
+#[test]
+fn check_fix_tab_indented_code_with_trailing_spaces() {
+    let content = "# T\n\n\tA  \n";
+    let violations = all_rules_engine().lint_content(content).unwrap();
+    let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+    let fixed = Fixer::new()
+        .apply_fixes_to_content(content, &fixes)
+        .unwrap();
+    assert_eq!(fixed, "# T\n\n```\nA  \n```\n");
+}
+
+#[test]
+fn check_fix_combines_tab_and_trailing_space_fixes() {
+    let content = "# Heading\n\nText\there   \n";
+    let violations = all_rules_engine().lint_content(content).unwrap();
+    let fixes: Vec<_> = violations
+        .iter()
+        .filter(|v| v.rule == "MD009" || v.rule == "MD010")
+        .filter_map(|v| v.fix.clone())
+        .collect();
+    assert_eq!(fixes.len(), 2, "{violations:?}");
+    let fixed = Fixer::new()
+        .apply_fixes_to_content(content, &fixes)
+        .unwrap();
+    assert_eq!(fixed, "# Heading\n\nText    here\n");
+}
+
+#[test]
+fn check_fix_removes_trailing_tabs() {
+    for line in ["A\t", "A\t  "] {
+        let content = format!("# Heading\n\n{line}\n");
+        let violations = all_rules_engine().lint_content(&content).unwrap();
+        let fixes: Vec<_> = violations.iter().filter_map(|v| v.fix.clone()).collect();
+        assert!(
+            violations.iter().any(|v| v.rule == "MD009")
+                && violations.iter().any(|v| v.rule == "MD010"),
+            "{violations:?}"
+        );
+        let fixed = Fixer::new()
+            .apply_fixes_to_content(&content, &fixes)
+            .unwrap();
+        assert_eq!(fixed, "# Heading\n\nA\n", "{line:?}");
+    }
+}
+
 #[test]
 fn check_clean_file_has_no_violations() {
     let content = fixture("format/expected.md");
@@ -134,3 +179,97 @@ fn check_clean_file_has_no_violations() {
         "formatted file should have no violations (except MD013/MD043): {non_trivial:?}"
     );
 }
+
+// ── Library API tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn lint_str_reports_known_violation() {
+    let violations = mdlint::lint_str("# Title\n\nTrailing   \n", &Config::default()).unwrap();
+
+    let trailing: Vec<_> = violations.iter().filter(|v| v.rule == "MD009").collect();
+    assert_eq!(trailing.len(), 1, "{violations:?}");
+    assert_eq!(trailing[0].line, 3);
+}
+
+#[test]
+fn lint_str_respects_config() {
+    let config = Config {
+        rules: std::collections::HashMap::from([(
+            "MD009".to_string(),
+            mdlint::config::RuleConfig::Enabled(false),
+        )]),
+        ..Config::default()
+    };
+    let violations = mdlint::lint_str("# Title\n\nTrailing   \n", &config).unwrap();
+    assert!(
+        violations.iter().all(|v| v.rule != "MD009"),
+        "{violations:?}"
+    );
+}
+
+// ── Custom rule tests ─────────────────────────────────────────────────────────
+
+struct NoTodoRule;
+
+impl mdlint::Rule for NoTodoRule {
+    fn name(&self) -> &str {
+        "X001"
+    }
+
+    fn description(&self) -> &str {
+        "No TODO markers"
+    }
+
+    fn tags(&self) -> &[&str] {
+        &["custom"]
+    }
+
+    fn check(
+        &self,
+        parser: &mdlint::MarkdownParser,
+        _config: Option<&serde_json::Value>,
+    ) -> Vec<mdlint::Violation> {
+        parser
+            .lines()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, line)| {
+                line.find("TODO").map(|col| {
+                    mdlint::Violation::new(
+                        idx + 1,
+                        Some(col + 1),
+                        self.name(),
+                        "TODO marker left in document",
+                    )
+                    .with_end_column(col + 4)
+                })
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn check_runs_custom_rule_alongside_builtin_rules() {
+    let mut registry = mdlint::create_default_registry();
+    registry.register(Box::new(NoTodoRule));
+    let engine = LintEngine::with_registry(
+        Config {
+            default_enabled: true,
+            ..Config::default()
+        },
+        registry,
+    );
+
+    let violations = engine.lint_content("# Title\n\nTODO: finish   \n").unwrap();
+
+    let custom: Vec<_> = violations.iter().filter(|v| v.rule == "X001").collect();
+    assert_eq!(custom.len(), 1, "{violations:?}");
+    assert_eq!(
+        (custom[0].line, custom[0].column, custom[0].end_column),
+        (3, Some(1), Some(4))
+    );
+    assert!(
+        violations.iter().any(|v| v.rule == "MD009"),
+        "{violations:?}"
+    );
+}